@@ -0,0 +1,3079 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct CircleOfLifeCenterProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for CircleOfLifeCenterProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = CircleOfLifeCenterProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        CircleOfLifeCenterProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct CircleOfLifeCenterProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> CircleOfLifeCenterProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> CircleOfLifeCenterProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> CircleOfLifeCenterProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Depot dans le token de circulation du cercle du membre. 
+    /// Seuls les depots EGLD comptent pour le bonus de depot (1 EGLD = 1%) 
+    pub fn deposit(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit")
+            .original_result()
+    }
+
+    /// Configure l'adresse du contrat template pour les SC peripheriques 
+    /// Chaque changement cree une nouvelle version de code: les SC deja deployes 
+    /// deviennent obsoletes jusqu'a upgradePeripherals 
+    pub fn set_peripheral_template<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        template_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPeripheralTemplate")
+            .argument(&template_address)
+            .original_result()
+    }
+
+    /// Met a jour depuis le template actuel les SC obsoletes parmi les SC 
+    /// [start, start + count) (1-based). Les SC deja a jour sont ignores. 
+    /// Limite du protocole: l'upgrade depuis un template est un appel asynchrone qui 
+    /// termine la transaction (pas de promesse possible), donc un seul SC est mis a jour 
+    /// par appel et `count` borne la plage parcourue. Relancer avec la meme plage jusqu'a 
+    /// l'evenement peripherals_up_to_date. Le SC enregistre lui-meme sa nouvelle version 
+    pub fn upgrade_peripherals<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        start: Arg0,
+        count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("upgradePeripherals")
+            .argument(&start)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn change_owner<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_owner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("changeOwner")
+            .argument(&new_owner)
+            .original_result()
+    }
+
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn set_entry_fee<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        new_fee: Arg0,
+        ring_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEntryFee")
+            .argument(&new_fee)
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    pub fn set_circulation_amount<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        new_amount: Arg0,
+        ring_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCirculationAmount")
+            .argument(&new_amount)
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Choisit comment le montant circulant d'un cercle est calcule (owner ou DAO V2, proposition SetCirculationPolicy) 
+    /// Le montant est recalcule au demarrage de chaque cycle (voir getNextCirculationAmount) 
+    pub fn set_circulation_policy<
+        Arg0: ProxyArg<CirculationPolicy<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        policy: Arg0,
+        ring_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCirculationPolicy")
+            .argument(&policy)
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Cree un nouveau cercle independant (rotation, membres, frais et stats propres) 
+    /// Le pool de recompenses, la synchro NFT et la distribution EGLD sont partages 
+    /// max_members: taille max de la rotation (0 = illimitee). Retourne le ring_id 
+    pub fn create_ring<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        name: Arg0,
+        entry_fee: Arg1,
+        circulation_amount: Arg2,
+        max_members: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createRing")
+            .argument(&name)
+            .argument(&entry_fee)
+            .argument(&circulation_amount)
+            .argument(&max_members)
+            .original_result()
+    }
+
+    /// Change la taille max de la rotation d'un cercle (0 = illimitee) 
+    /// Les SC deja actifs au-dela de la limite le restent 
+    pub fn set_ring_max_members<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        max_members: Arg0,
+        ring_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRingMaxMembers")
+            .argument(&max_members)
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Choisit le token qui circule dans un cercle (EGLD, stablecoin, XCIRCLEX...) 
+    /// Il sert aussi a payer les frais d'entree. Refuse pendant un cycle: les fonds 
+    /// en transit sont dans l'ancien token 
+    pub fn set_circulation_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        token: Arg0,
+        ring_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCirculationToken")
+            .argument(&token)
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Active ou desactive la distribution automatique des EGLD 
+    pub fn set_distribution_enabled<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDistributionEnabled")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    /// Configure l'adresse du contrat DAO V2 
+    pub fn set_dao_contract<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        dao_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDaoContract")
+            .argument(&dao_address)
+            .original_result()
+    }
+
+    /// Configure l'adresse du contrat de staking (destination StakingRewards) 
+    pub fn set_staking_contract<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        staking_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStakingContract")
+            .argument(&staking_address)
+            .original_result()
+    }
+
+    /// Remplace la table de distribution des EGLD (DAO V2 seulement, proposition SetDistributionTable). 
+    /// Les parts doivent totaliser 100% (BPS_BASE); une destination ne peut apparaitre qu'une fois 
+    pub fn set_distribution_table<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, DistributionShare<Env::Api>>>,
+    >(
+        self,
+        shares: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDistributionTable")
+            .argument(&shares)
+            .original_result()
+    }
+
+    /// Rachete des XCIRCLEX avec les EGLD accumules par BurnSwap puis les brule, en deux appels 
+    /// (Promises API, un seul appel async par etape comme le pipeline de liquidite): 
+    /// 1. wrap des EGLD en attente en WEGLD 
+    /// 2. swap des WEGLD en XCIRCLEX, brules dans le callback 
+    /// amount_out_min: minimum de XCIRCLEX attendu du swap (etape 2) 
+    pub fn process_burn_swap<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount_out_min: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("processBurnSwap")
+            .argument(&amount_out_min)
+            .original_result()
+    }
+
+    /// Configure l'adresse de la paire xExchange XCIRCLEX/WEGLD 
+    pub fn set_xexchange_pair<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        pair_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setXExchangePair")
+            .argument(&pair_address)
+            .original_result()
+    }
+
+    /// Configure l'adresse du contrat WEGLD 
+    pub fn set_wegld_contract<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        wegld_address: Arg0,
+        wegld_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setWegldContract")
+            .argument(&wegld_address)
+            .argument(&wegld_token)
+            .original_result()
+    }
+
+    /// Configure l'adresse du LP Locker 
+    pub fn set_lp_locker<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        locker_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLpLocker")
+            .argument(&locker_address)
+            .original_result()
+    }
+
+    /// Configure le slippage tolerance pour xExchange (en BPS, 100 = 1%) 
+    pub fn set_slippage_tolerance<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        slippage_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSlippageTolerance")
+            .argument(&slippage_bps)
+            .original_result()
+    }
+
+    /// Configure la protection de prix des swaps xExchange (owner ou DAO) 
+    pub fn set_price_guard_policy<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        max_deviation_bps: Arg0,
+        twap_window_seconds: Arg1,
+        oracle: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPriceGuardPolicy")
+            .argument(&max_deviation_bps)
+            .argument(&twap_window_seconds)
+            .argument(&oracle)
+            .original_result()
+    }
+
+    /// Met a jour la moyenne mobile du prix avec le prix spot de la paire (public, keepers) 
+    pub fn update_price_reference(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updatePriceReference")
+            .original_result()
+    }
+
+    /// Configure le seuil minimum pour declencher auto-processing de liquidite 
+    pub fn set_liquidity_threshold<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        threshold: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLiquidityThreshold")
+            .argument(&threshold)
+            .original_result()
+    }
+
+    /// Configure le LP token ID (obtenu apres premier addLiquidity) 
+    pub fn set_lp_token_id<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLpTokenId")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Configure le XCIRCLEX token ID 
+    pub fn set_xcirclex_token_id<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setXcirclexTokenId")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Unlock les LP tokens après expiration du lock (365 jours) 
+    /// Les LP tokens seront envoyés à l'adresse spécifiée 
+    pub fn unlock_lp_tokens<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        lock_id: Arg0,
+        recipient: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unlockLpTokens")
+            .argument(&lock_id)
+            .argument(&recipient)
+            .original_result()
+    }
+
+    /// Etape 1 (DAO): recupere dans SC0 les LP tokens d'un lock protocolaire expire. 
+    /// proposal_id: proposition du DAO rouverte si l'unlock echoue cote LP Locker 
+    pub fn unlock_protocol_liquidity<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        lock_id: Arg0,
+        proposal_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unlockProtocolLiquidity")
+            .argument(&lock_id)
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Etape 2 (DAO): retire de la paire la liquidite d'un lock debloque. 
+    /// Protege par le controle de prix: si le prix est manipule, l'appel echoue 
+    /// et la proposition du DAO est rouverte pour relancer l'etape 
+    pub fn remove_protocol_liquidity<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        lock_id: Arg0,
+        proposal_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeProtocolLiquidity")
+            .argument(&lock_id)
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Etape 3 (DAO): unwrap les WEGLD recuperes et distribue les EGLD 
+    /// selon la table de distribution 
+    pub fn distribute_liquidity_proceeds<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("distributeLiquidityProceeds")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Retire les EGLD accumules pour la liquidite (traitement manuel) 
+    pub fn withdraw_pending_liquidity<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        to: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawPendingLiquidity")
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Admin: execute la prochaine etape du pipeline de liquidite selon getLiquidityStage 
+    /// Idle -> wrap, Wrapped -> swap, Swapped -> addLiquidity, LiquidityAdded -> lock. 
+    /// Apres l'echec d'un appel async, relance la meme etape 
+    pub fn advance_liquidity(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("advanceLiquidity")
+            .original_result()
+    }
+
+    /// Admin: Etape 1 - Wrap EGLD -> WEGLD 
+    /// Utilise les EGLD en attente (pending_liquidity_egld) 
+    /// Apres succes, appeler liquidityStep2_Swap 
+    pub fn liquidity_step1_wrap_egld(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("liquidityStep1_WrapEgld")
+            .original_result()
+    }
+
+    /// Admin: Etape 2 - Swap WEGLD -> XCIRCLEX (50%) 
+    /// Appeler apres que l'etape 1 (wrap) soit terminee 
+    pub fn liquidity_step2_swap(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("liquidityStep2_Swap")
+            .original_result()
+    }
+
+    /// Admin: Etape 3 - Add Liquidity (WEGLD + XCIRCLEX) 
+    /// Appeler apres que l'etape 2 (swap) soit terminee 
+    pub fn liquidity_step3_add_liquidity(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("liquidityStep3_AddLiquidity")
+            .original_result()
+    }
+
+    /// Admin: Etape 4 - Lock LP tokens pour 365 jours 
+    /// Appeler apres que l'etape 3 (addLiquidity) soit terminee 
+    pub fn liquidity_step4_lock_lp(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("liquidityStep4_LockLp")
+            .original_result()
+    }
+
+    /// Admin: force l'etape du pipeline (reprise manuelle, ex: tokens laisses 
+    /// dans SC0 par une version precedente du contrat) 
+    pub fn set_liquidity_stage<
+        Arg0: ProxyArg<LiquidityStage>,
+    >(
+        self,
+        stage: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLiquidityStage")
+            .argument(&stage)
+            .original_result()
+    }
+
+    /// Distribue les EGLD existants dans SC0 selon la table de distribution 
+    /// (par defaut formule V4: 3.14% treasury (reste), 70% liquidite, 30% DAO) 
+    /// Utilise le solde EGLD actuel du contrat (moins le montant circulant requis) 
+    pub fn distribute_existing_egld<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("distributeExistingEgld")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Configure le token de recompense (XCIRCLEX) 
+    pub fn set_reward_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRewardToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Configure la recompense par cycle complete 
+    /// Exemple: 100 tokens = 100 * 10^18 
+    pub fn set_reward_per_cycle<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRewardPerCycle")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Deposer des tokens XCIRCLEX dans le pool de recompenses 
+    pub fn deposit_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositRewards")
+            .original_result()
+    }
+
+    /// Retirer des tokens du pool (owner only - urgence) 
+    pub fn withdraw_rewards<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawRewards")
+            .argument(&amount)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Recuperer les XCIRCLEX orphelins (retournes par LP) et les ajouter au pool 
+    /// Ces tokens sont dans SC0 mais pas comptabilises dans rewards_pool 
+    pub fn recover_orphan_xcirclex(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("recoverOrphanXcirclex")
+            .original_result()
+    }
+
+    /// Configure le montant de burn par SC actif pour chaque cycle reussi 
+    /// Defaut: 1 XCIRCLEX (1 * 10^18 avec 18 decimales) 
+    pub fn set_burn_per_sc<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setBurnPerSc")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Configure le bonus percentage pour celui qui demarre le cycle 
+    /// Ex: 1000 = 10%, 500 = 5%, max 5000 = 50% 
+    /// Base 10000 (pour permettre des decimales: 150 = 1.5%) 
+    pub fn set_starter_bonus_percentage<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        percentage: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStarterBonusPercentage")
+            .argument(&percentage)
+            .original_result()
+    }
+
+    /// Configure l'adresse du contrat NFT pour la synchronisation automatique des cycles 
+    pub fn set_nft_contract<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setNftContract")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Choisit la strategie d'ordre de rotation (owner ou DAO) 
+    /// Refuse pendant un cycle pour ne pas changer l'ordre en pleine rotation 
+    pub fn set_rotation_strategy<
+        Arg0: ProxyArg<RotationStrategy>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        strategy: Arg0,
+        ring_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRotationStrategy")
+            .argument(&strategy)
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Configure la politique de failCycle (owner ou DAO) 
+    pub fn set_failure_policy<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<bool>,
+    >(
+        self,
+        grace_period_seconds: Arg0,
+        warning_infractions: Arg1,
+        ban_days_per_infraction: Arg2,
+        infraction_reset_days: Arg3,
+        credit_signed_members: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFailurePolicy")
+            .argument(&grace_period_seconds)
+            .argument(&warning_infractions)
+            .argument(&ban_days_per_infraction)
+            .argument(&infraction_reset_days)
+            .argument(&credit_signed_members)
+            .original_result()
+    }
+
+    /// Configure le nombre d'epochs d'historique conserves par cercle (owner ou DAO, 0 = illimite) 
+    /// Les plus anciens sont elagues au demarrage des cycles suivants 
+    pub fn set_cycle_history_retention<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        epochs: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCycleHistoryRetention")
+            .argument(&epochs)
+            .original_result()
+    }
+
+    /// Configure le delai par tour avant skipHolder (owner ou DAO, 0 = desactive) 
+    /// skips_per_infraction: nombre de sauts comptant pour une infraction 
+    pub fn set_hop_timeout<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        timeout_seconds: Arg0,
+        skips_per_infraction: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setHopTimeout")
+            .argument(&timeout_seconds)
+            .argument(&skips_per_infraction)
+            .original_result()
+    }
+
+    /// Configure la politique de depart des membres (owner ou DAO) 
+    /// fee_refund_bps: part des frais d'entree remboursee, degressive sur refund_period_days 
+    pub fn set_exit_policy<
+        Arg0: ProxyArg<bool>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        pay_pending_rewards: Arg0,
+        fee_refund_bps: Arg1,
+        refund_period_days: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setExitPolicy")
+            .argument(&pay_pending_rewards)
+            .argument(&fee_refund_bps)
+            .argument(&refund_period_days)
+            .original_result()
+    }
+
+    /// Configure les pourboires des keepers (owner ou DAO) 
+    /// tip_per_transfer: verse par transfert traite (et par failCycle) 
+    /// cap_per_cycle: total maximum verse pour un meme cycle (epoch) 
+    pub fn set_keeper_reward<
+        Arg0: ProxyArg<KeeperTipSource>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        source: Arg0,
+        tip_per_transfer: Arg1,
+        cap_per_cycle: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setKeeperReward")
+            .argument(&source)
+            .argument(&tip_per_transfer)
+            .argument(&cap_per_cycle)
+            .original_result()
+    }
+
+    /// Configure le calendrier de reclamation des recompenses (owner ou DAO) 
+    pub fn set_claim_schedule<
+        Arg0: ProxyArg<ClaimSchedule>,
+    >(
+        self,
+        schedule: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setClaimSchedule")
+            .argument(&schedule)
+            .original_result()
+    }
+
+    /// Nombre de SC regles directement dans la transaction qui complete le cycle 
+    /// Le reste est regle via settleCycle (0 = tout passe par settleCycle) 
+    pub fn set_auto_settlement_items<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        count: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAutoSettlementItems")
+            .argument(&count)
+            .original_result()
+    }
+
+    /// Reset le cycle pour permettre de redemarrer (TEST ONLY) 
+    pub fn reset_cycle<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resetCycle")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Initialise les index pionniers pour les SC existants (MIGRATION) 
+    /// A appeler une seule fois apres l'upgrade pour attribuer les index aux SC deja deployes 
+    pub fn initialize_pioneer_indices(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("initializePioneerIndices")
+            .original_result()
+    }
+
+    /// Reconstruit l'index des SC actifs depuis contract_active (MIGRATION) 
+    /// Ouvre aussi l'intervalle de participation aux recompenses des SC actifs 
+    /// (les pending_rewards existants restent acquis et s'y ajoutent) 
+    /// Par lots pour rester sous la limite de gas: traite les SC [start, start + count) 
+    /// (1-based) et retourne le prochain index a traiter (> total = termine) 
+    /// Chaque SC est range dans l'index de son cercle 
+    pub fn rebuild_active_index<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        start: Arg0,
+        count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rebuildActiveIndex")
+            .argument(&start)
+            .argument(&count)
+            .original_result()
+    }
+
+    /// Simule le passage au jour suivant (TEST ONLY - pour tester failCycle et ban) 
+    /// Decremente cycle_day de 1 pour que current_day > cycle_day 
+    /// NE PAS effacer cycle_holder ni current_cycle_index - ils sont necessaires pour failCycle 
+    pub fn simulate_next_day<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("simulateNextDay")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Force la fin d'un cycle echoue (timeout) - les fonds vont a SC0 
+    /// Peut etre appele par n'importe qui une fois le jour du cycle termine + delai de grace 
+    /// Le SC qui a bloque le cycle est sanctionne selon le systeme progressif (voir FailurePolicy): 
+    /// les premieres infractions sont de simples avertissements, puis ban de N × 30 jours 
+    /// (N = infractions au-dela des avertissements). Le compteur est remis a zero apres 
+    /// 360 jours consecutifs sans infraction. Valeurs par defaut: 0 avertissement, 30 et 360 jours. 
+    pub fn fail_cycle<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("failCycle")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Saute le holder qui bloque la rotation depuis plus de hop_timeout_seconds 
+    /// Peut etre appele par n'importe qui: le montant circulant est retire du SC bloque 
+    /// et transfere au suivant de la rotation, le cycle continue pour les autres. 
+    /// Le SC saute ne signe pas pour ce cycle: il n'en recoit ni la recompense ni le credit 
+    /// de cycle reussi, et recoit une infraction partielle: skips_per_infraction sauts 
+    /// valent une infraction du systeme progressif (voir failCycle) 
+    /// Un holder qui a pre-signe n'est jamais saute: sa signature est executee a la place 
+    pub fn skip_holder<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("skipHolder")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Conteste un ban (ex: panne reseau) en deposant une caution EGLD et un motif. 
+    /// Cree une proposition dans le DAO V2: si elle passe, le DAO leve le ban et remet 
+    /// le compteur d'infractions a zero (caution rendue), sinon la caution va a la tresorerie du DAO. 
+    pub fn appeal_ban<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        reason: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("appealBan")
+            .argument(&reason)
+            .original_result()
+    }
+
+    /// Cloture d'un appel (DAO V2 seulement, apres execution ou rejet de la proposition). 
+    /// approved = true: ban leve et compteur d'infractions remis a zero. 
+    pub fn resolve_ban_appeal<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        sc: Arg0,
+        approved: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resolveBanAppeal")
+            .argument(&sc)
+            .argument(&approved)
+            .original_result()
+    }
+
+    /// Clot un appel reste ouvert sans decision (owner ou DAO), ex: resolveBanAppeal 
+    /// en echec cote DAO (ban_appeal_resolution_failed). Le ban reste inchange et le 
+    /// membre peut faire un nouvel appel. 
+    pub fn clear_ban_appeal<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearBanAppeal")
+            .argument(&sc)
+            .original_result()
+    }
+
+    /// Configure la caution EGLD exigee pour un appel (owner ou DAO, 0 = appels desactives) 
+    pub fn set_appeal_bond<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAppealBond")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn withdraw<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdraw")
+            .argument(&amount)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Retire un token de SC0 (frais d'entree ESDT...) sans toucher aux montants 
+    /// circulants des cercles ni au pool de recompenses 
+    pub fn withdraw_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        amount: Arg1,
+        to: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawToken")
+            .argument(&token)
+            .argument(&amount)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Alimente SC0 en token de circulation (ex: cercle ESDT sans frais d'entree) 
+    pub fn fund_circulation(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fundCirculation")
+            .original_result()
+    }
+
+    /// Recupere les fonds d'un SC peripherique bloque vers SC0 
+    /// Utilisable uniquement par l'owner en cas de cycle bloque 
+    pub fn recover_funds_from_peripheral<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        peripheral_sc: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("recoverFundsFromPeripheral")
+            .argument(&peripheral_sc)
+            .original_result()
+    }
+
+    /// Rejoindre le cercle en payant les frais d'entree 
+    /// SC0 deploie un nouveau smart contract pour le membre 
+    /// `ring_id` choisit le cercle a rejoindre (cercle principal par defaut) 
+    /// Les frais sont payes dans le token de circulation du cercle (EGLD ou ESDT) 
+    pub fn join_circle<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("joinCircle")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Demarre le cycle quotidien - envoie le circulation_amount au premier SC 
+    /// Le caller recoit un bonus XCIRCLEX si le cycle se termine avec succes 
+    /// Chaque cercle a son propre cycle quotidien, independant des autres 
+    pub fn start_daily_cycle<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("startDailyCycle")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Pre-signe pour participer au cycle (peut etre fait a l'avance) 
+    /// Le transfert s'executera automatiquement quand c'est le tour du membre 
+    pub fn pre_sign<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        on_behalf_of: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("preSign")
+            .argument(&on_behalf_of)
+            .original_result()
+    }
+
+    /// Active l'auto-sign permanent (pre-signature automatique pour tous les cycles futurs) 
+    /// Peut etre desactive a tout moment avec disableAutoSign 
+    pub fn enable_auto_sign<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        on_behalf_of: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enableAutoSign")
+            .argument(&on_behalf_of)
+            .original_result()
+    }
+
+    /// Active l'auto-sign pour les N prochains cycles 
+    /// @param num_cycles: nombre de cycles a pre-signer (1-365) 
+    pub fn enable_auto_sign_for_cycles<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        num_cycles: Arg0,
+        on_behalf_of: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enableAutoSignForCycles")
+            .argument(&num_cycles)
+            .argument(&on_behalf_of)
+            .original_result()
+    }
+
+    /// Desactive l'auto-sign (permanent et limite) 
+    pub fn disable_auto_sign<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        on_behalf_of: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("disableAutoSign")
+            .argument(&on_behalf_of)
+            .original_result()
+    }
+
+    /// Traite les transferts en attente pour les membres qui ont pre-signe 
+    /// Peut etre appele par n'importe qui (permissionless) 
+    pub fn process_next_transfer<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("processNextTransfer")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Traite TOUS les transferts en attente en une seule transaction 
+    /// Boucle sur tous les SC qui ont pre-signe (manuellement ou auto-sign) et execute leurs transferts 
+    /// Retourne le nombre de transferts effectues 
+    pub fn process_all_pending_transfers<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("processAllPendingTransfers")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Avance le reglement du dernier cycle complete (synchro des cycles vers le NFT) 
+    /// Peut etre appele par n'importe qui (permissionless), par lots de max_items SC 
+    /// Retourne le nombre de SC restant a regler 
+    pub fn settle_cycle<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        max_items: Arg0,
+        ring_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("settleCycle")
+            .argument(&max_items)
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Signe et transfere au prochain SC (version classique - doit etre son tour) 
+    pub fn sign_and_forward<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        on_behalf_of: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("signAndForward")
+            .argument(&on_behalf_of)
+            .original_result()
+    }
+
+    /// Autorise un delegue a agir pour le SC du caller jusqu'a expires_epoch (exclu) 
+    /// Remplace la delegation precedente. Le SC peripherique accepte aussi ce delegue 
+    pub fn set_delegate<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<DelegateScope>,
+    >(
+        self,
+        delegate: Arg0,
+        expires_epoch: Arg1,
+        scope: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDelegate")
+            .argument(&delegate)
+            .argument(&expires_epoch)
+            .argument(&scope)
+            .original_result()
+    }
+
+    /// Revoque la delegation du SC du caller 
+    pub fn revoke_delegate(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeDelegate")
+            .original_result()
+    }
+
+    pub fn set_inactive<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        on_behalf_of: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setInactive")
+            .argument(&on_behalf_of)
+            .original_result()
+    }
+
+    pub fn set_active<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        on_behalf_of: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setActive")
+            .argument(&on_behalf_of)
+            .original_result()
+    }
+
+    /// Quitte le cercle (hors cycle en cours du cercle du membre): 
+    /// - recompenses en attente versees ou rendues au pool selon ExitPolicy 
+    ///   (versement immediat, hors fenetre de reclamation) 
+    /// - solde du SC peripherique en token de circulation renvoye au membre 
+    /// - remboursement degressif des frais d'entree, dans la limite du solde libre de SC0 
+    /// Le SC est retire et reutilise si le membre revient via joinCircle 
+    pub fn leave_circle(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("leaveCircle")
+            .original_result()
+    }
+
+    /// Propose de transferer l'adhesion (SC, index pionnier, depots, historique) 
+    /// vers un nouveau wallet, qui doit accepter avec acceptMembershipTransfer 
+    /// Remplace la proposition precedente 
+    pub fn propose_membership_transfer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeMembershipTransfer")
+            .argument(&new_member)
+            .original_result()
+    }
+
+    /// Annule la proposition de transfert d'adhesion en cours 
+    pub fn cancel_membership_transfer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelMembershipTransfer")
+            .original_result()
+    }
+
+    /// Accepte l'adhesion proposee par old_member (appele par le nouveau wallet) 
+    /// Le SC peripherique change d'owner (il doit etre a jour, voir upgradePeripherals), 
+    /// la delegation est revoquee et le contrat NFT reporte le membre 
+    pub fn accept_membership_transfer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        old_member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptMembershipTransfer")
+            .argument(&old_member)
+            .original_result()
+    }
+
+    /// Reclamer les recompenses XCIRCLEX accumulees (pendant une fenetre de reclamation) 
+    pub fn claim_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRewards")
+            .original_result()
+    }
+
+    pub fn get_circle_info<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue6<usize, usize, BigUint<Env::Api>, BigUint<Env::Api>, u64, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCircleInfo")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    pub fn get_my_contract<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMyContract")
+            .argument(&member)
+            .original_result()
+    }
+
+    pub fn is_member<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        addr: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isMember")
+            .argument(&addr)
+            .original_result()
+    }
+
+    pub fn is_active<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isActive")
+            .argument(&member)
+            .original_result()
+    }
+
+    pub fn is_my_turn<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isMyTurn")
+            .argument(&member)
+            .original_result()
+    }
+
+    pub fn get_active_contracts_view<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActiveContracts")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Retourne la strategie de rotation active 
+    pub fn get_rotation_strategy<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RotationStrategy> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRotationStrategy")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Retourne l'etat du reglement: (en attente, epoch, SC regles, total SC) 
+    pub fn get_settlement_info<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<bool, u64, usize, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSettlementInfo")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Nombre de SC restant a regler pour le dernier cycle complete 
+    pub fn get_settlement_remaining<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSettlementRemaining")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Retourne l'ordre de passage prevu pour l'epoch actuel 
+    /// (premier = recoit de SC0, dernier = ferme le cercle) 
+    pub fn get_rotation_order_view<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRotationOrder")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    pub fn get_all_contracts(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllContracts")
+            .original_result()
+    }
+
+    pub fn get_peripheral_template(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPeripheralTemplate")
+            .original_result()
+    }
+
+    /// Retourne (version du template, version du code deployee sur le SC) 
+    pub fn get_peripheral_code_version<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPeripheralCodeVersion")
+            .argument(&sc)
+            .original_result()
+    }
+
+    /// SC peripheriques [start, start + count) (1-based) dont le code est plus ancien que le template 
+    pub fn get_outdated_peripherals<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        start: Arg0,
+        count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOutdatedPeripherals")
+            .argument(&start)
+            .argument(&count)
+            .original_result()
+    }
+
+    pub fn get_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOwner")
+            .original_result()
+    }
+
+    pub fn get_is_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    pub fn get_contract_balance(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractBalance")
+            .original_result()
+    }
+
+    pub fn get_cycle_holder<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCycleHolder")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Historique du cycle d'un epoch: (enregistrement, passages dans l'ordre) 
+    pub fn get_cycle_record<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        epoch: Arg0,
+        ring_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue2<CycleRecord<Env::Api>, ManagedVec<Env::Api, CycleHop<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCycleRecord")
+            .argument(&epoch)
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Historique des cycles des epochs [from_epoch, from_epoch + count) encore conserves 
+    pub fn get_cycle_history<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        from_epoch: Arg0,
+        count: Arg1,
+        ring_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, CycleRecord<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCycleHistory")
+            .argument(&from_epoch)
+            .argument(&count)
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Retourne (epochs conserves, plus ancien epoch conserve) pour un cercle 
+    pub fn get_cycle_history_info<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCycleHistoryInfo")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Retourne (delai par tour, sauts par infraction, debut du tour, skipHolder possible a partir de) 
+    /// Le dernier champ vaut 0 hors cycle ou si le saut de tour est desactive 
+    pub fn get_hop_info<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<u64, u64, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHopInfo")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    pub fn get_skipped_hops<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSkippedHops")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    pub fn get_cycle_day<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCycleDay")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    pub fn get_current_day_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentDay")
+            .original_result()
+    }
+
+    pub fn get_cycle_epoch<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCycleEpoch")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    pub fn get_cycles_completed<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCyclesCompleted")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    pub fn get_cycles_failed<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCyclesFailed")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    pub fn get_cycle_stats<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<u64, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCycleStats")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Nombre total de cercles, cercle principal inclus 
+    pub fn get_ring_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRingCount")
+            .original_result()
+    }
+
+    /// Retourne la configuration et l'etat d'un cercle: 
+    /// (nom, frais d'entree, montant circulant, taille max, SC actifs, cycles reussis) 
+    pub fn get_ring_info<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue6<ManagedBuffer<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, u64, usize, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRingInfo")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    pub fn get_circulation_policy_view<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, CirculationPolicy<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCirculationPolicy")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Montant circulant du prochain cycle d'un cercle, calcule avec les SC actifs 
+    /// et la tresorerie actuels (le montant du cycle en cours ne change pas) 
+    pub fn get_next_circulation_amount<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNextCirculationAmount")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Token de circulation d'un cercle (EGLD par defaut) 
+    pub fn get_circulation_token_view<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCirculationToken")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Retourne (recompenses en attente, remboursement des frais, solde du SC) 
+    /// qu'un membre recevrait en quittant le cercle maintenant 
+    pub fn get_exit_quote<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExitQuote")
+            .argument(&member)
+            .original_result()
+    }
+
+    pub fn get_exit_policy_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ExitPolicy> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExitPolicy")
+            .original_result()
+    }
+
+    /// Nouveau wallet propose pour le SC d'un membre (transfert d'adhesion en attente) 
+    pub fn get_pending_membership_transfer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingMembershipTransfer")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// SC retire d'un ancien membre (reutilise s'il revient) 
+    pub fn get_retired_contract<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRetiredContract")
+            .argument(&member)
+            .original_result()
+    }
+
+    pub fn is_contract_retired<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isContractRetired")
+            .argument(&sc)
+            .original_result()
+    }
+
+    /// Cercle auquel appartient un membre 
+    pub fn get_member_ring<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMemberRing")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Verifie si un membre a pre-signe pour le cycle actuel (manuel ou auto-sign) 
+    pub fn has_pre_signed<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasPreSigned")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Verifie si un membre a deja signe/transfere dans ce cycle 
+    pub fn has_signed_this_cycle<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasSignedThisCycle")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne le statut complet de l'auto-sign pour un membre 
+    /// (is_auto_sign_permanent, auto_sign_until_epoch, remaining_cycles) 
+    pub fn get_auto_sign_status<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<bool, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAutoSignStatus")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Verifie si l'auto-sign permanent est active pour un membre 
+    pub fn is_auto_sign_enabled<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isAutoSignEnabled")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne l'epoch jusqu'a laquelle l'auto-sign est actif (0 si desactive) 
+    pub fn get_auto_sign_until<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAutoSignUntil")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne le nombre de cycles restants pour l'auto-sign limite 
+    pub fn get_auto_sign_remaining_cycles<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAutoSignRemainingCycles")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne le proprietaire d'un contrat peripherique 
+    pub fn get_contract_owner<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractOwner")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    /// Retourne tous les contrats avec leurs proprietaires 
+    pub fn get_all_contracts_with_owners(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, ManagedAddress<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllContractsWithOwners")
+            .original_result()
+    }
+
+    /// Retourne la liste des membres qui ont pre-signe pour le cycle actuel 
+    pub fn get_pre_signed_members<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPreSignedMembers")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Retourne les statistiques d'un SC peripherique (cycles reussis, echoues, ban until, is_banned, infraction_count) 
+    pub fn get_sc_stats<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue5<u64, u64, u64, bool, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getScStats")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    /// Retourne (proposition DAO de l'appel en cours ou 0, caution exigee pour un appel) 
+    pub fn get_appeal_info<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAppealInfo")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    /// Retourne les informations detaillees du ban progressif pour un SC 
+    /// (infraction_count, last_infraction_timestamp, ban_until, remaining_ban_days, days_until_reset) 
+    /// Les 5 dernieres valeurs sont les seuils de FailurePolicy: (delai de grace, 
+    /// avertissements, jours de ban par infraction, jours avant reset, credit partiel) 
+    pub fn get_infraction_info<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue10<u64, u64, u64, u64, u64, u64, u64, u64, u64, bool>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInfractionInfo")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    /// Verifie si le compteur d'infractions sera remis a zero (N jours sans infraction) 
+    pub fn will_infraction_reset<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("willInfractionReset")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    /// Verifie si un SC est banni et retourne la date de fin de ban 
+    pub fn is_banned<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<bool, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isBanned")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    /// Retourne les statistiques de tous les SC avec leurs stats (incluant infraction_count) 
+    pub fn get_all_sc_stats(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue6<ManagedAddress<Env::Api>, u64, u64, u64, bool, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllScStats")
+            .original_result()
+    }
+
+    /// Retourne le token ID de recompense 
+    pub fn get_reward_token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<TokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardTokenId")
+            .original_result()
+    }
+
+    /// Retourne le pool de recompenses disponible 
+    pub fn get_rewards_pool(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardsPool")
+            .original_result()
+    }
+
+    /// Retourne la recompense par cycle (calculee avec π × 360) 
+    pub fn get_reward_per_cycle(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardPerCycle")
+            .original_result()
+    }
+
+    /// Retourne la recompense de base configuree manuellement (ancienne methode - deprecated) 
+    pub fn get_base_reward_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBaseRewardConfig")
+            .original_result()
+    }
+
+    /// Retourne les recompenses en attente pour un membre 
+    pub fn get_pending_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingRewards")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne le total des recompenses distribuees 
+    pub fn get_total_rewards_distributed(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalRewardsDistributed")
+            .original_result()
+    }
+
+    /// Retourne les informations de recompense completes 
+    pub fn get_rewards_info(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, bool>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardsInfo")
+            .original_result()
+    }
+
+    /// Verifie si c'est dimanche (jour ou on peut claim les recompenses) 
+    pub fn is_sunday_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isSunday")
+            .original_result()
+    }
+
+    /// Retourne le jour de la semaine (0=Dimanche, 1=Lundi, ..., 6=Samedi) 
+    pub fn get_day_of_week(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDayOfWeek")
+            .original_result()
+    }
+
+    /// Verifie si un membre peut claim ses recompenses maintenant 
+    /// (fenetre ouverte, montant en attente, a des recompenses) 
+    pub fn can_claim_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<bool, BigUint<Env::Api>, bool>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("canClaimRewards")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne la configuration des pourboires: (source, pourboire par transfert, 
+    /// plafond par cycle, deja verse pour le cycle actuel) 
+    pub fn get_keeper_reward_config<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<KeeperTipSource, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getKeeperRewardConfig")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Retourne les statistiques d'un keeper: 
+    /// (transferts traites, cycles echoues declenches, pourboires XCIRCLEX, pourboires EGLD) 
+    pub fn get_keeper_stats<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        keeper: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<u64, u64, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getKeeperStats")
+            .argument(&keeper)
+            .original_result()
+    }
+
+    /// Retourne la delegation d'un membre: (delegue, epoch d'expiration, portee, valide) 
+    pub fn get_delegation<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<MultiValue4<ManagedAddress<Env::Api>, u64, DelegateScope, bool>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegation")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne les membres pour lesquels une adresse peut agir (delegations non expirees) 
+    pub fn get_delegated_members<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        delegate: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDelegatedMembers")
+            .argument(&delegate)
+            .original_result()
+    }
+
+    /// Retourne le calendrier de reclamation actuel 
+    pub fn get_claim_schedule_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ClaimSchedule> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimSchedule")
+            .original_result()
+    }
+
+    /// Retourne la fenetre de reclamation en cours ou la prochaine: (debut, fin) en timestamps 
+    /// Always = (0, u64::MAX). AfterCycles sans fenetre ouverte = (0, 0): la prochaine 
+    /// s'ouvrira a la completion du prochain multiple de every_cycles cycles 
+    pub fn get_next_claim_window(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNextClaimWindow")
+            .original_result()
+    }
+
+    /// Retourne la recompense actuelle pour un cycle (avec halving applique) 
+    pub fn get_current_cycle_reward(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentCycleReward")
+            .original_result()
+    }
+
+    /// Retourne l'ere actuelle (nombre de halvings effectues) 
+    pub fn get_current_era_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentEra")
+            .original_result()
+    }
+
+    /// Retourne le prochain cycle qui declenchera un bonus cercle complet 
+    pub fn get_next_circle_complete_cycle(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNextCircleCompleteCycle")
+            .original_result()
+    }
+
+    /// Retourne les cycles restants avant le prochain halving 
+    pub fn get_cycles_until_next_halving(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCyclesUntilNextHalving")
+            .original_result()
+    }
+
+    /// Retourne les informations completes du systeme π × 360 
+    /// (current_reward, current_era, next_circle_complete, cycles_until_halving, pi_bonus_amount) 
+    pub fn get_option_f_info(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue5<BigUint<Env::Api>, u64, u64, u64, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOptionFInfo")
+            .original_result()
+    }
+
+    /// Verifie si le prochain cycle complete sera un "cercle complet" (360, 720, ...) 
+    pub fn is_next_cycle_circle_complete(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isNextCycleCircleComplete")
+            .original_result()
+    }
+
+    /// Retourne la table des recompenses par ere (pour affichage) 
+    /// Retourne les 5 prochaines eres avec leurs recompenses 
+    pub fn get_reward_schedule(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<u64, u64, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardSchedule")
+            .original_result()
+    }
+
+    /// Retourne le total de tokens brules 
+    pub fn get_total_burned(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalBurned")
+            .original_result()
+    }
+
+    /// Retourne le montant de burn par SC actif 
+    pub fn get_burn_per_sc(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBurnPerSc")
+            .original_result()
+    }
+
+    /// Retourne les statistiques de burn completes 
+    /// (total_burned, burn_per_sc, estimated_next_burn basé sur le nombre de SC actifs) 
+    pub fn get_burn_stats<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBurnStats")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Retourne le total distribue au treasury (3.14%) 
+    pub fn get_total_distributed_treasury(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalDistributedTreasury")
+            .original_result()
+    }
+
+    /// Retourne le total distribue au DAO (30% du restant) 
+    pub fn get_total_distributed_dao(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalDistributedDao")
+            .original_result()
+    }
+
+    /// Retourne les EGLD en attente pour la liquidite (70% du restant) 
+    pub fn get_pending_liquidity_egld(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingLiquidityEgld")
+            .original_result()
+    }
+
+    /// Retourne tous les montants en attente pour la liquidite 
+    /// (pending_egld, pending_wegld_swap, pending_wegld_lp, pending_xcirclex, pending_lp, processing_in_progress) 
+    pub fn get_pending_liquidity_info(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue6<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, bool>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingLiquidityInfo")
+            .original_result()
+    }
+
+    /// Table de distribution active. Sans table configuree, retourne la 
+    /// repartition historique arrondie en BPS du total (3.14% / 67.80% / 29.06%) 
+    pub fn get_distribution_table(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, DistributionShare<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDistributionTable")
+            .original_result()
+    }
+
+    /// Historique des changements de la table de distribution (du plus ancien au plus recent) 
+    pub fn get_distribution_table_history<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from: Arg0,
+        count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, DistributionTableChange<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDistributionTableHistory")
+            .argument(&from)
+            .argument(&count)
+            .original_result()
+    }
+
+    /// Nombre de changements de la table de distribution 
+    pub fn get_distribution_table_history_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDistributionTableHistoryCount")
+            .original_result()
+    }
+
+    /// Retourne le total distribue au contrat de staking 
+    pub fn get_total_distributed_staking(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalDistributedStaking")
+            .original_result()
+    }
+
+    /// Retourne les EGLD en attente pour le rachat + burn de XCIRCLEX 
+    pub fn get_pending_burn_swap_egld(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingBurnSwapEgld")
+            .original_result()
+    }
+
+    /// WEGLD wrappes par processBurnSwap en attente du swap 
+    pub fn get_pending_burn_swap_wegld(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingBurnSwapWegld")
+            .original_result()
+    }
+
+    /// Etape du pipeline de liquidite et nombre d'echecs consecutifs de cette etape 
+    pub fn get_liquidity_stage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<LiquidityStage, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidityStage")
+            .original_result()
+    }
+
+    /// Locks de liquidite detenue par le protocole, a partir de l'index from (0-based) 
+    pub fn get_protocol_locks<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from: Arg0,
+        count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ProtocolLock<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolLocks")
+            .argument(&from)
+            .argument(&count)
+            .original_result()
+    }
+
+    /// Lock protocolaire par ID du LP Locker 
+    pub fn get_protocol_lock_view<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        lock_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProtocolLock<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolLock")
+            .argument(&lock_id)
+            .original_result()
+    }
+
+    /// Totaux de la liquidite detenue par le protocole 
+    /// (lp_tokens, egld_contributed, xcirclex_contributed, egld_recovered, xcirclex_recovered, nb_locks) 
+    pub fn get_protocol_liquidity(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue6<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolLiquidity")
+            .original_result()
+    }
+
+    /// Valeur actuelle des LP tokens du protocole dans la paire: (XCIRCLEX, WEGLD) 
+    pub fn get_protocol_liquidity_value(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolLiquidityValue")
+            .original_result()
+    }
+
+    /// LP tokens debloques et WEGLD recuperes en attente de la prochaine etape DAO 
+    pub fn get_pending_protocol_withdrawal(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<BigUint<Env::Api>, BigUint<Env::Api>, bool>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingProtocolWithdrawal")
+            .original_result()
+    }
+
+    pub fn get_price_guard_policy_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PriceGuardPolicy<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPriceGuardPolicy")
+            .original_result()
+    }
+
+    /// Moyenne mobile du prix XCIRCLEX par WEGLD (x 10^18) et timestamp de la derniere observation 
+    pub fn get_twap_price(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTwapPrice")
+            .original_result()
+    }
+
+    /// Verifie si la distribution est activee 
+    pub fn is_distribution_enabled(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isDistributionEnabled")
+            .original_result()
+    }
+
+    /// Retourne les informations du bonus starter 
+    /// (percentage, current_starter, potential_bonus, total_distributed) 
+    pub fn get_starter_bonus_info<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<u64, OptionalValue<ManagedAddress<Env::Api>>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStarterBonusInfo")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Retourne le pourcentage de bonus starter configure 
+    pub fn get_starter_bonus_percentage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStarterBonusPercentage")
+            .original_result()
+    }
+
+    /// Retourne l'adresse de celui qui a demarre le cycle actuel 
+    pub fn get_cycle_starter<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCycleStarter")
+            .argument(&ring_id)
+            .original_result()
+    }
+
+    /// Retourne le total des bonus starter distribues 
+    pub fn get_total_starter_bonus_distributed(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalStarterBonusDistributed")
+            .original_result()
+    }
+
+    /// Retourne l'adresse du contrat NFT configure pour la synchronisation 
+    pub fn get_nft_contract(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNftContract")
+            .original_result()
+    }
+
+    /// Verifie si un membre est un pionnier (parmi les 360 premiers) 
+    pub fn is_pioneer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPioneer")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne l'index du SC d'un membre (0 si pas membre) 
+    pub fn get_peripheral_index<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPeripheralIndex")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne les infos pionnier pour un membre 
+    /// (is_pioneer, index, bonus_percentage, remaining_pioneer_slots) 
+    pub fn get_pioneer_info<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<bool, u64, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPioneerInfo")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne le nombre de places pionniers restantes 
+    pub fn get_remaining_pioneer_slots(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRemainingPioneerSlots")
+            .original_result()
+    }
+
+    /// Retourne les infos de bonus de depot pour un membre 
+    /// (total_deposits, bonus_percent, bonus_bps, max_bonus_percent) 
+    pub fn get_deposit_bonus_info<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<BigUint<Env::Api>, u64, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositBonusInfo")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne le total depose par un membre dans un token ESDT 
+    pub fn get_member_token_deposits<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMemberTokenDeposits")
+            .argument(&member)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Retourne le total des EGLD deposes par un membre 
+    pub fn get_member_egld_deposits<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMemberEgldDeposits")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne le bonus de depot en pourcentage (1-360%) 
+    pub fn get_deposit_bonus_percent<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositBonusPercent")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Retourne le total global des EGLD deposes 
+    pub fn get_total_egld_deposits(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalEgldDeposits")
+            .original_result()
+    }
+
+    /// Retourne les bonus totaux pour un membre (pioneer + deposit) 
+    /// (is_pioneer, pioneer_bonus_bps, deposit_bonus_bps, total_bonus_bps) 
+    pub fn get_all_bonuses<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        member: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<bool, u64, u64, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllBonuses")
+            .argument(&member)
+            .original_result()
+    }
+
+    /// Verifie combien de transferts peuvent etre traites automatiquement 
+    pub fn get_pending_auto_transfers<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        ring_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingAutoTransfers")
+            .argument(&ring_id)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum CirculationPolicy<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Fixed,
+    TreasuryShare {
+        bps: u64,
+        cap: BigUint<Api>,
+    },
+    PerMember {
+        amount_per_member: BigUint<Api>,
+        cap: BigUint<Api>,
+    },
+    Tiers {
+        tiers: ManagedVec<Api, CirculationTier<Api>>,
+        cap: BigUint<Api>,
+    },
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug)]
+pub struct CirculationTier<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub min_members: u64,
+    pub amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug)]
+pub struct DistributionShare<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub target: DistributionTarget<Api>,
+    pub bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug)]
+pub enum DistributionTarget<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Treasury,
+    Liquidity,
+    Dao,
+    StakingRewards,
+    BurnSwap,
+    Address(ManagedAddress<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum LiquidityStage {
+    Idle,
+    Wrapping,
+    Wrapped,
+    Swapping,
+    Swapped,
+    AddingLiquidity,
+    LiquidityAdded,
+    Locking,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum RotationStrategy {
+    InsertionOrder,
+    Reverse,
+    EpochShuffle,
+    ReputationWeighted,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum KeeperTipSource {
+    Disabled,
+    RewardToken,
+    Egld,
+}
+
+#[rustfmt::skip]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum ClaimSchedule {
+    Always,
+    Weekdays {
+        days_mask: u8,
+    },
+    EveryNDays {
+        interval_days: u64,
+        anchor_day: u64,
+    },
+    AfterCycles {
+        every_cycles: u64,
+        window_seconds: u64,
+    },
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum DelegateScope {
+    SignOnly,
+    SignAndStatus,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct CycleRecord<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub day: u64,
+    pub starter: ManagedAddress<Api>,
+    pub started_at: u64,
+    pub status: CycleStatus,
+    pub ended_at: u64,
+    pub failed_sc: Option<ManagedAddress<Api>>,
+    pub rewards_paid: BigUint<Api>,
+    pub burned: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum CycleStatus {
+    Running,
+    Completed,
+    Failed,
+    Reset,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug)]
+pub struct CycleHop<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub from: ManagedAddress<Api>,
+    pub to: ManagedAddress<Api>,
+    pub timestamp: u64,
+    pub skipped: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ExitPolicy {
+    pub pay_pending_rewards: bool,
+    pub fee_refund_bps: u64,
+    pub refund_period_days: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct DistributionTableChange<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub timestamp: u64,
+    pub changed_by: ManagedAddress<Api>,
+    pub shares: ManagedVec<Api, DistributionShare<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ProtocolLock<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub lock_id: u64,
+    pub lp_amount: BigUint<Api>,
+    pub egld_contributed: BigUint<Api>,
+    pub xcirclex_contributed: BigUint<Api>,
+    pub locked_at: u64,
+    pub unlock_at: u64,
+    pub status: ProtocolLockStatus,
+    pub egld_recovered: BigUint<Api>,
+    pub xcirclex_recovered: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum ProtocolLockStatus {
+    Locked,
+    Unlocked,
+    Removed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct PriceGuardPolicy<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub max_deviation_bps: u64,
+    pub twap_window_seconds: u64,
+    pub oracle: Option<ManagedAddress<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct FailurePolicy {
+    pub grace_period_seconds: u64,
+    pub warning_infractions: u64,
+    pub ban_days_per_infraction: u64,
+    pub infraction_reset_days: u64,
+    pub credit_signed_members: bool,
+}
@@ -3,6 +3,10 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use multiversx_sc::storage::StorageKey;
+
+pub mod circle_of_life_center_proxy;
+
 /// Circle of Life Center Contract (SC0) - Version 3
 ///
 /// Ce smart contract est le centre du Cercle de Vie.
@@ -48,12 +52,335 @@ const TREASURY_PERCENTAGE_BPS: u64 = 314;
 const LIQUIDITY_PERCENTAGE_BPS: u64 = 7000;
 /// Pourcentage pour le DAO (du restant après treasury) - 3000 BPS = 30%
 const DAO_PERCENTAGE_BPS: u64 = 3000;
+/// Nombre maximum de parts dans la table de distribution
+const MAX_DISTRIBUTION_SHARES: usize = 10;
+/// Duree des locks de LP tokens (jours)
+const LP_LOCK_DURATION_DAYS: u64 = 365;
 /// Slippage par défaut pour xExchange - 100 BPS = 1%
 const DEFAULT_SLIPPAGE_BPS: u64 = 100;
 /// Slippage minimum - 50 BPS = 0.5%
 const MIN_SLIPPAGE_BPS: u64 = 50;
 /// Slippage maximum - 1000 BPS = 10%
 const MAX_SLIPPAGE_BPS: u64 = 1000;
+/// Precision des prix de la paire XCIRCLEX/WEGLD (XCIRCLEX par WEGLD x 10^18)
+const PRICE_PRECISION: u64 = 1_000_000_000_000_000_000;
+/// Ecart maximum par defaut entre prix spot et prix de reference - 500 BPS = 5%
+const DEFAULT_MAX_PRICE_DEVIATION_BPS: u64 = 500;
+/// Ecart maximum configurable - 5000 BPS = 50%
+const MAX_PRICE_DEVIATION_BPS: u64 = 5_000;
+/// Fenetre par defaut de la moyenne mobile du prix (1h)
+const DEFAULT_TWAP_WINDOW_SECONDS: u64 = 3_600;
+/// Fenetre maximum de la moyenne mobile du prix (7 jours)
+const MAX_TWAP_WINDOW_SECONDS: u64 = 604_800;
+/// Duree du ban par infraction par defaut (jours)
+const DEFAULT_BAN_DAYS_PER_INFRACTION: u64 = 30;
+/// Jours sans infraction avant remise a zero du compteur par defaut
+const DEFAULT_INFRACTION_RESET_DAYS: u64 = 360;
+/// Delai de grace maximum apres la fin du jour du cycle
+const MAX_GRACE_PERIOD_SECONDS: u64 = 86_400;
+/// Nombre de SC regles directement dans la transaction qui complete le cycle (par defaut)
+const DEFAULT_AUTO_SETTLEMENT_ITEMS: usize = 10;
+/// Fenetre de reclamation par defaut: dimanche uniquement (bit 0)
+const DEFAULT_CLAIM_DAYS_MASK: u8 = 0b0000_0001;
+/// Masque des 7 jours de la semaine
+const ALL_DAYS_MASK: u8 = 0b0111_1111;
+const SECONDS_PER_DAY: u64 = 86_400;
+/// Bornes du calendrier de reclamation (EveryNDays / AfterCycles)
+const MAX_CLAIM_INTERVAL_DAYS: u64 = 365;
+const MAX_CLAIM_WINDOW_SECONDS: u64 = 30 * SECONDS_PER_DAY;
+/// Cercle principal: toujours present, garde les cles de stockage historiques
+const DEFAULT_RING_ID: u64 = 0;
+/// Gas alloue a l'upgrade de chaque SC peripherique (upgradePeripherals)
+const PERIPHERAL_UPGRADE_GAS: u64 = 10_000_000;
+/// Historiques de cycle elagues au plus a chaque demarrage de cycle
+const MAX_CYCLE_RECORDS_PRUNED: usize = 2;
+/// Sauts de tour (skipHolder) comptant pour une infraction par defaut
+const DEFAULT_SKIPS_PER_INFRACTION: u64 = 1;
+
+/// Strategie d'ordre de rotation des SC actifs pour un cycle
+/// L'ordre est fixe pour tout un epoch (meme graine, memes membres)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum RotationStrategy {
+    InsertionOrder,     // Ordre d'entree dans l'index des SC actifs: adhesion, un SC reactive passe en fin de liste
+    Reverse,            // Ordre InsertionOrder inverse
+    EpochShuffle,       // Melange aleatoire, graine tiree a chaque nouvel epoch
+    ReputationWeighted, // Melange pondere par sc_cycles_completed (les plus fiables ferment le cercle)
+}
+
+/// Palier de CirculationPolicy::Tiers: montant applique a partir de min_members SC actifs
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug)]
+pub struct CirculationTier<M: ManagedTypeApi> {
+    pub min_members: u64,
+    pub amount: BigUint<M>,
+}
+
+/// Calcul du montant circulant d'un cercle, applique au demarrage de chaque cycle
+/// cap: plafond du montant calcule (0 = pas de plafond)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum CirculationPolicy<M: ManagedTypeApi> {
+    /// Montant fixe par setCirculationAmount - comportement historique
+    Fixed,
+    /// Part (BPS) de la tresorerie de SC0 disponible pour le cercle
+    TreasuryShare { bps: u64, cap: BigUint<M> },
+    /// Montant par SC actif de la rotation
+    PerMember { amount_per_member: BigUint<M>, cap: BigUint<M> },
+    /// Paliers par nombre de SC actifs, tries par min_members croissant
+    Tiers { tiers: ManagedVec<M, CirculationTier<M>>, cap: BigUint<M> },
+}
+
+/// Destination d'une part de la distribution des EGLD (frais d'entree et depots)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug)]
+pub enum DistributionTarget<M: ManagedTypeApi> {
+    /// Reste dans SC0 (tresorerie, montant circulant)
+    Treasury,
+    /// Accumule pour le traitement de liquidite xExchange
+    Liquidity,
+    /// Envoye au contrat DAO V2 (reste dans SC0 si non configure)
+    Dao,
+    /// Envoye au contrat de staking (reste dans SC0 si non configure)
+    StakingRewards,
+    /// Accumule pour processBurnSwap (rachat puis burn de XCIRCLEX)
+    BurnSwap,
+    /// Envoye a une adresse quelconque
+    Address(ManagedAddress<M>),
+}
+
+/// Part de la table de distribution, en BPS du montant distribue
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug)]
+pub struct DistributionShare<M: ManagedTypeApi> {
+    pub target: DistributionTarget<M>,
+    pub bps: u64,
+}
+
+/// Entree de l'historique des changements de la table de distribution
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct DistributionTableChange<M: ManagedTypeApi> {
+    pub timestamp: u64,
+    pub changed_by: ManagedAddress<M>,
+    pub shares: ManagedVec<M, DistributionShare<M>>,
+}
+
+/// Intervalle de participation d'un SC pas encore comptabilise: epochs [from_epoch, until_epoch)
+/// until_epoch = u64::MAX tant que le SC reste actif
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct RewardCheckpoint {
+    pub from_epoch: u64,
+    pub until_epoch: u64,
+}
+
+/// Portee d'une delegation de signature
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum DelegateScope {
+    /// preSign, signAndForward, auto-sign
+    SignOnly,
+    /// SignOnly + setActive/setInactive
+    SignAndStatus,
+}
+
+/// Delegation accordee par un membre a un relayer/bot pour son SC
+/// Valide tant que l'epoch de la blockchain est < expires_epoch
+/// (lue aussi par le SC peripherique: ne pas changer l'encodage)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Delegation<M: ManagedTypeApi> {
+    pub delegate: ManagedAddress<M>,
+    pub expires_epoch: u64,
+    pub scope: DelegateScope,
+}
+
+/// Politique appliquee par failCycle (delai de grace, avertissements, bans)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct FailurePolicy {
+    /// Delai apres la fin du jour du cycle avant de pouvoir appeler failCycle
+    pub grace_period_seconds: u64,
+    /// Nombre de premieres infractions sanctionnees par un simple avertissement (sans ban)
+    pub warning_infractions: u64,
+    /// Duree du ban par infraction au-dela des avertissements
+    pub ban_days_per_infraction: u64,
+    /// Jours sans infraction apres lesquels le compteur est remis a zero
+    pub infraction_reset_days: u64,
+    /// Crediter un cycle reussi aux SC qui avaient signe avant le blocage
+    pub credit_signed_members: bool,
+}
+
+/// Politique appliquee par leaveCircle (recompenses en attente, remboursement des frais)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ExitPolicy {
+    /// Verser les recompenses en attente au depart (sinon elles retournent au pool)
+    pub pay_pending_rewards: bool,
+    /// Part des frais d'entree remboursee a l'entree du membre, en BPS
+    pub fee_refund_bps: u64,
+    /// Duree sur laquelle le remboursement decroit lineairement jusqu'a zero
+    pub refund_period_days: u64,
+}
+
+/// Protection des swaps xExchange de SC0 contre la manipulation du prix spot
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct PriceGuardPolicy<M: ManagedTypeApi> {
+    /// Ecart maximum entre prix spot et prix de reference, en BPS (0 = pas de controle)
+    pub max_deviation_bps: u64,
+    /// Fenetre de la moyenne mobile du prix maintenue par SC0
+    pub twap_window_seconds: u64,
+    /// Oracle de prix (view getPrice) utilise comme reference a la place de la moyenne mobile
+    pub oracle: Option<ManagedAddress<M>>,
+}
+
+/// Source des pourboires verses aux keepers
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum KeeperTipSource {
+    /// Pas de pourboire (defaut)
+    Disabled,
+    /// XCIRCLEX preleve sur rewards_pool
+    RewardToken,
+    /// EGLD preleve sur le treasury de SC0 (hors montant circulant et liquidite en attente)
+    Egld,
+}
+
+/// Statistiques d'un keeper
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct KeeperStats<M: ManagedTypeApi> {
+    pub transfers_processed: u64,
+    pub cycles_failed: u64,
+    pub tips_reward_token: BigUint<M>,
+    pub tips_egld: BigUint<M>,
+}
+
+/// Calendrier des fenetres de reclamation des recompenses (defaut: dimanche)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum ClaimSchedule {
+    /// Reclamation possible a tout moment
+    Always,
+    /// Jours de la semaine (UTC) autorises: bit 0 = dimanche, ..., bit 6 = samedi
+    Weekdays { days_mask: u8 },
+    /// Un jour sur N, a partir du jour anchor_day (jours depuis l'epoch Unix)
+    EveryNDays { interval_days: u64, anchor_day: u64 },
+    /// Fenetre de window_seconds ouverte apres chaque multiple de every_cycles cycles reussis
+    AfterCycles { every_cycles: u64, window_seconds: u64 },
+}
+
+/// Index cumulatifs a l'ouverture d'un epoch (recompense de base par SC et cycles reussis)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct EpochRewardIndex<M: ManagedTypeApi> {
+    pub reward_per_share: BigUint<M>,
+    pub completed_cycles: u64,
+}
+
+/// Issue d'un cycle (historique par epoch)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum CycleStatus {
+    Running,
+    Completed,
+    Failed,
+    /// Interrompu par resetCycle
+    Reset,
+}
+
+/// Historique d'un cycle d'un cercle (voir getCycleRecord)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct CycleRecord<M: ManagedTypeApi> {
+    pub day: u64,
+    pub starter: ManagedAddress<M>,
+    pub started_at: u64,
+    pub status: CycleStatus,
+    /// Timestamp de fin (0 tant que le cycle est en cours)
+    pub ended_at: u64,
+    /// SC qui a bloque le cycle (failCycle)
+    pub failed_sc: Option<ManagedAddress<M>>,
+    /// XCIRCLEX retires du pool pour les recompenses du cycle (base et bonus)
+    pub rewards_paid: BigUint<M>,
+    pub burned: BigUint<M>,
+}
+
+/// Passage du montant circulant d'un SC au suivant
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug)]
+pub struct CycleHop<M: ManagedTypeApi> {
+    pub from: ManagedAddress<M>,
+    pub to: ManagedAddress<M>,
+    pub timestamp: u64,
+    /// Transfert force par skipHolder (le SC n'a pas signe)
+    pub skipped: bool,
+}
+
+/// Etat d'un lock de LP tokens detenu par le protocole
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum ProtocolLockStatus {
+    /// LP tokens verrouilles dans le LP Locker
+    Locked,
+    /// LP tokens revenus dans SC0, en attente de removeProtocolLiquidity
+    Unlocked,
+    /// Liquidite retiree de la paire
+    Removed,
+}
+
+/// Lock de LP tokens cree par le pipeline de liquidite (liquidite detenue par le protocole)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct ProtocolLock<M: ManagedTypeApi> {
+    /// ID du lock dans le LP Locker
+    pub lock_id: u64,
+    pub lp_amount: BigUint<M>,
+    /// WEGLD et XCIRCLEX apportes a addLiquidity (hors excedents rendus par la paire)
+    pub egld_contributed: BigUint<M>,
+    pub xcirclex_contributed: BigUint<M>,
+    pub locked_at: u64,
+    pub unlock_at: u64,
+    pub status: ProtocolLockStatus,
+    /// WEGLD et XCIRCLEX recuperes par removeProtocolLiquidity
+    pub egld_recovered: BigUint<M>,
+    pub xcirclex_recovered: BigUint<M>,
+}
+
+/// Etape du pipeline de liquidite xExchange (voir advanceLiquidity).
+/// Les etapes "...ing" attendent le callback d'un appel async; en cas d'echec
+/// le pipeline revient a l'etape precedente et advanceLiquidity relance l'appel
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum LiquidityStage {
+    /// Rien en cours: prochaine etape = wrap des EGLD en attente
+    Idle,
+    Wrapping,
+    /// WEGLD recus: prochaine etape = swap de la moitie en XCIRCLEX
+    Wrapped,
+    Swapping,
+    /// XCIRCLEX recus: prochaine etape = addLiquidity
+    Swapped,
+    AddingLiquidity,
+    /// LP tokens recus: prochaine etape = lock dans le LP Locker
+    LiquidityAdded,
+    Locking,
+}
+
+impl LiquidityStage {
+    /// Appel async en vol, en attente de son callback
+    pub fn is_in_flight(&self) -> bool {
+        matches!(
+            self,
+            LiquidityStage::Wrapping
+                | LiquidityStage::Swapping
+                | LiquidityStage::AddingLiquidity
+                | LiquidityStage::Locking
+        )
+    }
+}
 
 #[multiversx_sc::contract]
 pub trait CircleOfLifeCenter {
@@ -68,20 +395,20 @@ pub trait CircleOfLifeCenter {
         self.owner().set(&caller);
 
         // Frais d'entree: 1 EGLD par defaut
-        self.entry_fee().set(BigUint::from(1_000_000_000_000_000_000u64));
+        self.entry_fee(DEFAULT_RING_ID).set(BigUint::from(1_000_000_000_000_000_000u64));
 
         // Montant circulant: 0.001 EGLD
-        self.circulation_amount().set(BigUint::from(1_000_000_000_000_000u64));
+        self.circulation_amount(DEFAULT_RING_ID).set(BigUint::from(1_000_000_000_000_000u64));
 
         // Initialiser le cycle
-        self.current_cycle_index().set(0usize);
-        self.cycle_day().set(0u64);
-        self.cycle_epoch().set(1u64); // Epoch commence a 1
+        self.current_cycle_index(DEFAULT_RING_ID).set(0usize);
+        self.cycle_day(DEFAULT_RING_ID).set(0u64);
+        self.cycle_epoch(DEFAULT_RING_ID).set(1u64); // Epoch commence a 1
         self.is_paused().set(false);
 
         // Initialiser les compteurs de cycles
-        self.cycles_completed().set(0u64);
-        self.cycles_failed().set(0u64);
+        self.cycles_completed(DEFAULT_RING_ID).set(0u64);
+        self.cycles_failed(DEFAULT_RING_ID).set(0u64);
     }
 
     #[upgrade]
@@ -91,22 +418,38 @@ pub trait CircleOfLifeCenter {
     // PAYABLE - Recevoir des fonds
     // ═══════════════════════════════════════════════════════════════
 
-    #[payable("EGLD")]
+    /// Depot dans le token de circulation du cercle du membre.
+    /// Seuls les depots EGLD comptent pour le bonus de depot (1 EGLD = 1%)
+    #[payable("*")]
     #[endpoint(deposit)]
     fn deposit(&self) {
         let caller = self.blockchain().get_caller();
-        let payment = self.call_value().egld().clone_value();
+        let (token, payment) = self.call_value().egld_or_single_fungible_esdt();
 
         // Verifier que le caller est membre
         require!(
             !self.member_contract(&caller).is_empty(),
             "Must be a member to deposit for bonus"
         );
+        let ring_id = self.get_sc_ring(&self.member_contract(&caller).get());
+        require!(token == self.get_circulation_token(ring_id), "Token de paiement invalide");
+
+        if !token.is_egld() {
+            self.process_esdt_distribution(&token, &payment);
+            self.member_token_deposits(&caller, &token).update(|total| *total += &payment);
+            self.member_deposit_tokens(&caller).insert(token.clone());
+            self.token_deposit_event(&caller, &token, &payment);
+            return;
+        }
 
         // === DISTRIBUTION V4 ===
         // Distribuer les EGLD: 3.14% treasury, 70% liquidite, 30% DAO
         self.process_egld_distribution(&payment);
 
+        // Comptabiliser les recompenses acquises avec l'ancien bonus de depot
+        let caller_sc = self.member_contract(&caller).get();
+        self.checkpoint_rewards(&caller_sc);
+
         // Accumuler les depots EGLD pour le bonus (1 EGLD = 1%)
         let current_deposits = self.member_egld_deposits(&caller).get();
         let new_deposits = &current_deposits + &payment;
@@ -128,10 +471,57 @@ pub trait CircleOfLifeCenter {
     // ═══════════════════════════════════════════════════════════════
 
     /// Configure l'adresse du contrat template pour les SC peripheriques
+    /// Chaque changement cree une nouvelle version de code: les SC deja deployes
+    /// deviennent obsoletes jusqu'a upgradePeripherals
     #[endpoint(setPeripheralTemplate)]
     fn set_peripheral_template(&self, template_address: ManagedAddress) {
         self.require_owner();
         self.peripheral_template().set(&template_address);
+
+        let version = self.peripheral_template_version().get() + 1;
+        self.peripheral_template_version().set(version);
+        self.peripheral_template_changed_event(&template_address, version);
+    }
+
+    /// Met a jour depuis le template actuel les SC obsoletes parmi les SC
+    /// [start, start + count) (1-based). Les SC deja a jour sont ignores.
+    /// Limite du protocole: l'upgrade depuis un template est un appel asynchrone qui
+    /// termine la transaction (pas de promesse possible), donc un seul SC est mis a jour
+    /// par appel et `count` borne la plage parcourue. Relancer avec la meme plage jusqu'a
+    /// l'evenement peripherals_up_to_date. Le SC enregistre lui-meme sa nouvelle version
+    #[endpoint(upgradePeripherals)]
+    fn upgrade_peripherals(&self, start: usize, count: usize) {
+        self.require_owner();
+        require!(start >= 1, "Index de depart invalide (1-based)");
+        require!(!self.peripheral_template().is_empty(), "Template non configure");
+
+        let version = self.peripheral_template_version().get();
+        let total = self.peripheral_contracts().len();
+        let end = core::cmp::min(start.saturating_add(count), total + 1);
+        for i in start..end {
+            let sc = self.peripheral_contracts().get(i);
+            if self.peripheral_code_version(sc.clone()).get() >= version {
+                continue;
+            }
+
+            self.peripheral_upgrade_started_event(&sc, i as u64, version);
+            self.tx()
+                .to(sc)
+                .gas(PERIPHERAL_UPGRADE_GAS)
+                .raw_upgrade()
+                .from_source(self.peripheral_template().get())
+                .code_metadata(self.peripheral_code_metadata())
+                .argument(&version)
+                .upgrade_async_call_and_exit();
+            return;
+        }
+
+        self.peripherals_up_to_date_event(start as u64, end.saturating_sub(start) as u64, version);
+    }
+
+    /// Metadonnees des SC peripheriques (deploiement et upgrade)
+    fn peripheral_code_metadata(&self) -> CodeMetadata {
+        CodeMetadata::UPGRADEABLE | CodeMetadata::READABLE | CodeMetadata::PAYABLE | CodeMetadata::PAYABLE_BY_SC
     }
 
     #[endpoint(changeOwner)]
@@ -153,15 +543,109 @@ pub trait CircleOfLifeCenter {
     }
 
     #[endpoint(setEntryFee)]
-    fn set_entry_fee(&self, new_fee: BigUint) {
+    fn set_entry_fee(&self, new_fee: BigUint, ring_id: OptionalValue<u64>) {
         self.require_owner();
-        self.entry_fee().set(&new_fee);
+        let ring_id = self.resolve_ring(ring_id);
+        self.entry_fee(ring_id).set(&new_fee);
     }
 
     #[endpoint(setCirculationAmount)]
-    fn set_circulation_amount(&self, new_amount: BigUint) {
+    fn set_circulation_amount(&self, new_amount: BigUint, ring_id: OptionalValue<u64>) {
+        self.require_owner();
+        let ring_id = self.resolve_ring(ring_id);
+        self.circulation_amount(ring_id).set(&new_amount);
+    }
+
+    /// Choisit comment le montant circulant d'un cercle est calcule (owner ou DAO V2, proposition SetCirculationPolicy)
+    /// Le montant est recalcule au demarrage de chaque cycle (voir getNextCirculationAmount)
+    #[endpoint(setCirculationPolicy)]
+    fn set_circulation_policy(&self, policy: CirculationPolicy<Self::Api>, ring_id: OptionalValue<u64>) {
+        self.require_owner_or_dao();
+        let ring_id = self.resolve_ring(ring_id);
+        require!(
+            self.cycle_holder(ring_id).is_empty(),
+            "Impossible de changer la politique pendant un cycle"
+        );
+
+        match &policy {
+            CirculationPolicy::Fixed => {},
+            CirculationPolicy::TreasuryShare { bps, .. } => {
+                require!(*bps > 0 && *bps <= BPS_BASE, "Part de tresorerie invalide");
+            },
+            CirculationPolicy::PerMember { amount_per_member, .. } => {
+                require!(*amount_per_member > 0u64, "Montant par membre nul");
+            },
+            CirculationPolicy::Tiers { tiers, .. } => {
+                require!(!tiers.is_empty(), "Aucun palier");
+                let mut previous_min: Option<u64> = None;
+                for tier in tiers.iter() {
+                    require!(tier.amount > 0u64, "Montant de palier nul");
+                    if let Some(previous_min) = previous_min {
+                        require!(tier.min_members > previous_min, "Paliers non tries");
+                    }
+                    previous_min = Some(tier.min_members);
+                }
+            },
+        }
+
+        self.circulation_policy(ring_id).set(&policy);
+        self.circulation_policy_changed_event(&self.blockchain().get_caller(), ring_id, &policy);
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // ADMIN - CERCLES (RINGS)
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Cree un nouveau cercle independant (rotation, membres, frais et stats propres)
+    /// Le pool de recompenses, la synchro NFT et la distribution EGLD sont partages
+    /// max_members: taille max de la rotation (0 = illimitee). Retourne le ring_id
+    #[endpoint(createRing)]
+    fn create_ring(
+        &self,
+        name: ManagedBuffer,
+        entry_fee: BigUint,
+        circulation_amount: BigUint,
+        max_members: u64
+    ) -> u64 {
+        self.require_owner();
+        require!(circulation_amount > 0, "Montant circulant non defini");
+
+        let ring_id = self.ring_count().get() + 1;
+        self.ring_count().set(ring_id);
+
+        self.ring_name(ring_id).set(&name);
+        self.ring_max_members(ring_id).set(max_members);
+        self.entry_fee(ring_id).set(&entry_fee);
+        self.circulation_amount(ring_id).set(&circulation_amount);
+        self.cycle_epoch(ring_id).set(1u64); // Epoch commence a 1
+
+        self.ring_created_event(ring_id, &entry_fee, &circulation_amount, &name);
+        ring_id
+    }
+
+    /// Change la taille max de la rotation d'un cercle (0 = illimitee)
+    /// Les SC deja actifs au-dela de la limite le restent
+    #[endpoint(setRingMaxMembers)]
+    fn set_ring_max_members(&self, max_members: u64, ring_id: OptionalValue<u64>) {
+        self.require_owner();
+        let ring_id = self.resolve_ring(ring_id);
+        self.ring_max_members(ring_id).set(max_members);
+    }
+
+    /// Choisit le token qui circule dans un cercle (EGLD, stablecoin, XCIRCLEX...)
+    /// Il sert aussi a payer les frais d'entree. Refuse pendant un cycle: les fonds
+    /// en transit sont dans l'ancien token
+    #[endpoint(setCirculationToken)]
+    fn set_circulation_token(&self, token: EgldOrEsdtTokenIdentifier, ring_id: OptionalValue<u64>) {
         self.require_owner();
-        self.circulation_amount().set(&new_amount);
+        let ring_id = self.resolve_ring(ring_id);
+        require!(token.is_valid(), "Token invalide");
+        require!(
+            self.cycle_holder(ring_id).is_empty(),
+            "Impossible de changer le token pendant un cycle"
+        );
+        self.circulation_token(ring_id).set(&token);
+        self.circulation_token_changed_event(ring_id, &token);
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -182,6 +666,109 @@ pub trait CircleOfLifeCenter {
         self.dao_contract_address().set(&dao_address);
     }
 
+    /// Configure l'adresse du contrat de staking (destination StakingRewards)
+    #[endpoint(setStakingContract)]
+    fn set_staking_contract(&self, staking_address: ManagedAddress) {
+        self.require_owner();
+        self.staking_contract_address().set(&staking_address);
+    }
+
+    /// Remplace la table de distribution des EGLD (DAO V2 seulement, proposition SetDistributionTable).
+    /// Les parts doivent totaliser 100% (BPS_BASE); une destination ne peut apparaitre qu'une fois
+    #[endpoint(setDistributionTable)]
+    fn set_distribution_table(&self, shares: MultiValueEncoded<DistributionShare<Self::Api>>) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.dao_contract_address().is_empty() && caller == self.dao_contract_address().get(),
+            "DAO seulement"
+        );
+
+        let shares = shares.to_vec();
+        require!(!shares.is_empty(), "Table de distribution vide");
+        require!(shares.len() <= MAX_DISTRIBUTION_SHARES, "Trop de parts dans la table");
+
+        let sc_address = self.blockchain().get_sc_address();
+        let mut total_bps = 0u64;
+        for (i, share) in shares.iter().enumerate() {
+            require!(share.bps > 0, "Part nulle");
+            require!(share.bps <= BPS_BASE, "Part superieure a 100%");
+            if let DistributionTarget::Address(address) = &share.target {
+                require!(
+                    !address.is_zero() && *address != sc_address,
+                    "Adresse de distribution invalide"
+                );
+            }
+            for other in shares.iter().skip(i + 1) {
+                require!(other.target != share.target, "Destination en double");
+            }
+            total_bps += share.bps;
+        }
+        require!(total_bps == BPS_BASE, "La somme des parts doit faire 100%");
+
+        self.distribution_table().set(&shares);
+        self.distribution_table_history().push(&DistributionTableChange {
+            timestamp: self.blockchain().get_block_timestamp(),
+            changed_by: caller.clone(),
+            shares: shares.clone(),
+        });
+
+        self.distribution_table_changed_event(&caller, &shares);
+    }
+
+    /// Rachete des XCIRCLEX avec les EGLD accumules par BurnSwap puis les brule, en deux appels
+    /// (Promises API, un seul appel async par etape comme le pipeline de liquidite):
+    /// 1. wrap des EGLD en attente en WEGLD
+    /// 2. swap des WEGLD en XCIRCLEX, brules dans le callback
+    /// amount_out_min: minimum de XCIRCLEX attendu du swap (etape 2)
+    #[endpoint(processBurnSwap)]
+    fn process_burn_swap(&self, amount_out_min: BigUint) {
+        self.require_owner();
+        require!(!self.burn_swap_in_flight().get(), "Rachat BurnSwap en cours");
+        // Le pipeline de liquidite utilise les soldes WEGLD de SC0
+        require!(
+            self.liquidity_stage().get() == LiquidityStage::Idle,
+            "Etape de liquidite en cours"
+        );
+        self.require_no_protocol_withdrawal();
+        require!(!self.wegld_contract_address().is_empty(), "WEGLD non configure");
+        require!(!self.xexchange_pair_address().is_empty(), "xExchange non configure");
+        require!(!self.wegld_token_id().is_empty(), "WEGLD token ID non configure");
+        require!(!self.xcirclex_token_id().is_empty(), "XCIRCLEX token ID non configure");
+
+        let wegld = self.burn_swap_wegld().get();
+        if wegld == 0u32 {
+            // 1. Wrap EGLD -> WEGLD
+            let pending = self.pending_burn_swap_egld().take();
+            require!(pending > BigUint::zero(), "Pas d'EGLD en attente pour le rachat");
+
+            self.burn_swap_in_flight().set(true);
+            self.wegld_proxy(self.wegld_contract_address().get())
+                .wrap_egld()
+                .with_egld_transfer(pending.clone())
+                .with_gas_limit(20_000_000u64)
+                .with_callback(self.callbacks().burn_swap_wrap_callback(pending))
+                .with_extra_gas_for_callback(10_000_000u64)
+                .register_promise();
+            return;
+        }
+
+        // 2. Swap WEGLD -> XCIRCLEX. Prix manipule: les WEGLD restent en attente
+        let min_out = match self.check_swap_price(&wegld) {
+            Some(min_out) => core::cmp::max(min_out, amount_out_min),
+            None => return,
+        };
+
+        self.burn_swap_wegld().clear();
+        self.burn_swap_in_flight().set(true);
+        self.xexchange_proxy(self.xexchange_pair_address().get())
+            .swap_tokens_fixed_input(self.xcirclex_token_id().get(), min_out)
+            .with_esdt_transfer(EsdtTokenPayment::new(self.wegld_token_id().get(), 0, wegld.clone()))
+            .with_gas_limit(30_000_000u64)
+            .with_callback(self.callbacks().burn_swap_callback(wegld))
+            .with_extra_gas_for_callback(20_000_000u64)
+            .register_promise();
+    }
+
     /// Configure l'adresse de la paire xExchange XCIRCLEX/WEGLD
     #[endpoint(setXExchangePair)]
     fn set_xexchange_pair(&self, pair_address: ManagedAddress) {
@@ -215,6 +802,39 @@ pub trait CircleOfLifeCenter {
         self.slippage_tolerance_bps().set(slippage_bps);
     }
 
+    /// Configure la protection de prix des swaps xExchange (owner ou DAO)
+    #[endpoint(setPriceGuardPolicy)]
+    fn set_price_guard_policy(
+        &self,
+        max_deviation_bps: u64,
+        twap_window_seconds: u64,
+        oracle: OptionalValue<ManagedAddress>
+    ) {
+        self.require_owner_or_dao();
+        require!(max_deviation_bps <= MAX_PRICE_DEVIATION_BPS, "Ecart de prix max 50%");
+        require!(
+            twap_window_seconds > 0 && twap_window_seconds <= MAX_TWAP_WINDOW_SECONDS,
+            "Fenetre TWAP invalide"
+        );
+
+        let policy = PriceGuardPolicy {
+            max_deviation_bps,
+            twap_window_seconds,
+            oracle: oracle.into_option(),
+        };
+        self.price_guard_policy().set(&policy);
+        self.price_guard_policy_changed_event(&self.blockchain().get_caller(), &policy);
+    }
+
+    /// Met a jour la moyenne mobile du prix avec le prix spot de la paire (public, keepers)
+    #[endpoint(updatePriceReference)]
+    fn update_price_reference(&self) -> BigUint {
+        require!(!self.xexchange_pair_address().is_empty(), "xExchange non configure");
+        let spot = self.get_spot_price();
+        require!(spot > BigUint::zero(), "Reserves de la paire vides");
+        self.update_twap(&spot)
+    }
+
     /// Configure le seuil minimum pour declencher auto-processing de liquidite
     #[endpoint(setLiquidityThreshold)]
     fn set_liquidity_threshold(&self, threshold: BigUint) {
@@ -243,6 +863,10 @@ pub trait CircleOfLifeCenter {
         self.require_owner();
 
         require!(!self.lp_locker_address().is_empty(), "LP Locker non configure");
+        require!(
+            self.protocol_lock_position(lock_id).is_empty(),
+            "Lock protocolaire: utiliser unlockProtocolLiquidity"
+        );
 
         let locker_address = self.lp_locker_address().get();
 
@@ -256,6 +880,97 @@ pub trait CircleOfLifeCenter {
             .register_promise();
     }
 
+    // ═══════════════════════════════════════════════════════════════
+    // PROTOCOL-OWNED LIQUIDITY (DAO V2)
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Etape 1 (DAO): recupere dans SC0 les LP tokens d'un lock protocolaire expire.
+    /// proposal_id: proposition du DAO rouverte si l'unlock echoue cote LP Locker
+    #[endpoint(unlockProtocolLiquidity)]
+    fn unlock_protocol_liquidity(&self, lock_id: u64, proposal_id: u64) {
+        self.require_protocol_liquidity_dao();
+
+        let lock = self.get_protocol_lock(lock_id);
+        require!(lock.status == ProtocolLockStatus::Locked, "Lock deja debloque");
+        require!(
+            self.blockchain().get_block_timestamp() >= lock.unlock_at,
+            "Lock pas encore expire"
+        );
+
+        self.protocol_withdrawal_in_flight().set(true);
+        self.lp_locker_proxy(self.lp_locker_address().get())
+            .unlock(lock_id)
+            .with_gas_limit(20_000_000u64)
+            .with_callback(self.callbacks().protocol_unlock_callback(lock_id, proposal_id))
+            .with_extra_gas_for_callback(10_000_000u64)
+            .register_promise();
+    }
+
+    /// Etape 2 (DAO): retire de la paire la liquidite d'un lock debloque.
+    /// Protege par le controle de prix: si le prix est manipule, l'appel echoue
+    /// et la proposition du DAO est rouverte pour relancer l'etape
+    #[endpoint(removeProtocolLiquidity)]
+    fn remove_protocol_liquidity(&self, lock_id: u64, proposal_id: u64) {
+        self.require_protocol_liquidity_dao();
+
+        let lock = self.get_protocol_lock(lock_id);
+        require!(lock.status == ProtocolLockStatus::Unlocked, "Lock non debloque");
+        require!(self.check_pair_price().is_some(), "Prix de la paire manipule, retrait bloque");
+
+        // Minimums = part des reserves revenant aux LP tokens, moins le slippage
+        let pair_address = self.xexchange_pair_address().get();
+        let (xcirclex_reserve, wegld_reserve, lp_supply) = self
+            .xexchange_proxy(pair_address.clone())
+            .get_reserves_and_total_supply()
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+            .into_tuple();
+        require!(lp_supply > BigUint::zero(), "Reserves de la paire vides");
+        let slippage = self.get_slippage_tolerance();
+        let xcirclex_min = &lock.lp_amount * &xcirclex_reserve / &lp_supply * (BPS_BASE - slippage) / BPS_BASE;
+        let wegld_min = &lock.lp_amount * &wegld_reserve / &lp_supply * (BPS_BASE - slippage) / BPS_BASE;
+
+        let wegld_before = self.blockchain().get_sc_balance(
+            &EgldOrEsdtTokenIdentifier::esdt(self.wegld_token_id().get()), 0
+        );
+        let xcirclex_before = self.blockchain().get_sc_balance(
+            &EgldOrEsdtTokenIdentifier::esdt(self.xcirclex_token_id().get()), 0
+        );
+
+        self.protocol_withdrawal_in_flight().set(true);
+        self.xexchange_proxy(pair_address)
+            .remove_liquidity(xcirclex_min, wegld_min)
+            .with_esdt_transfer(EsdtTokenPayment::new(self.lp_token_id().get(), 0, lock.lp_amount))
+            .with_gas_limit(50_000_000u64)
+            .with_callback(self.callbacks().protocol_remove_callback(
+                lock_id,
+                proposal_id,
+                wegld_before,
+                xcirclex_before,
+            ))
+            .with_extra_gas_for_callback(30_000_000u64)
+            .register_promise();
+    }
+
+    /// Etape 3 (DAO): unwrap les WEGLD recuperes et distribue les EGLD
+    /// selon la table de distribution
+    #[endpoint(distributeLiquidityProceeds)]
+    fn distribute_liquidity_proceeds(&self, proposal_id: u64) {
+        self.require_protocol_liquidity_dao();
+
+        let amount = self.pending_proceeds_wegld().take();
+        require!(amount > BigUint::zero(), "Pas de WEGLD recuperes a distribuer");
+
+        self.protocol_withdrawal_in_flight().set(true);
+        self.wegld_proxy(self.wegld_contract_address().get())
+            .unwrap_egld()
+            .with_esdt_transfer(EsdtTokenPayment::new(self.wegld_token_id().get(), 0, amount.clone()))
+            .with_gas_limit(20_000_000u64)
+            .with_callback(self.callbacks().proceeds_unwrap_callback(amount, proposal_id))
+            .with_extra_gas_for_callback(30_000_000u64)
+            .register_promise();
+    }
+
     /// Retire les EGLD accumules pour la liquidite (traitement manuel)
     #[endpoint(withdrawPendingLiquidity)]
     fn withdraw_pending_liquidity(&self, to: ManagedAddress) {
@@ -274,19 +989,31 @@ pub trait CircleOfLifeCenter {
     // XEXCHANGE LIQUIDITY PROCESSING
     // ═══════════════════════════════════════════════════════════════
 
+    /// Admin: execute la prochaine etape du pipeline de liquidite selon getLiquidityStage
+    /// Idle -> wrap, Wrapped -> swap, Swapped -> addLiquidity, LiquidityAdded -> lock.
+    /// Apres l'echec d'un appel async, relance la meme etape
+    #[endpoint(advanceLiquidity)]
+    fn advance_liquidity(&self) {
+        self.require_owner();
+        self.require_no_protocol_withdrawal();
+        self.require_no_burn_swap();
+
+        match self.liquidity_stage().get() {
+            LiquidityStage::Idle => self.do_process_liquidity(),
+            LiquidityStage::Wrapped => self.do_swap_for_liquidity(),
+            LiquidityStage::Swapped => self.do_add_liquidity(),
+            LiquidityStage::LiquidityAdded => self.do_lock_lp(),
+            _ => sc_panic!("Etape de liquidite en cours"),
+        }
+    }
+
     /// Admin: Etape 1 - Wrap EGLD -> WEGLD
     /// Utilise les EGLD en attente (pending_liquidity_egld)
     /// Apres succes, appeler liquidityStep2_Swap
     #[endpoint(liquidityStep1_WrapEgld)]
     fn liquidity_step1_wrap_egld(&self) {
         self.require_owner();
-        self.do_process_liquidity();
-    }
-
-    /// DEPRECATED: Utiliser liquidityStep1_WrapEgld a la place
-    #[endpoint(processLiquidity)]
-    fn process_liquidity(&self) {
-        self.require_owner();
+        self.require_liquidity_stage(LiquidityStage::Idle);
         self.do_process_liquidity();
     }
 
@@ -295,91 +1022,204 @@ pub trait CircleOfLifeCenter {
     #[endpoint(liquidityStep2_Swap)]
     fn liquidity_step2_swap(&self) {
         self.require_owner();
+        self.require_liquidity_stage(LiquidityStage::Wrapped);
+        self.do_swap_for_liquidity();
+    }
 
-        // Verifier config
-        require!(!self.wegld_token_id().is_empty(), "WEGLD token ID non configure");
-        require!(!self.xexchange_pair_address().is_empty(), "xExchange non configure");
-        require!(!self.xcirclex_token_id().is_empty(), "XCIRCLEX token ID non configure");
-        require!(!self.lp_locker_address().is_empty(), "LP Locker non configure");
-
-        // Recuperer le solde WEGLD du SC
-        let wegld_token = self.wegld_token_id().get();
-        let wegld_balance = self.blockchain().get_sc_balance(
-            &EgldOrEsdtTokenIdentifier::esdt(wegld_token.clone()), 0
-        );
-
-        require!(wegld_balance > BigUint::zero(), "Pas de WEGLD dans le SC");
+    /// Admin: Etape 3 - Add Liquidity (WEGLD + XCIRCLEX)
+    /// Appeler apres que l'etape 2 (swap) soit terminee
+    #[endpoint(liquidityStep3_AddLiquidity)]
+    fn liquidity_step3_add_liquidity(&self) {
+        self.require_owner();
+        self.require_liquidity_stage(LiquidityStage::Swapped);
+        self.do_add_liquidity();
+    }
 
-        // Marquer le processing comme en cours
-        self.liquidity_processing_in_progress().set(true);
+    /// Admin: Etape 4 - Lock LP tokens pour 365 jours
+    /// Appeler apres que l'etape 3 (addLiquidity) soit terminee
+    #[endpoint(liquidityStep4_LockLp)]
+    fn liquidity_step4_lock_lp(&self) {
+        self.require_owner();
+        self.require_liquidity_stage(LiquidityStage::LiquidityAdded);
+        self.do_lock_lp();
+    }
 
-        // Split 50/50 le WEGLD disponible
-        let half = &wegld_balance / 2u64;
-        let other_half = &wegld_balance - &half;
+    /// Admin: force l'etape du pipeline (reprise manuelle, ex: tokens laisses
+    /// dans SC0 par une version precedente du contrat)
+    #[endpoint(setLiquidityStage)]
+    fn set_liquidity_stage(&self, stage: LiquidityStage) {
+        self.require_owner();
+        require!(!stage.is_in_flight(), "Etape d'attente de callback");
+        self.liquidity_failed_attempts().clear();
+        self.set_stage(stage);
+    }
 
-        // Stocker les montants pour le tracking
-        self.pending_wegld_for_swap().set(&half);
-        self.pending_wegld_for_lp().set(&other_half);
+    fn require_liquidity_stage(&self, expected: LiquidityStage) {
+        require!(self.liquidity_stage().get() == expected, "Etape de liquidite invalide");
+        self.require_no_protocol_withdrawal();
+        self.require_no_burn_swap();
+    }
 
-        self.liquidity_processing_started_event(&wegld_balance);
+    /// Le pipeline utilise les soldes WEGLD / LP de SC0: il attend la fin d'un
+    /// retrait de liquidite protocolaire (LP debloques ou WEGLD a distribuer)
+    fn require_no_protocol_withdrawal(&self) {
+        require!(
+            !self.protocol_withdrawal_in_flight().get()
+                && self.protocol_lp_unlocked().get() == 0u32
+                && self.pending_proceeds_wegld().get() == 0u32,
+            "Retrait de liquidite protocolaire en cours"
+        );
+    }
 
-        // Aller directement au swap (skip wrap)
-        let xcirclex_token = self.xcirclex_token_id().get();
-        let pair_address = self.xexchange_pair_address().get();
-        let min_out = BigUint::from(1u64);
+    /// Meme contrainte pour un rachat BurnSwap en cours (WEGLD wrappes en attente de swap)
+    fn require_no_burn_swap(&self) {
+        require!(
+            !self.burn_swap_in_flight().get() && self.burn_swap_wegld().get() == 0u32,
+            "Rachat BurnSwap en cours"
+        );
+    }
 
-        let payment = EsdtTokenPayment::new(wegld_token, 0, half);
+    fn set_stage(&self, stage: LiquidityStage) {
+        self.liquidity_stage().set(stage);
+        self.liquidity_stage_changed_event(stage);
+    }
 
-        self.xexchange_proxy(pair_address)
-            .swap_tokens_fixed_input(xcirclex_token, min_out)
-            .with_esdt_transfer(payment)
-            .with_gas_limit(30_000_000u64)
-            .with_callback(self.callbacks().swap_xcirclex_callback())
-            .with_extra_gas_for_callback(50_000_000u64)
-            .register_promise();
+    /// Echec d'un appel async: retour a l'etape qui l'a lance pour le relancer
+    fn rewind_liquidity_stage(&self, stage: LiquidityStage, step: &[u8], err_msg: &ManagedBuffer) {
+        self.liquidity_processing_error_event(&ManagedBuffer::from(step), err_msg);
+        self.liquidity_failed_attempts().update(|attempts| *attempts += 1);
+        self.set_stage(stage);
     }
 
-    /// Admin: Etape 3 - Add Liquidity (WEGLD + XCIRCLEX)
-    /// Appeler apres que l'etape 2 (swap) soit terminee
-    #[endpoint(liquidityStep3_AddLiquidity)]
-    fn liquidity_step3_add_liquidity(&self) {
-        self.require_owner();
+    /// Fonction interne pour traiter la liquidite
+    fn do_process_liquidity(&self) {
+        let pending = self.pending_liquidity_egld().get();
+        require!(pending > BigUint::zero(), "Pas de liquidite en attente");
+
+        // Verifier que les montants circulants (tous cercles) restent disponibles apres processing
+        let sc_balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        let circulation = self.get_circulation_reserved(&EgldOrEsdtTokenIdentifier::egld());
+        require!(
+            sc_balance >= &pending + &circulation,
+            "Solde insuffisant: le montant circulant doit rester disponible"
+        );
 
         // Verifier config
+        require!(!self.wegld_contract_address().is_empty(), "WEGLD non configure");
+        require!(!self.xexchange_pair_address().is_empty(), "xExchange non configure");
         require!(!self.wegld_token_id().is_empty(), "WEGLD token ID non configure");
         require!(!self.xcirclex_token_id().is_empty(), "XCIRCLEX token ID non configure");
-        require!(!self.xexchange_pair_address().is_empty(), "xExchange non configure");
         require!(!self.lp_locker_address().is_empty(), "LP Locker non configure");
-        require!(!self.lp_token_id().is_empty(), "LP Token ID non configure");
 
-        // Recuperer les balances
-        let wegld_token = self.wegld_token_id().get();
-        let xcirclex_token = self.xcirclex_token_id().get();
+        self.set_stage(LiquidityStage::Wrapping);
 
+        // Split 50/50
+        let half = &pending / 2u64;
+        let other_half = &pending - &half;
+
+        // Reset pending EGLD (restaure par le callback en cas d'echec)
+        self.pending_liquidity_egld().clear();
+
+        // Stocker les montants pour le tracking dans les callbacks
+        self.pending_wegld_for_swap().set(&half);
+        self.pending_wegld_for_lp().set(&other_half);
+
+        self.liquidity_processing_started_event(&pending);
+
+        // Etape 1: Wrap tout l'EGLD en WEGLD (utilise promises API)
+        let wegld_contract = self.wegld_contract_address().get();
+        self.wegld_proxy(wegld_contract)
+            .wrap_egld()
+            .with_egld_transfer(pending)
+            .with_gas_limit(20_000_000u64)
+            .with_callback(self.callbacks().wrap_egld_callback())
+            .with_extra_gas_for_callback(50_000_000u64)
+            .register_promise();
+    }
+
+    /// Swap WEGLD -> XCIRCLEX (50% du WEGLD du SC)
+    fn do_swap_for_liquidity(&self) {
+        // Verifier config
+        require!(!self.wegld_token_id().is_empty(), "WEGLD token ID non configure");
+        require!(!self.xexchange_pair_address().is_empty(), "xExchange non configure");
+        require!(!self.xcirclex_token_id().is_empty(), "XCIRCLEX token ID non configure");
+        require!(!self.lp_locker_address().is_empty(), "LP Locker non configure");
+
+        // Recuperer le solde WEGLD du SC
+        let wegld_token = self.wegld_token_id().get();
         let wegld_balance = self.blockchain().get_sc_balance(
             &EgldOrEsdtTokenIdentifier::esdt(wegld_token.clone()), 0
         );
-        let xcirclex_balance = self.blockchain().get_sc_balance(
-            &EgldOrEsdtTokenIdentifier::esdt(xcirclex_token.clone()), 0
+
+        require!(wegld_balance > BigUint::zero(), "Pas de WEGLD dans le SC");
+
+        // Split 50/50 le WEGLD disponible
+        let half = &wegld_balance / 2u64;
+        let other_half = &wegld_balance - &half;
+
+        // Prix manipule: pas de swap, le pipeline reste a Wrapped
+        let min_out = match self.check_swap_price(&half) {
+            Some(min_out) => min_out,
+            None => return,
+        };
+
+        self.set_stage(LiquidityStage::Swapping);
+
+        // Stocker les montants pour le tracking
+        self.pending_wegld_for_swap().set(&half);
+        self.pending_wegld_for_lp().set(&other_half);
+
+        // Solde XCIRCLEX avant swap: le callback n'attribue au LP que les tokens achetes
+        let xcirclex_token = self.xcirclex_token_id().get();
+        self.pending_xcirclex_for_lp().set(
+            self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(xcirclex_token.clone()), 0)
+        );
+        let pair_address = self.xexchange_pair_address().get();
+
+        let payment = EsdtTokenPayment::new(wegld_token, 0, half);
+
+        self.xexchange_proxy(pair_address)
+            .swap_tokens_fixed_input(xcirclex_token, min_out)
+            .with_esdt_transfer(payment)
+            .with_gas_limit(30_000_000u64)
+            .with_callback(self.callbacks().swap_xcirclex_callback())
+            .with_extra_gas_for_callback(50_000_000u64)
+            .register_promise();
+    }
+
+    /// Add Liquidity avec le WEGLD restant et les XCIRCLEX achetes
+    fn do_add_liquidity(&self) {
+        // Verifier config
+        require!(!self.wegld_token_id().is_empty(), "WEGLD token ID non configure");
+        require!(!self.xcirclex_token_id().is_empty(), "XCIRCLEX token ID non configure");
+        require!(!self.xexchange_pair_address().is_empty(), "xExchange non configure");
+        require!(!self.lp_locker_address().is_empty(), "LP Locker non configure");
+        require!(!self.lp_token_id().is_empty(), "LP Token ID non configure");
+
+        let wegld_token = self.wegld_token_id().get();
+        let xcirclex_token = self.xcirclex_token_id().get();
+
+        let wegld_balance = self.blockchain().get_sc_balance(
+            &EgldOrEsdtTokenIdentifier::esdt(wegld_token.clone()), 0
         );
+        let xcirclex_amount = self.pending_xcirclex_for_lp().get();
 
         require!(wegld_balance > BigUint::zero(), "Pas de WEGLD dans le SC");
-        require!(xcirclex_balance > BigUint::zero(), "Pas de XCIRCLEX dans le SC");
+        require!(xcirclex_amount > BigUint::zero(), "Pas de XCIRCLEX dans le SC");
 
-        // Marquer processing en cours
-        self.liquidity_processing_in_progress().set(true);
+        self.set_stage(LiquidityStage::AddingLiquidity);
 
-        // Stocker pour tracking
-        self.pending_xcirclex_for_lp().set(&xcirclex_balance);
+        // Montants envoyes: le callback en deduit les excedents rendus par la paire
         self.pending_wegld_for_lp().set(&wegld_balance);
+        let xcirclex_balance = self.blockchain().get_sc_balance(
+            &EgldOrEsdtTokenIdentifier::esdt(xcirclex_token.clone()), 0
+        );
+        self.xcirclex_balance_outside_lp().set(&xcirclex_balance - &xcirclex_amount);
 
-        self.swap_executed_event(&wegld_balance, &xcirclex_balance);
-
-        // Aller directement a addLiquidity
         let pair_address = self.xexchange_pair_address().get();
 
         let mut payments = ManagedVec::new();
-        payments.push(EsdtTokenPayment::new(xcirclex_token, 0, xcirclex_balance));
+        payments.push(EsdtTokenPayment::new(xcirclex_token, 0, xcirclex_amount));
         payments.push(EsdtTokenPayment::new(wegld_token, 0, wegld_balance));
 
         self.xexchange_proxy(pair_address)
@@ -391,12 +1231,8 @@ pub trait CircleOfLifeCenter {
             .register_promise();
     }
 
-    /// Admin: Etape 4 - Lock LP tokens pour 365 jours
-    /// Appeler apres que l'etape 3 (addLiquidity) soit terminee
-    #[endpoint(liquidityStep4_LockLp)]
-    fn liquidity_step4_lock_lp(&self) {
-        self.require_owner();
-
+    /// Lock des LP tokens pour 365 jours
+    fn do_lock_lp(&self) {
         // Verifier config
         require!(!self.lp_token_id().is_empty(), "LP Token ID non configure");
         require!(!self.lp_locker_address().is_empty(), "LP Locker non configure");
@@ -409,9 +1245,11 @@ pub trait CircleOfLifeCenter {
 
         require!(lp_balance > BigUint::zero(), "Pas de LP tokens dans le SC");
 
+        self.set_stage(LiquidityStage::Locking);
+
         // Lock pour 365 jours
         let locker_address = self.lp_locker_address().get();
-        let lock_duration = 365u64;
+        let lock_duration = LP_LOCK_DURATION_DAYS;
 
         let payment = EsdtTokenPayment::new(lp_token, 0, lp_balance.clone());
 
@@ -424,82 +1262,8 @@ pub trait CircleOfLifeCenter {
             .register_promise();
     }
 
-    // ══════════ ALIASES POUR COMPATIBILITE ══════════
-
-    /// DEPRECATED: Utiliser liquidityStep2_Swap
-    #[endpoint(resumeProcessingFromWegld)]
-    fn resume_processing_from_wegld(&self) {
-        self.liquidity_step2_swap();
-    }
-
-    /// DEPRECATED: Utiliser liquidityStep3_AddLiquidity
-    #[endpoint(resumeFromAddLiquidity)]
-    fn resume_from_add_liquidity(&self) {
-        self.liquidity_step3_add_liquidity();
-    }
-
-    /// DEPRECATED: Utiliser liquidityStep4_LockLp
-    #[endpoint(lockPendingLpTokens)]
-    fn lock_pending_lp_tokens(&self) {
-        self.liquidity_step4_lock_lp();
-    }
-
-    /// Fonction interne pour traiter la liquidite
-    fn do_process_liquidity(&self) {
-        let pending = self.pending_liquidity_egld().get();
-        require!(pending > BigUint::zero(), "Pas de liquidite en attente");
-
-        // Verifier qu'un processing n'est pas deja en cours
-        require!(
-            !self.liquidity_processing_in_progress().get(),
-            "Processing deja en cours"
-        );
-
-        // Verifier que le montant circulant reste disponible apres processing
-        let sc_balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
-        let circulation = self.circulation_amount().get();
-        require!(
-            sc_balance >= &pending + &circulation,
-            "Solde insuffisant: le montant circulant doit rester disponible"
-        );
-
-        // Verifier config
-        require!(!self.wegld_contract_address().is_empty(), "WEGLD non configure");
-        require!(!self.xexchange_pair_address().is_empty(), "xExchange non configure");
-        require!(!self.wegld_token_id().is_empty(), "WEGLD token ID non configure");
-        require!(!self.xcirclex_token_id().is_empty(), "XCIRCLEX token ID non configure");
-        require!(!self.lp_locker_address().is_empty(), "LP Locker non configure");
-
-        // Marquer le processing comme en cours
-        self.liquidity_processing_in_progress().set(true);
-
-        // Split 50/50
-        let half = &pending / 2u64;
-        let other_half = &pending - &half;
-
-        // Reset pending EGLD
-        self.pending_liquidity_egld().clear();
-
-        // Stocker les montants pour le tracking dans les callbacks
-        self.pending_wegld_for_swap().set(&half);
-        self.pending_wegld_for_lp().set(&other_half);
-
-        self.liquidity_processing_started_event(&pending);
-
-        // Etape 1: Wrap tout l'EGLD en WEGLD (utilise promises API)
-        let wegld_contract = self.wegld_contract_address().get();
-        self.wegld_proxy(wegld_contract)
-            .wrap_egld()
-            .with_egld_transfer(pending)
-            .with_gas_limit(20_000_000u64)
-            
-            .with_callback(self.callbacks().wrap_egld_callback())
-            .with_extra_gas_for_callback(50_000_000u64)
-            .register_promise();
-    }
-
-    /// Distribue les EGLD existants dans SC0 selon la formule V4
-    /// 3.14% treasury (reste), 70% liquidite, 30% DAO
+    /// Distribue les EGLD existants dans SC0 selon la table de distribution
+    /// (par defaut formule V4: 3.14% treasury (reste), 70% liquidite, 30% DAO)
     /// Utilise le solde EGLD actuel du contrat (moins le montant circulant requis)
     #[endpoint(distributeExistingEgld)]
     fn distribute_existing_egld(&self, amount: BigUint) {
@@ -517,7 +1281,7 @@ pub trait CircleOfLifeCenter {
 
         // Verifier le solde disponible
         let sc_balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
-        let circulation = self.circulation_amount().get();
+        let circulation = self.get_circulation_reserved(&EgldOrEsdtTokenIdentifier::egld());
         let available = if sc_balance > circulation {
             &sc_balance - &circulation
         } else {
@@ -653,166 +1417,790 @@ pub trait CircleOfLifeCenter {
         self.starter_bonus_percentage().set(percentage);
     }
 
-    /// Configure l'adresse du contrat NFT pour la synchronisation automatique des cycles
-    #[endpoint(setNftContract)]
-    fn set_nft_contract(&self, address: ManagedAddress) {
-        self.require_owner();
-        self.nft_contract().set(&address);
+    /// Configure l'adresse du contrat NFT pour la synchronisation automatique des cycles
+    #[endpoint(setNftContract)]
+    fn set_nft_contract(&self, address: ManagedAddress) {
+        self.require_owner();
+        self.nft_contract().set(&address);
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // ADMIN - ROTATION
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Choisit la strategie d'ordre de rotation (owner ou DAO)
+    /// Refuse pendant un cycle pour ne pas changer l'ordre en pleine rotation
+    #[endpoint(setRotationStrategy)]
+    fn set_rotation_strategy(&self, strategy: RotationStrategy, ring_id: OptionalValue<u64>) {
+        self.require_owner_or_dao();
+        let ring_id = self.resolve_ring(ring_id);
+        require!(
+            self.cycle_holder(ring_id).is_empty(),
+            "Impossible de changer la rotation pendant un cycle"
+        );
+        self.rotation_strategy(ring_id).set(strategy);
+        self.rotation_strategy_changed_event(&self.blockchain().get_caller(), strategy);
+    }
+
+    /// Configure la politique de failCycle (owner ou DAO)
+    #[endpoint(setFailurePolicy)]
+    fn set_failure_policy(
+        &self,
+        grace_period_seconds: u64,
+        warning_infractions: u64,
+        ban_days_per_infraction: u64,
+        infraction_reset_days: u64,
+        credit_signed_members: bool
+    ) {
+        self.require_owner_or_dao();
+        require!(grace_period_seconds <= MAX_GRACE_PERIOD_SECONDS, "Delai de grace max 24h");
+        require!(ban_days_per_infraction > 0, "Duree de ban invalide");
+        require!(infraction_reset_days > 0, "Delai de remise a zero invalide");
+
+        let policy = FailurePolicy {
+            grace_period_seconds,
+            warning_infractions,
+            ban_days_per_infraction,
+            infraction_reset_days,
+            credit_signed_members,
+        };
+        self.failure_policy().set(&policy);
+        self.failure_policy_changed_event(&self.blockchain().get_caller(), &policy);
+    }
+
+    /// Configure le nombre d'epochs d'historique conserves par cercle (owner ou DAO, 0 = illimite)
+    /// Les plus anciens sont elagues au demarrage des cycles suivants
+    #[endpoint(setCycleHistoryRetention)]
+    fn set_cycle_history_retention(&self, epochs: u64) {
+        self.require_owner_or_dao();
+        self.cycle_history_retention().set(epochs);
+        self.cycle_history_retention_changed_event(&self.blockchain().get_caller(), epochs);
+    }
+
+    /// Configure le delai par tour avant skipHolder (owner ou DAO, 0 = desactive)
+    /// skips_per_infraction: nombre de sauts comptant pour une infraction
+    #[endpoint(setHopTimeout)]
+    fn set_hop_timeout(&self, timeout_seconds: u64, skips_per_infraction: u64) {
+        self.require_owner_or_dao();
+        require!(timeout_seconds <= SECONDS_PER_DAY, "Delai par tour max 24h");
+        require!(skips_per_infraction > 0, "Nombre de sauts par infraction invalide");
+
+        self.hop_timeout_seconds().set(timeout_seconds);
+        self.skips_per_infraction().set(skips_per_infraction);
+        self.hop_timeout_changed_event(&self.blockchain().get_caller(), timeout_seconds, skips_per_infraction);
+    }
+
+    /// Configure la politique de depart des membres (owner ou DAO)
+    /// fee_refund_bps: part des frais d'entree remboursee, degressive sur refund_period_days
+    #[endpoint(setExitPolicy)]
+    fn set_exit_policy(&self, pay_pending_rewards: bool, fee_refund_bps: u64, refund_period_days: u64) {
+        self.require_owner_or_dao();
+        require!(fee_refund_bps <= BPS_BASE, "Remboursement max 100%");
+        require!(
+            fee_refund_bps == 0 || refund_period_days > 0,
+            "Periode de remboursement invalide"
+        );
+
+        let policy = ExitPolicy {
+            pay_pending_rewards,
+            fee_refund_bps,
+            refund_period_days,
+        };
+        self.exit_policy().set(&policy);
+        self.exit_policy_changed_event(&self.blockchain().get_caller(), &policy);
+    }
+
+    /// Configure les pourboires des keepers (owner ou DAO)
+    /// tip_per_transfer: verse par transfert traite (et par failCycle)
+    /// cap_per_cycle: total maximum verse pour un meme cycle (epoch)
+    #[endpoint(setKeeperReward)]
+    fn set_keeper_reward(&self, source: KeeperTipSource, tip_per_transfer: BigUint, cap_per_cycle: BigUint) {
+        self.require_owner_or_dao();
+        if source != KeeperTipSource::Disabled {
+            require!(tip_per_transfer > 0u64, "Pourboire invalide");
+            require!(cap_per_cycle >= tip_per_transfer, "Plafond inferieur au pourboire");
+        }
+
+        self.keeper_tip_source().set(source);
+        self.keeper_tip_per_transfer().set(&tip_per_transfer);
+        self.keeper_tip_cap_per_cycle().set(&cap_per_cycle);
+        self.keeper_reward_changed_event(source, &tip_per_transfer, &cap_per_cycle);
+    }
+
+    /// Configure le calendrier de reclamation des recompenses (owner ou DAO)
+    #[endpoint(setClaimSchedule)]
+    fn set_claim_schedule(&self, schedule: ClaimSchedule) {
+        self.require_owner_or_dao();
+        match &schedule {
+            ClaimSchedule::Always => {},
+            ClaimSchedule::Weekdays { days_mask } => {
+                require!(
+                    *days_mask > 0 && *days_mask <= ALL_DAYS_MASK,
+                    "Masque de jours invalide"
+                );
+            },
+            ClaimSchedule::EveryNDays { interval_days, anchor_day } => {
+                require!(
+                    *interval_days > 0 && *interval_days <= MAX_CLAIM_INTERVAL_DAYS,
+                    "Intervalle de jours invalide"
+                );
+                require!(
+                    *anchor_day <= self.get_current_day() + MAX_CLAIM_INTERVAL_DAYS,
+                    "Jour d'ancrage trop lointain"
+                );
+            },
+            ClaimSchedule::AfterCycles { every_cycles, window_seconds } => {
+                require!(*every_cycles > 0, "Nombre de cycles invalide");
+                require!(
+                    *window_seconds > 0 && *window_seconds <= MAX_CLAIM_WINDOW_SECONDS,
+                    "Duree de fenetre invalide"
+                );
+                // La prochaine fenetre s'ouvrira au prochain multiple de every_cycles
+                self.claim_window_opened_at().clear();
+            },
+        }
+
+        self.claim_schedule().set(&schedule);
+        self.claim_schedule_changed_event(&self.blockchain().get_caller(), &schedule);
+    }
+
+    /// Nombre de SC regles directement dans la transaction qui complete le cycle
+    /// Le reste est regle via settleCycle (0 = tout passe par settleCycle)
+    #[endpoint(setAutoSettlementItems)]
+    fn set_auto_settlement_items(&self, count: usize) {
+        self.require_owner();
+        self.auto_settlement_items().set(count);
+    }
+
+    /// Reset le cycle pour permettre de redemarrer (TEST ONLY)
+    #[endpoint(resetCycle)]
+    fn reset_cycle(&self, ring_id: OptionalValue<u64>) {
+        self.require_owner();
+        let ring_id = self.resolve_ring(ring_id);
+        if !self.cycle_holder(ring_id).is_empty() {
+            let epoch = self.cycle_epoch(ring_id).get();
+            self.close_cycle_record(ring_id, epoch, CycleStatus::Reset, None, BigUint::zero(), BigUint::zero());
+        }
+        // Incrementer l'epoch pour invalider les anciennes signatures
+        self.advance_cycle_epoch(ring_id);
+        self.cycle_day(ring_id).clear();
+        self.current_cycle_index(ring_id).set(0usize);
+        self.cycle_holder(ring_id).clear();
+    }
+
+    /// Initialise les index pionniers pour les SC existants (MIGRATION)
+    /// A appeler une seule fois apres l'upgrade pour attribuer les index aux SC deja deployes
+    #[endpoint(initializePioneerIndices)]
+    fn initialize_pioneer_indices(&self) {
+        self.require_owner();
+
+        let total = self.peripheral_contracts().len();
+        for i in 1..=total {
+            let sc = self.peripheral_contracts().get(i);
+            // Ne mettre a jour que si l'index n'est pas deja defini
+            if self.peripheral_index(&sc).is_empty() || self.peripheral_index(&sc).get() == 0 {
+                self.peripheral_index(&sc).set(i as u64);
+            }
+        }
+
+        self.pioneer_indices_initialized_event(total as u64);
+    }
+
+    /// Reconstruit l'index des SC actifs depuis contract_active (MIGRATION)
+    /// Ouvre aussi l'intervalle de participation aux recompenses des SC actifs
+    /// (les pending_rewards existants restent acquis et s'y ajoutent)
+    /// Par lots pour rester sous la limite de gas: traite les SC [start, start + count)
+    /// (1-based) et retourne le prochain index a traiter (> total = termine)
+    /// Chaque SC est range dans l'index de son cercle
+    #[endpoint(rebuildActiveIndex)]
+    fn rebuild_active_index(&self, start: usize, count: usize) -> usize {
+        self.require_owner();
+        require!(start >= 1, "Index de depart invalide (1-based)");
+
+        let total = self.peripheral_contracts().len();
+        let end = core::cmp::min(start.saturating_add(count), total + 1);
+        for i in start..end {
+            let sc = self.peripheral_contracts().get(i);
+            let ring_id = self.get_sc_ring(&sc);
+            require!(
+                self.cycle_holder(ring_id).is_empty(),
+                "Impossible de reconstruire l'index pendant un cycle"
+            );
+
+            if self.contract_active(&sc).get() {
+                if self.reward_checkpoint(&sc).is_empty() {
+                    self.reward_checkpoint(&sc).set(RewardCheckpoint {
+                        from_epoch: self.cycle_epoch(ring_id).get(),
+                        until_epoch: u64::MAX,
+                    });
+                }
+                self.active_contracts_index(ring_id).insert(sc);
+            } else {
+                self.active_contracts_index(ring_id).remove(&sc);
+            }
+        }
+
+        let mut active_count = 0u64;
+        for ring_id in 0..=self.ring_count().get() {
+            active_count += self.active_contracts_index(ring_id).len() as u64;
+        }
+        self.active_index_rebuilt_event(start as u64, end as u64, active_count);
+        end
+    }
+
+    /// Simule le passage au jour suivant (TEST ONLY - pour tester failCycle et ban)
+    /// Decremente cycle_day de 1 pour que current_day > cycle_day
+    /// NE PAS effacer cycle_holder ni current_cycle_index - ils sont necessaires pour failCycle
+    #[endpoint(simulateNextDay)]
+    fn simulate_next_day(&self, ring_id: OptionalValue<u64>) {
+        self.require_owner();
+        let ring_id = self.resolve_ring(ring_id);
+        let current_day = self.cycle_day(ring_id).get();
+        if current_day > 0 {
+            self.cycle_day(ring_id).set(current_day - 1);
+        }
+        // NE PAS incrementer l'epoch ici - il sera incremente par failCycle ou startDailyCycle
+        // NE PAS effacer cycle_holder - il est necessaire pour identifier le SC responsable dans failCycle
+        // NE PAS effacer current_cycle_index - il est necessaire pour identifier le SC qui a bloque
+    }
+
+    /// Force la fin d'un cycle echoue (timeout) - les fonds vont a SC0
+    /// Peut etre appele par n'importe qui une fois le jour du cycle termine + delai de grace
+    /// Le SC qui a bloque le cycle est sanctionne selon le systeme progressif (voir FailurePolicy):
+    /// les premieres infractions sont de simples avertissements, puis ban de N × 30 jours
+    /// (N = infractions au-dela des avertissements). Le compteur est remis a zero apres
+    /// 360 jours consecutifs sans infraction. Valeurs par defaut: 0 avertissement, 30 et 360 jours.
+    #[endpoint(failCycle)]
+    fn fail_cycle(&self, ring_id: OptionalValue<u64>) {
+        self.require_not_paused();
+        let ring_id = self.resolve_ring(ring_id);
+
+        // Verifier qu'il y a un cycle en cours
+        require!(!self.cycle_holder(ring_id).is_empty(), "Pas de cycle en cours");
+
+        let cycle_day = self.cycle_day(ring_id).get();
+        let policy = self.get_failure_policy();
+
+        // Le cycle peut etre declare echoue a partir du jour suivant + delai de grace
+        let timeout_at = (cycle_day + 1) * SECONDS_PER_DAY + policy.grace_period_seconds;
+        require!(
+            self.blockchain().get_block_timestamp() >= timeout_at,
+            "Le cycle n'est pas encore en timeout"
+        );
+
+        let cycle_holder = self.cycle_holder(ring_id).get();
+        let failed_epoch = self.cycle_epoch(ring_id).get();
+        let amount = self.circulation_amount(ring_id).get();
+        let sc0_address = self.blockchain().get_sc_address();
+
+        // Recuperer les fonds du holder actuel vers SC0
+        self.call_peripheral_transfer(&cycle_holder, &sc0_address, &amount);
+        self.close_cycle_record(
+            ring_id,
+            failed_epoch,
+            CycleStatus::Failed,
+            Some(cycle_holder.clone()),
+            BigUint::zero(),
+            BigUint::zero(),
+        );
+
+        // Incrementer le compteur de cycles echoues du cercle
+        let failed = self.cycles_failed(ring_id).get();
+        self.cycles_failed(ring_id).set(failed + 1);
+
+        // Incrementer le compteur de cycles echoues pour le SC responsable
+        let sc_failed = self.sc_cycles_failed(&cycle_holder).get();
+        self.sc_cycles_failed(&cycle_holder).set(sc_failed + 1);
+
+        // Credit partiel: les SC qui ont signe avant le blocage comptent un cycle reussi
+        if policy.credit_signed_members {
+            let holder_index = self.current_cycle_index(ring_id).get();
+            for i in 0..holder_index {
+                let sc = self.rotation_sc_at(ring_id, i);
+                if !self.last_signature(&sc, failed_epoch).is_empty() {
+                    self.sc_cycles_completed(&sc).update(|completed| *completed += 1);
+                }
+            }
+            self.partial_credit_event(failed_epoch, holder_index as u64);
+        }
+
+        // Sanction progressive du SC qui a bloque le cycle
+        self.record_infraction(&cycle_holder, &policy);
+
+        // Reset le cycle
+        self.advance_cycle_epoch(ring_id);
+        self.cycle_holder(ring_id).clear();
+        self.current_cycle_index(ring_id).set(0usize);
+
+        self.cycle_failed_event(cycle_day, &cycle_holder, ring_id);
+
+        // Pourboire du keeper qui a debloque le cycle
+        let keeper = self.blockchain().get_caller();
+        let mut stats = self.get_keeper_stats_or_default(&keeper);
+        stats.cycles_failed += 1;
+        self.keeper_stats(&keeper).set(stats);
+        self.pay_keeper_tip(&keeper, 1, ring_id, failed_epoch);
+    }
+
+    /// Saute le holder qui bloque la rotation depuis plus de hop_timeout_seconds
+    /// Peut etre appele par n'importe qui: le montant circulant est retire du SC bloque
+    /// et transfere au suivant de la rotation, le cycle continue pour les autres.
+    /// Le SC saute ne signe pas pour ce cycle: il n'en recoit ni la recompense ni le credit
+    /// de cycle reussi, et recoit une infraction partielle: skips_per_infraction sauts
+    /// valent une infraction du systeme progressif (voir failCycle)
+    /// Un holder qui a pre-signe n'est jamais saute: sa signature est executee a la place
+    #[endpoint(skipHolder)]
+    fn skip_holder(&self, ring_id: OptionalValue<u64>) {
+        self.require_not_paused();
+        let ring_id = self.resolve_ring(ring_id);
+
+        require!(!self.cycle_holder(ring_id).is_empty(), "Pas de cycle en cours");
+        let timeout = self.hop_timeout_seconds().get();
+        require!(timeout > 0, "Saut de tour desactive");
+
+        // Executer d'abord les pre-signatures pas encore traitees par les keepers
+        let holder_before = self.cycle_holder(ring_id).get();
+        self.process_pending_transfers(ring_id);
+        if self.cycle_holder(ring_id).is_empty() || self.cycle_holder(ring_id).get() != holder_before {
+            return;
+        }
+
+        require!(
+            self.blockchain().get_block_timestamp() >= self.hop_started_at(ring_id).get() + timeout,
+            "Le tour n'est pas encore en timeout"
+        );
+
+        let skipped_sc = self.cycle_holder(ring_id).get();
+        let current_index = self.current_cycle_index(ring_id).get();
+        let current_epoch = self.cycle_epoch(ring_id).get();
+        let current_day = self.cycle_day(ring_id).get();
+
+        // Exclure le SC saute de la recompense et du credit de cet epoch, avant que
+        // le transfert ne complete eventuellement le cycle
+        self.exclude_from_epoch_rewards(&skipped_sc, current_epoch);
+        self.cycle_skipped_count(ring_id).update(|count| *count += 1);
+        let next_sc = self.forward_circulation(ring_id, &skipped_sc, current_index, current_epoch, current_day, true);
+
+        // Infraction partielle
+        let skipped_hops = self.sc_skipped_hops(&skipped_sc).get() + 1;
+        if skipped_hops >= self.get_skips_per_infraction() {
+            self.sc_skipped_hops(&skipped_sc).clear();
+            self.record_infraction(&skipped_sc, &self.get_failure_policy());
+        } else {
+            self.sc_skipped_hops(&skipped_sc).set(skipped_hops);
+        }
+        self.holder_skipped_event(&skipped_sc, &next_sc, ring_id, skipped_hops);
+
+        // Pourboire du keeper qui a debloque la rotation
+        let keeper = self.blockchain().get_caller();
+        let mut stats = self.get_keeper_stats_or_default(&keeper);
+        stats.transfers_processed += 1;
+        self.keeper_stats(&keeper).set(stats);
+        self.pay_keeper_tip(&keeper, 1, ring_id, current_epoch);
+
+        // Les suivants qui ont pre-signe signent dans la foulee
+        self.process_pending_transfers(ring_id);
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // BANS - Appel via le DAO
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Conteste un ban (ex: panne reseau) en deposant une caution EGLD et un motif.
+    /// Cree une proposition dans le DAO V2: si elle passe, le DAO leve le ban et remet
+    /// le compteur d'infractions a zero (caution rendue), sinon la caution va a la tresorerie du DAO.
+    #[payable("EGLD")]
+    #[endpoint(appealBan)]
+    fn appeal_ban(&self, reason: ManagedBuffer) -> u64 {
+        let caller = self.blockchain().get_caller();
+        require!(!self.member_contract(&caller).is_empty(), "Pas membre");
+
+        let sc = self.member_contract(&caller).get();
+        let ban_until = self.sc_ban_until(&sc).get();
+        require!(
+            self.blockchain().get_block_timestamp() < ban_until,
+            "Votre SC n'est pas banni"
+        );
+        require!(self.sc_appeal_proposal(&sc).is_empty(), "Appel deja en cours");
+        require!(!reason.is_empty(), "Motif requis");
+        require!(!self.dao_contract_address().is_empty(), "DAO non configure");
+
+        let bond = self.appeal_bond_amount().get();
+        require!(bond > 0, "Appels desactives");
+        let payment = self.call_value().egld().clone_value();
+        require!(payment == bond, "Caution incorrecte");
+
+        let dao_address = self.dao_contract_address().get();
+        let proposal_id: u64 = self.dao_proxy(dao_address)
+            .create_ban_appeal(&caller, &sc, reason)
+            .egld(&payment)
+            .returns(ReturnsResult)
+            .sync_call();
+
+        self.sc_appeal_proposal(&sc).set(proposal_id);
+        self.ban_appealed_event(&sc, proposal_id, &payment);
+
+        proposal_id
+    }
+
+    /// Cloture d'un appel (DAO V2 seulement, apres execution ou rejet de la proposition).
+    /// approved = true: ban leve et compteur d'infractions remis a zero.
+    #[endpoint(resolveBanAppeal)]
+    fn resolve_ban_appeal(&self, sc: ManagedAddress, approved: bool) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.dao_contract_address().is_empty() && caller == self.dao_contract_address().get(),
+            "DAO seulement"
+        );
+        // Idempotent: un appel deja clos ne bloque pas la proposition cote DAO
+        if self.sc_appeal_proposal(&sc).is_empty() {
+            return;
+        }
+
+        let proposal_id = self.sc_appeal_proposal(&sc).take();
+        if approved {
+            self.sc_ban_until(&sc).clear();
+            self.sc_infraction_count(&sc).clear();
+        }
+
+        self.ban_appeal_resolved_event(&sc, proposal_id, approved);
+    }
+
+    /// Clot un appel reste ouvert sans decision (owner ou DAO), ex: resolveBanAppeal
+    /// en echec cote DAO (ban_appeal_resolution_failed). Le ban reste inchange et le
+    /// membre peut faire un nouvel appel.
+    #[endpoint(clearBanAppeal)]
+    fn clear_ban_appeal(&self, sc: ManagedAddress) {
+        self.require_owner_or_dao();
+        require!(!self.sc_appeal_proposal(&sc).is_empty(), "Aucun appel en cours");
+
+        let proposal_id = self.sc_appeal_proposal(&sc).take();
+        self.ban_appeal_cleared_event(&sc, proposal_id, &self.blockchain().get_caller());
+    }
+
+    /// Configure la caution EGLD exigee pour un appel (owner ou DAO, 0 = appels desactives)
+    #[endpoint(setAppealBond)]
+    fn set_appeal_bond(&self, amount: BigUint) {
+        self.require_owner_or_dao();
+        self.appeal_bond_amount().set(&amount);
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // KEEPERS - Pourboires
+    // ═══════════════════════════════════════════════════════════════
+
+    fn get_keeper_stats_or_default(&self, keeper: &ManagedAddress) -> KeeperStats<Self::Api> {
+        if self.keeper_stats(keeper).is_empty() {
+            return KeeperStats {
+                transfers_processed: 0,
+                cycles_failed: 0,
+                tips_reward_token: BigUint::zero(),
+                tips_egld: BigUint::zero(),
+            };
+        }
+        self.keeper_stats(keeper).get()
+    }
+
+    /// Verse le pourboire pour `work_units` unites de travail effectuees pendant
+    /// le cycle `epoch` du cercle `ring_id`, dans la limite du plafond par cycle et des fonds disponibles
+    /// Rien n'est verse sans travail effectif (anti-griefing)
+    fn pay_keeper_tip(&self, keeper: &ManagedAddress, work_units: u64, ring_id: u64, epoch: u64) {
+        let source = self.keeper_tip_source().get();
+        if work_units == 0 || source == KeeperTipSource::Disabled {
+            return;
+        }
+
+        let already_paid = self.keeper_tips_paid(ring_id, epoch).get();
+        let cap = self.keeper_tip_cap_per_cycle().get();
+        if already_paid >= cap {
+            return;
+        }
+        let mut tip = self.keeper_tip_per_transfer().get() * work_units;
+        let remaining_cap = &cap - &already_paid;
+        if tip > remaining_cap {
+            tip = remaining_cap;
+        }
+
+        match source {
+            KeeperTipSource::RewardToken => {
+                if self.reward_token_id().is_empty() {
+                    return;
+                }
+                let pool = self.rewards_pool().get();
+                if tip > pool {
+                    tip = pool.clone();
+                }
+                if tip == 0u64 {
+                    return;
+                }
+                self.rewards_pool().set(&(pool - &tip));
+                self.send().direct_esdt(keeper, &self.reward_token_id().get(), 0, &tip);
+                self.keeper_stats(keeper).update(|stats| stats.tips_reward_token += &tip);
+            },
+            KeeperTipSource::Egld => {
+                // Ne jamais toucher au montant circulant ni a la liquidite en attente
+                let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+                let reserved = self.get_circulation_reserved(&EgldOrEsdtTokenIdentifier::egld())
+                    + self.get_pending_distribution_egld();
+                if balance <= reserved {
+                    return;
+                }
+                let available = balance - reserved;
+                if tip > available {
+                    tip = available;
+                }
+                self.send().direct_egld(keeper, &tip);
+                self.keeper_stats(keeper).update(|stats| stats.tips_egld += &tip);
+            },
+            KeeperTipSource::Disabled => return,
+        }
+
+        self.keeper_tips_paid(ring_id, epoch).set(&(already_paid + &tip));
+        self.keeper_tipped_event(keeper, epoch, source, &tip);
     }
 
-    /// Reset le cycle pour permettre de redemarrer (TEST ONLY)
-    #[endpoint(resetCycle)]
-    fn reset_cycle(&self) {
+    #[endpoint(withdraw)]
+    fn withdraw(&self, amount: BigUint, to: ManagedAddress) {
         self.require_owner();
-        // Incrementer l'epoch pour invalider les anciennes signatures
-        let current_epoch = self.cycle_epoch().get();
-        self.cycle_epoch().set(current_epoch + 1);
-        self.cycle_day().clear();
-        self.current_cycle_index().set(0usize);
-        self.cycle_holder().clear();
+        let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        require!(balance >= amount, "Solde insuffisant");
+        self.send().direct_egld(&to, &amount);
     }
 
-    /// Initialise les index pionniers pour les SC existants (MIGRATION)
-    /// A appeler une seule fois apres l'upgrade pour attribuer les index aux SC deja deployes
-    #[endpoint(initializePioneerIndices)]
-    fn initialize_pioneer_indices(&self) {
+    /// Retire un token de SC0 (frais d'entree ESDT...) sans toucher aux montants
+    /// circulants des cercles ni au pool de recompenses
+    #[endpoint(withdrawToken)]
+    fn withdraw_token(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint, to: ManagedAddress) {
         self.require_owner();
-
-        let total = self.peripheral_contracts().len();
-        for i in 1..=total {
-            let sc = self.peripheral_contracts().get(i);
-            // Ne mettre a jour que si l'index n'est pas deja defini
-            if self.peripheral_index(&sc).is_empty() || self.peripheral_index(&sc).get() == 0 {
-                self.peripheral_index(&sc).set(i as u64);
-            }
+        let balance = self.get_circulation_balance(&token);
+        let mut reserved = self.get_circulation_reserved(&token);
+        if token.is_egld() {
+            reserved += self.get_pending_distribution_egld();
         }
-
-        self.pioneer_indices_initialized_event(total as u64);
+        require!(balance >= &reserved + &amount, "Solde insuffisant");
+        self.send().direct(&to, &token, 0, &amount);
     }
 
-    /// Simule le passage au jour suivant (TEST ONLY - pour tester failCycle et ban)
-    /// Decremente cycle_day de 1 pour que current_day > cycle_day
-    /// NE PAS effacer cycle_holder ni current_cycle_index - ils sont necessaires pour failCycle
-    #[endpoint(simulateNextDay)]
-    fn simulate_next_day(&self) {
-        self.require_owner();
-        let current_day = self.cycle_day().get();
-        if current_day > 0 {
-            self.cycle_day().set(current_day - 1);
-        }
-        // NE PAS incrementer l'epoch ici - il sera incremente par failCycle ou startDailyCycle
-        // NE PAS effacer cycle_holder - il est necessaire pour identifier le SC responsable dans failCycle
-        // NE PAS effacer current_cycle_index - il est necessaire pour identifier le SC qui a bloque
+    /// Alimente SC0 en token de circulation (ex: cercle ESDT sans frais d'entree)
+    #[payable("*")]
+    #[endpoint(fundCirculation)]
+    fn fund_circulation(&self) {
+        let (token, amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(amount > 0u64, "Montant nul");
+        self.circulation_funded_event(&self.blockchain().get_caller(), &token, &amount);
     }
 
-    /// Force la fin d'un cycle echoue (timeout) - les fonds vont a SC0
-    /// Peut etre appele par n'importe qui si le cycle est bloque depuis trop longtemps
-    /// Le SC qui a bloque le cycle est automatiquement banni selon le systeme progressif:
-    /// 1ère infraction = 30 jours, 2ème = 60 jours, 3ème = 90 jours, etc.
-    /// Le compteur d'infractions est remis a zero apres 360 jours consecutifs sans infraction.
-    #[endpoint(failCycle)]
-    fn fail_cycle(&self) {
-        self.require_not_paused();
-
-        // Verifier qu'il y a un cycle en cours
-        require!(!self.cycle_holder().is_empty(), "Pas de cycle en cours");
-
-        let current_day = self.get_current_day();
-        let cycle_day = self.cycle_day().get();
-
-        // Le cycle peut etre declare echoue si on est au jour suivant
-        require!(current_day > cycle_day, "Le cycle n'est pas encore en timeout");
-
-        let cycle_holder = self.cycle_holder().get();
-        let amount = self.circulation_amount().get();
-        let sc0_address = self.blockchain().get_sc_address();
+    /// Recupere les fonds d'un SC peripherique bloque vers SC0
+    /// Utilisable uniquement par l'owner en cas de cycle bloque
+    #[endpoint(recoverFundsFromPeripheral)]
+    fn recover_funds_from_peripheral(&self, peripheral_sc: ManagedAddress) {
+        self.require_owner();
 
-        // Recuperer les fonds du holder actuel vers SC0
-        self.call_peripheral_transfer(&cycle_holder, &sc0_address, &amount);
+        // Appeler forceTransferToSC0 sur le SC peripherique
+        let _: IgnoreValue = self.peripheral_proxy(peripheral_sc.clone())
+            .force_transfer_to_sc0()
+            .execute_on_dest_context();
 
-        // Incrementer le compteur de cycles echoues (global)
-        let failed = self.cycles_failed().get();
-        self.cycles_failed().set(failed + 1);
+        self.funds_recovered_event(&peripheral_sc);
+    }
 
-        // Incrementer le compteur de cycles echoues pour le SC responsable
-        let sc_failed = self.sc_cycles_failed(&cycle_holder).get();
-        self.sc_cycles_failed(&cycle_holder).set(sc_failed + 1);
+    fn require_owner(&self) {
+        let caller = self.blockchain().get_caller();
+        let owner = self.owner().get();
+        require!(caller == owner, "Owner only");
+    }
 
-        // ═══════════════════════════════════════════════════════════════
-        // SYSTEME DE BAN PROGRESSIF
-        // ═══════════════════════════════════════════════════════════════
+    /// Systeme de ban progressif: enregistre une infraction pour un SC
+    /// (avertissement, puis ban de N × ban_days_per_infraction jours)
+    fn record_infraction(&self, sc: &ManagedAddress, policy: &FailurePolicy) {
         let current_timestamp = self.blockchain().get_block_timestamp();
 
-        // Verifier si le compteur doit etre remis a zero (360 jours sans infraction)
-        let last_infraction = self.sc_last_infraction(&cycle_holder).get();
+        // Verifier si le compteur doit etre remis a zero (N jours sans infraction)
+        let last_infraction = self.sc_last_infraction(sc).get();
         let days_since_last_infraction = if last_infraction > 0 {
             (current_timestamp - last_infraction) / 86400
         } else {
             0
         };
 
-        // Reset le compteur si 360+ jours sans infraction
-        let mut infraction_count = if days_since_last_infraction >= 360 {
+        // Reset le compteur si N+ jours sans infraction
+        let mut infraction_count = if days_since_last_infraction >= policy.infraction_reset_days {
             0u64
         } else {
-            self.sc_infraction_count(&cycle_holder).get()
+            self.sc_infraction_count(sc).get()
         };
 
         // Incrementer le compteur d'infractions
         infraction_count += 1;
-        self.sc_infraction_count(&cycle_holder).set(infraction_count);
+        self.sc_infraction_count(sc).set(infraction_count);
 
         // Enregistrer la date de cette infraction
-        self.sc_last_infraction(&cycle_holder).set(current_timestamp);
+        self.sc_last_infraction(sc).set(current_timestamp);
 
-        // Calculer la duree du ban: N × 30 jours (N = nombre d'infractions)
-        let ban_days = infraction_count * 30;
-        let ban_duration: u64 = ban_days * 24 * 60 * 60; // En secondes
-        let ban_until = current_timestamp + ban_duration;
+        if infraction_count <= policy.warning_infractions {
+            // Simple avertissement: pas de ban, le SC reste actif
+            self.sc_warned_event(sc, infraction_count, policy.warning_infractions);
+        } else {
+            // Calculer la duree du ban: N × 30 jours (N = infractions au-dela des avertissements)
+            let ban_days = (infraction_count - policy.warning_infractions) * policy.ban_days_per_infraction;
+            let ban_duration: u64 = ban_days * 24 * 60 * 60; // En secondes
+            let ban_until = current_timestamp + ban_duration;
 
-        self.sc_ban_until(&cycle_holder).set(ban_until);
+            self.sc_ban_until(sc).set(ban_until);
 
-        // Desactiver automatiquement le SC
-        self.contract_active(&cycle_holder).set(false);
+            // Desactiver automatiquement le SC
+            self.set_contract_active(sc, false);
 
-        // Emettre l'evenement de ban avec infos detaillees
-        self.sc_banned_progressive_event(&cycle_holder, ban_until, infraction_count, ban_days);
+            // Emettre l'evenement de ban avec infos detaillees
+            self.sc_banned_progressive_event(sc, ban_until, infraction_count, ban_days);
+        }
+    }
 
-        // Reset le cycle
-        let current_epoch = self.cycle_epoch().get();
-        self.cycle_epoch().set(current_epoch + 1);
-        self.cycle_holder().clear();
-        self.current_cycle_index().set(0usize);
+    /// Politique de failCycle (defaut: pas de grace, pas d'avertissement, 30/360 jours)
+    fn get_failure_policy(&self) -> FailurePolicy {
+        if self.failure_policy().is_empty() {
+            return FailurePolicy {
+                grace_period_seconds: 0,
+                warning_infractions: 0,
+                ban_days_per_infraction: DEFAULT_BAN_DAYS_PER_INFRACTION,
+                infraction_reset_days: DEFAULT_INFRACTION_RESET_DAYS,
+                credit_signed_members: false,
+            };
+        }
+        self.failure_policy().get()
+    }
 
-        self.cycle_failed_event(cycle_day, &cycle_holder);
+    /// Sauts de tour par infraction (defaut: 1)
+    fn get_skips_per_infraction(&self) -> u64 {
+        if self.skips_per_infraction().is_empty() {
+            return DEFAULT_SKIPS_PER_INFRACTION;
+        }
+        self.skips_per_infraction().get()
     }
 
-    #[endpoint(withdraw)]
-    fn withdraw(&self, amount: BigUint, to: ManagedAddress) {
-        self.require_owner();
-        let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
-        require!(balance >= amount, "Solde insuffisant");
-        self.send().direct_egld(&to, &amount);
+    /// Politique de depart (defaut: recompenses versees, pas de remboursement)
+    fn get_exit_policy(&self) -> ExitPolicy {
+        if self.exit_policy().is_empty() {
+            return ExitPolicy {
+                pay_pending_rewards: true,
+                fee_refund_bps: 0,
+                refund_period_days: 0,
+            };
+        }
+        self.exit_policy().get()
     }
 
-    /// Recupere les fonds d'un SC peripherique bloque vers SC0
-    /// Utilisable uniquement par l'owner en cas de cycle bloque
-    #[endpoint(recoverFundsFromPeripheral)]
-    fn recover_funds_from_peripheral(&self, peripheral_sc: ManagedAddress) {
-        self.require_owner();
+    fn get_price_guard_policy(&self) -> PriceGuardPolicy<Self::Api> {
+        if self.price_guard_policy().is_empty() {
+            return PriceGuardPolicy {
+                max_deviation_bps: DEFAULT_MAX_PRICE_DEVIATION_BPS,
+                twap_window_seconds: DEFAULT_TWAP_WINDOW_SECONDS,
+                oracle: None,
+            };
+        }
+        self.price_guard_policy().get()
+    }
 
-        // Appeler forceTransferToSC0 sur le SC peripherique
-        let _: IgnoreValue = self.peripheral_proxy(peripheral_sc.clone())
-            .force_transfer_to_sc0()
-            .execute_on_dest_context();
+    fn get_slippage_tolerance(&self) -> u64 {
+        if self.slippage_tolerance_bps().is_empty() {
+            return DEFAULT_SLIPPAGE_BPS;
+        }
+        self.slippage_tolerance_bps().get()
+    }
+
+    /// Prix spot de la paire: XCIRCLEX par WEGLD (x PRICE_PRECISION), 0 si la paire est vide.
+    /// La paire est XCIRCLEX (premier token) / WEGLD (second token)
+    fn get_spot_price(&self) -> BigUint {
+        let (xcirclex_reserve, wegld_reserve, _) = self
+            .xexchange_proxy(self.xexchange_pair_address().get())
+            .get_reserves_and_total_supply()
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+            .into_tuple();
+        if wegld_reserve == BigUint::zero() {
+            return BigUint::zero();
+        }
+        xcirclex_reserve * PRICE_PRECISION / wegld_reserve
+    }
+
+    /// Moyenne mobile du prix: le dernier prix spot observe est suppose constant
+    /// depuis son observation et pondere par le temps ecoule, plafonne a la fenetre:
+    /// la moyenne garde toujours au moins la moitie du poids, une observation ne la
+    /// remplace jamais. Chaque observation est ramenee a max_deviation_bps de la moyenne:
+    /// un prix manipule deplace la reference d'au plus la moitie de cet ecart.
+    /// Le prix spot courant n'est pris en compte qu'aux observations suivantes:
+    /// une manipulation dans le bloc n'a pas d'effet.
+    /// Retourne la moyenne avant prise en compte du prix spot courant
+    fn update_twap(&self, spot: &BigUint) -> BigUint {
+        let now = self.blockchain().get_block_timestamp();
+        let policy = self.get_price_guard_policy();
+        let window = policy.twap_window_seconds;
+        let elapsed = core::cmp::min(now - self.twap_updated_at().get(), window);
+
+        let twap = if self.twap_price().is_empty() {
+            spot.clone()
+        } else {
+            let current = self.twap_price().get();
+            let mut observed = self.last_spot_price().get();
+            if policy.max_deviation_bps > 0 {
+                let floor = &current * (BPS_BASE - policy.max_deviation_bps) / BPS_BASE;
+                let ceiling = &current * (BPS_BASE + policy.max_deviation_bps) / BPS_BASE;
+                observed = core::cmp::min(core::cmp::max(observed, floor), ceiling);
+            }
+            (current * window + observed * elapsed) / (window + elapsed)
+        };
 
-        self.funds_recovered_event(&peripheral_sc);
+        self.twap_price().set(&twap);
+        self.last_spot_price().set(spot);
+        self.twap_updated_at().set(now);
+        self.price_reference_updated_event(spot, &twap);
+        twap
+    }
+
+    /// Controle le prix avant un swap de wegld_in WEGLD vers XCIRCLEX.
+    /// Retourne le minimum de XCIRCLEX attendu, ou None si le prix est bloque
+    fn check_swap_price(&self, wegld_in: &BigUint) -> Option<BigUint> {
+        let price = self.check_pair_price()?;
+        let slippage = self.get_slippage_tolerance();
+        Some(wegld_in * &price / PRICE_PRECISION * (BPS_BASE - slippage) / BPS_BASE)
+    }
+
+    /// Compare le prix spot de la paire au prix de reference (oracle, sinon moyenne mobile).
+    /// Retourne le plus bas des deux, ou None (swap_blocked emis) si l'ecart
+    /// depasse max_deviation_bps
+    fn check_pair_price(&self) -> Option<BigUint> {
+        let policy = self.get_price_guard_policy();
+        let spot = self.get_spot_price();
+        require!(spot > BigUint::zero(), "Reserves de la paire vides");
+
+        let twap = self.update_twap(&spot);
+        let reference = match &policy.oracle {
+            Some(oracle) => self
+                .price_oracle_proxy(oracle.clone())
+                .get_price()
+                .returns(ReturnsResult)
+                .sync_call_readonly(),
+            None => twap,
+        };
+        require!(reference > BigUint::zero(), "Prix de reference invalide");
+
+        let difference = if spot > reference { &spot - &reference } else { &reference - &spot };
+        let deviation_bps = difference * BPS_BASE / &reference;
+        if policy.max_deviation_bps > 0 && deviation_bps > policy.max_deviation_bps {
+            self.swap_blocked_event(&spot, &reference, &deviation_bps);
+            return None;
+        }
+
+        Some(core::cmp::min(spot, reference))
     }
 
-    fn require_owner(&self) {
+    /// Owner ou contrat DAO V2 (pour les parametres gouvernables)
+    fn require_owner_or_dao(&self) {
         let caller = self.blockchain().get_caller();
-        let owner = self.owner().get();
-        require!(caller == owner, "Owner only");
+        let is_owner = caller == self.owner().get();
+        let is_dao = !self.dao_contract_address().is_empty()
+            && caller == self.dao_contract_address().get();
+        require!(is_owner || is_dao, "Owner ou DAO seulement");
     }
 
     fn require_not_paused(&self) {
@@ -833,6 +2221,15 @@ pub trait CircleOfLifeCenter {
     #[storage_mapper("peripheral_template")]
     fn peripheral_template(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// Version du code du template (incrementee a chaque setPeripheralTemplate)
+    #[storage_mapper("peripheral_template_version")]
+    fn peripheral_template_version(&self) -> SingleValueMapper<u64>;
+
+    /// Version du code deployee sur un SC peripherique, ecrite par le SC lui-meme
+    /// a l'init et a l'upgrade (0 = deploye avant le suivi des versions)
+    #[storage_mapper_from_address("code_version")]
+    fn peripheral_code_version(&self, sc: ManagedAddress) -> SingleValueMapper<u64, ManagedAddress>;
+
     /// Liste des SC peripheriques actifs
     #[storage_mapper("peripheral_contracts")]
     fn peripheral_contracts(&self) -> VecMapper<ManagedAddress>;
@@ -849,28 +2246,74 @@ pub trait CircleOfLifeCenter {
     #[storage_mapper("contract_active")]
     fn contract_active(&self, sc: &ManagedAddress) -> SingleValueMapper<bool>;
 
-    #[storage_mapper("current_cycle_index")]
-    fn current_cycle_index(&self) -> SingleValueMapper<usize>;
+    fn current_cycle_index(&self, ring_id: u64) -> SingleValueMapper<usize> {
+        SingleValueMapper::new(self.ring_storage_key(b"current_cycle_index", ring_id))
+    }
+
+    fn cycle_day(&self, ring_id: u64) -> SingleValueMapper<u64> {
+        SingleValueMapper::new(self.ring_storage_key(b"cycle_day", ring_id))
+    }
+
+    fn circulation_amount(&self, ring_id: u64) -> SingleValueMapper<BigUint> {
+        SingleValueMapper::new(self.ring_storage_key(b"circulation_amount", ring_id))
+    }
 
-    #[storage_mapper("cycle_day")]
-    fn cycle_day(&self) -> SingleValueMapper<u64>;
+    /// Politique de calcul du montant circulant (vide = Fixed)
+    fn circulation_policy(&self, ring_id: u64) -> SingleValueMapper<CirculationPolicy<Self::Api>> {
+        SingleValueMapper::new(self.ring_storage_key(b"circulation_policy", ring_id))
+    }
 
-    #[storage_mapper("circulation_amount")]
-    fn circulation_amount(&self) -> SingleValueMapper<BigUint>;
+    fn entry_fee(&self, ring_id: u64) -> SingleValueMapper<BigUint> {
+        SingleValueMapper::new(self.ring_storage_key(b"entry_fee", ring_id))
+    }
 
-    #[storage_mapper("entry_fee")]
-    fn entry_fee(&self) -> SingleValueMapper<BigUint>;
+    /// Token qui circule dans le cercle et paie les frais d'entree (vide = EGLD)
+    /// Lu aussi par les SC peripheriques (meme cle)
+    fn circulation_token(&self, ring_id: u64) -> SingleValueMapper<EgldOrEsdtTokenIdentifier> {
+        SingleValueMapper::new(self.ring_storage_key(b"circulation_token", ring_id))
+    }
 
     /// Epoch du cycle - increment a chaque reset pour invalider les signatures
-    #[storage_mapper("cycle_epoch")]
-    fn cycle_epoch(&self) -> SingleValueMapper<u64>;
+    fn cycle_epoch(&self, ring_id: u64) -> SingleValueMapper<u64> {
+        SingleValueMapper::new(self.ring_storage_key(b"cycle_epoch", ring_id))
+    }
 
     #[storage_mapper("last_signature")]
     fn last_signature(&self, sc: &ManagedAddress, epoch: u64) -> SingleValueMapper<u64>;
 
     /// SC qui detient actuellement le montant circulant
-    #[storage_mapper("cycle_holder")]
-    fn cycle_holder(&self) -> SingleValueMapper<ManagedAddress>;
+    fn cycle_holder(&self, ring_id: u64) -> SingleValueMapper<ManagedAddress> {
+        SingleValueMapper::new(self.ring_storage_key(b"cycle_holder", ring_id))
+    }
+
+    /// Historique du cycle d'un cercle pour un epoch
+    fn cycle_record(&self, ring_id: u64, epoch: u64) -> SingleValueMapper<CycleRecord<Self::Api>> {
+        let mut key = self.ring_storage_key(b"cycle_record", ring_id);
+        key.append_item(&epoch);
+        SingleValueMapper::new(key)
+    }
+
+    /// Passages successifs du montant circulant pendant le cycle d'un epoch
+    fn cycle_hops(&self, ring_id: u64, epoch: u64) -> VecMapper<CycleHop<Self::Api>> {
+        let mut key = self.ring_storage_key(b"cycle_hops", ring_id);
+        key.append_item(&epoch);
+        VecMapper::new(key)
+    }
+
+    /// Plus ancien epoch dont l'historique peut encore etre present
+    fn cycle_history_start(&self, ring_id: u64) -> SingleValueMapper<u64> {
+        SingleValueMapper::new(self.ring_storage_key(b"cycle_history_start", ring_id))
+    }
+
+    /// SC sautes pendant le cycle en cours (remis a zero a chaque nouvel epoch)
+    fn cycle_skipped_count(&self, ring_id: u64) -> SingleValueMapper<usize> {
+        SingleValueMapper::new(self.ring_storage_key(b"cycle_skipped_count", ring_id))
+    }
+
+    /// Debut du tour du holder actuel (base du delai de skipHolder)
+    fn hop_started_at(&self, ring_id: u64) -> SingleValueMapper<u64> {
+        SingleValueMapper::new(self.ring_storage_key(b"hop_started_at", ring_id))
+    }
 
     /// Pre-signatures: SC a signe a l'avance pour cet epoch
     #[storage_mapper("pre_signed")]
@@ -886,12 +2329,14 @@ pub trait CircleOfLifeCenter {
     fn auto_sign_until(&self, sc: &ManagedAddress) -> SingleValueMapper<u64>;
 
     /// Compteur de cycles complets (reussis)
-    #[storage_mapper("cycles_completed")]
-    fn cycles_completed(&self) -> SingleValueMapper<u64>;
+    fn cycles_completed(&self, ring_id: u64) -> SingleValueMapper<u64> {
+        SingleValueMapper::new(self.ring_storage_key(b"cycles_completed", ring_id))
+    }
 
     /// Compteur de cycles echoues (timeout ou abandon)
-    #[storage_mapper("cycles_failed")]
-    fn cycles_failed(&self) -> SingleValueMapper<u64>;
+    fn cycles_failed(&self, ring_id: u64) -> SingleValueMapper<u64> {
+        SingleValueMapper::new(self.ring_storage_key(b"cycles_failed", ring_id))
+    }
 
     /// Compteur de cycles reussis par SC peripherique
     #[storage_mapper("sc_cycles_completed")]
@@ -913,11 +2358,183 @@ pub trait CircleOfLifeCenter {
     #[storage_mapper("sc_last_infraction")]
     fn sc_last_infraction(&self, sc: &ManagedAddress) -> SingleValueMapper<u64>;
 
+    /// Caution EGLD exigee pour contester un ban (0 = appels desactives)
+    #[storage_mapper("appeal_bond_amount")]
+    fn appeal_bond_amount(&self) -> SingleValueMapper<BigUint>;
+
+    /// Proposition DAO de l'appel en cours pour un SC (vide = aucun appel)
+    #[storage_mapper("sc_appeal_proposal")]
+    fn sc_appeal_proposal(&self, sc: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Politique de failCycle (vide = valeurs par defaut)
+    #[storage_mapper("failure_policy")]
+    fn failure_policy(&self) -> SingleValueMapper<FailurePolicy>;
+
+    /// Nombre d'epochs d'historique conserves par cercle (0 = illimite)
+    #[storage_mapper("cycle_history_retention")]
+    fn cycle_history_retention(&self) -> SingleValueMapper<u64>;
+
+    /// Delai par tour avant de pouvoir sauter le holder (0 = skipHolder desactive)
+    #[storage_mapper("hop_timeout_seconds")]
+    fn hop_timeout_seconds(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("skips_per_infraction")]
+    fn skips_per_infraction(&self) -> SingleValueMapper<u64>;
+
+    /// Sauts de tour pas encore convertis en infraction
+    #[storage_mapper("sc_skipped_hops")]
+    fn sc_skipped_hops(&self, sc: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Politique de leaveCircle (vide = valeurs par defaut)
+    #[storage_mapper("exit_policy")]
+    fn exit_policy(&self) -> SingleValueMapper<ExitPolicy>;
+
+    /// Frais d'entree payes par un SC (base du remboursement au depart)
+    #[storage_mapper("entry_payment")]
+    fn entry_payment(&self, sc: &ManagedAddress) -> SingleValueMapper<EgldOrEsdtTokenPayment>;
+
+    /// Timestamp de l'entree (ou du retour) du membre dans le cercle
+    #[storage_mapper("member_joined_at")]
+    fn member_joined_at(&self, sc: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// SC retire d'un ancien membre, reutilise s'il revient
+    #[storage_mapper("retired_contract")]
+    fn retired_contract(&self, member: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("contract_retired")]
+    fn contract_retired(&self, sc: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Nouveau wallet propose pour reprendre l'adhesion d'un SC
+    #[storage_mapper("pending_membership_transfer")]
+    fn pending_membership_transfer(&self, sc: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    /// Source des pourboires keepers (defaut: Disabled)
+    #[storage_mapper("keeper_tip_source")]
+    fn keeper_tip_source(&self) -> SingleValueMapper<KeeperTipSource>;
+
+    #[storage_mapper("keeper_tip_per_transfer")]
+    fn keeper_tip_per_transfer(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("keeper_tip_cap_per_cycle")]
+    fn keeper_tip_cap_per_cycle(&self) -> SingleValueMapper<BigUint>;
+
+    /// Total des pourboires verses pour un cycle (epoch)
+    fn keeper_tips_paid(&self, ring_id: u64, epoch: u64) -> SingleValueMapper<BigUint> {
+        let mut key = self.ring_storage_key(b"keeper_tips_paid", ring_id);
+        key.append_item(&epoch);
+        SingleValueMapper::new(key)
+    }
+
+    #[storage_mapper("keeper_stats")]
+    fn keeper_stats(&self, keeper: &ManagedAddress) -> SingleValueMapper<KeeperStats<Self::Api>>;
+
+    /// Delegation de signature par SC (lue aussi par le SC peripherique)
+    #[storage_mapper("sc_delegate")]
+    fn sc_delegate(&self, sc: &ManagedAddress) -> SingleValueMapper<Delegation<Self::Api>>;
+
+    /// SC pour lesquels une adresse est deleguee
+    #[storage_mapper("delegated_contracts")]
+    fn delegated_contracts(&self, delegate: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Calendrier de reclamation des recompenses (vide = dimanche uniquement)
+    #[storage_mapper("claim_schedule")]
+    fn claim_schedule(&self) -> SingleValueMapper<ClaimSchedule>;
+
+    /// Ouverture de la derniere fenetre glissante AfterCycles
+    #[storage_mapper("claim_window_opened_at")]
+    fn claim_window_opened_at(&self) -> SingleValueMapper<u64>;
+
+    /// Strategie d'ordre de rotation (defaut: InsertionOrder)
+    fn rotation_strategy(&self, ring_id: u64) -> SingleValueMapper<RotationStrategy> {
+        SingleValueMapper::new(self.ring_storage_key(b"rotation_strategy", ring_id))
+    }
+
+    /// Graine de melange de la rotation pour un epoch (tiree a l'ouverture de l'epoch)
+    fn rotation_seed(&self, ring_id: u64, epoch: u64) -> SingleValueMapper<u64> {
+        let mut key = self.ring_storage_key(b"rotation_seed", ring_id);
+        key.append_item(&epoch);
+        SingleValueMapper::new(key)
+    }
+
+    /// Index des SC actifs, maintenu a chaque changement de statut
+    fn active_contracts_index(&self, ring_id: u64) -> SetMapper<ManagedAddress> {
+        SetMapper::new(self.ring_storage_key(b"active_contracts_index", ring_id))
+    }
+
+    /// Ordre de passage fige au demarrage du cycle (1-based)
+    fn rotation_snapshot(&self, ring_id: u64) -> VecMapper<ManagedAddress> {
+        VecMapper::new(self.ring_storage_key(b"rotation_snapshot", ring_id))
+    }
+
+    /// Epoch pour lequel rotation_snapshot a ete fige
+    fn rotation_snapshot_epoch(&self, ring_id: u64) -> SingleValueMapper<u64> {
+        SingleValueMapper::new(self.ring_storage_key(b"rotation_snapshot_epoch", ring_id))
+    }
+
+    /// Participation non comptabilisee de chaque SC (recompenses calculees a la demande)
+    #[storage_mapper("reward_checkpoint")]
+    fn reward_checkpoint(&self, sc: &ManagedAddress) -> SingleValueMapper<RewardCheckpoint>;
+
+    /// Index cumulatifs a l'ouverture de chaque epoch
+    fn epoch_reward_index(&self, ring_id: u64, epoch: u64) -> SingleValueMapper<EpochRewardIndex<Self::Api>> {
+        let mut key = self.ring_storage_key(b"epoch_reward_index", ring_id);
+        key.append_item(&epoch);
+        SingleValueMapper::new(key)
+    }
+
+    /// Reglement du dernier cycle complete en attente
+    fn settlement_pending(&self, ring_id: u64) -> SingleValueMapper<bool> {
+        SingleValueMapper::new(self.ring_storage_key(b"settlement_pending", ring_id))
+    }
+
+    /// Epoch du cycle en cours de reglement
+    fn settlement_epoch(&self, ring_id: u64) -> SingleValueMapper<u64> {
+        SingleValueMapper::new(self.ring_storage_key(b"settlement_epoch", ring_id))
+    }
+
+    /// Prochaine position (1-based) de rotation_snapshot a regler
+    fn settlement_cursor(&self, ring_id: u64) -> SingleValueMapper<usize> {
+        SingleValueMapper::new(self.ring_storage_key(b"settlement_cursor", ring_id))
+    }
+
+    /// Nombre de SC regles a la completion (vide = DEFAULT_AUTO_SETTLEMENT_ITEMS)
+    #[storage_mapper("auto_settlement_items")]
+    fn auto_settlement_items(&self) -> SingleValueMapper<usize>;
+
     /// Numéro d'index du SC périphérique (1 = premier SC, 2 = deuxième, etc.)
     /// Les 360 premiers SC obtiennent un bonus x3 sur leurs récompenses
     #[storage_mapper("peripheral_index")]
     fn peripheral_index(&self, sc: &ManagedAddress) -> SingleValueMapper<u64>;
 
+    // ═══════════════════════════════════════════════════════════════
+    // STORAGE - CERCLES (RINGS)
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Cle de stockage de l'etat d'un cercle. Le cercle principal garde la cle
+    /// historique (donnees conservees a l'upgrade), les autres y ajoutent leur ring_id
+    fn ring_storage_key(&self, base_key: &[u8], ring_id: u64) -> StorageKey<Self::Api> {
+        let mut key = StorageKey::new(base_key);
+        if ring_id != DEFAULT_RING_ID {
+            key.append_item(&ring_id);
+        }
+        key
+    }
+
+    /// Nombre de cercles crees en plus du cercle principal (ids 1..=ring_count)
+    #[storage_mapper("ring_count")]
+    fn ring_count(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("ring_name")]
+    fn ring_name(&self, ring_id: u64) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Taille max de la rotation d'un cercle (SC actifs), 0 = illimitee
+    #[storage_mapper("ring_max_members")]
+    fn ring_max_members(&self, ring_id: u64) -> SingleValueMapper<u64>;
+
+    /// Cercle d'un SC peripherique (vide = cercle principal, lu aussi par le SC peripherique)
+    #[storage_mapper("sc_ring")]
+    fn sc_ring(&self, sc: &ManagedAddress) -> SingleValueMapper<u64>;
+
     // ═══════════════════════════════════════════════════════════════
     // STORAGE - REWARDS XCIRCLEX
     // ═══════════════════════════════════════════════════════════════
@@ -963,13 +2580,26 @@ pub trait CircleOfLifeCenter {
     #[storage_mapper("total_egld_deposits")]
     fn total_egld_deposits(&self) -> SingleValueMapper<BigUint>;
 
+    /// Depots ESDT par membre (cercles a token ESDT, hors bonus de depot)
+    #[storage_mapper("member_token_deposits")]
+    fn member_token_deposits(
+        &self,
+        member: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Tokens ESDT deposes par un membre (suivis pour le transfert d'adhesion)
+    #[storage_mapper("member_deposit_tokens")]
+    fn member_deposit_tokens(&self, member: &ManagedAddress) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
     // ═══════════════════════════════════════════════════════════════
     // STORAGE - STARTER BONUS
     // ═══════════════════════════════════════════════════════════════
 
     /// Adresse de celui qui a demarre le cycle actuel
-    #[storage_mapper("cycle_starter")]
-    fn cycle_starter(&self) -> SingleValueMapper<ManagedAddress>;
+    fn cycle_starter(&self, ring_id: u64) -> SingleValueMapper<ManagedAddress> {
+        SingleValueMapper::new(self.ring_storage_key(b"cycle_starter", ring_id))
+    }
 
     /// Bonus percentage pour le starter (ex: 1000 = 10%, base 10000)
     /// Defaut: 0 (pas de bonus)
@@ -1032,6 +2662,50 @@ pub trait CircleOfLifeCenter {
     #[storage_mapper("pending_liquidity_egld")]
     fn pending_liquidity_egld(&self) -> SingleValueMapper<BigUint>;
 
+    /// Adresse du contrat de staking (destination StakingRewards)
+    #[storage_mapper("staking_contract_address")]
+    fn staking_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Table de distribution active (vide = repartition historique 3.14% / 70% / 30%)
+    #[storage_mapper("distribution_table")]
+    fn distribution_table(&self) -> SingleValueMapper<ManagedVec<DistributionShare<Self::Api>>>;
+
+    /// Historique des changements de la table de distribution
+    #[storage_mapper("distribution_table_history")]
+    fn distribution_table_history(&self) -> VecMapper<DistributionTableChange<Self::Api>>;
+
+    /// Total distribue au contrat de staking
+    #[storage_mapper("total_distributed_staking")]
+    fn total_distributed_staking(&self) -> SingleValueMapper<BigUint>;
+
+    /// EGLD en attente pour le rachat + burn de XCIRCLEX (processBurnSwap)
+    #[storage_mapper("pending_burn_swap_egld")]
+    fn pending_burn_swap_egld(&self) -> SingleValueMapper<BigUint>;
+
+    /// WEGLD wrappes par processBurnSwap, en attente du swap
+    #[storage_mapper("burn_swap_wegld")]
+    fn burn_swap_wegld(&self) -> SingleValueMapper<BigUint>;
+
+    /// Appel async de processBurnSwap en attente de callback
+    #[storage_mapper("burn_swap_in_flight")]
+    fn burn_swap_in_flight(&self) -> SingleValueMapper<bool>;
+
+    /// Protection de prix des swaps (vide = valeurs par defaut)
+    #[storage_mapper("price_guard_policy")]
+    fn price_guard_policy(&self) -> SingleValueMapper<PriceGuardPolicy<Self::Api>>;
+
+    /// Moyenne mobile du prix XCIRCLEX par WEGLD (x PRICE_PRECISION)
+    #[storage_mapper("twap_price")]
+    fn twap_price(&self) -> SingleValueMapper<BigUint>;
+
+    /// Dernier prix spot observe (integre a la moyenne aux observations suivantes)
+    #[storage_mapper("last_spot_price")]
+    fn last_spot_price(&self) -> SingleValueMapper<BigUint>;
+
+    /// Timestamp de la derniere observation du prix
+    #[storage_mapper("twap_updated_at")]
+    fn twap_updated_at(&self) -> SingleValueMapper<u64>;
+
     /// Seuil minimum pour declencher auto-processing de liquidite
     #[storage_mapper("liquidity_threshold")]
     fn liquidity_threshold(&self) -> SingleValueMapper<BigUint>;
@@ -1060,9 +2734,60 @@ pub trait CircleOfLifeCenter {
     #[storage_mapper("pending_lp_tokens")]
     fn pending_lp_tokens(&self) -> SingleValueMapper<BigUint>;
 
-    /// Flag indiquant si un processing de liquidite est en cours
-    #[storage_mapper("liquidity_processing_in_progress")]
-    fn liquidity_processing_in_progress(&self) -> SingleValueMapper<bool>;
+    /// Etape du pipeline de liquidite (vide = Idle)
+    #[storage_mapper("liquidity_stage")]
+    fn liquidity_stage(&self) -> SingleValueMapper<LiquidityStage>;
+
+    /// Echecs consecutifs de l'etape en cours (remis a zero a chaque etape reussie)
+    #[storage_mapper("liquidity_failed_attempts")]
+    fn liquidity_failed_attempts(&self) -> SingleValueMapper<u64>;
+
+    /// Solde XCIRCLEX hors liquidite au moment de addLiquidity (calcul des excedents rendus)
+    #[storage_mapper("xcirclex_balance_outside_lp")]
+    fn xcirclex_balance_outside_lp(&self) -> SingleValueMapper<BigUint>;
+
+    /// Apports du dernier addLiquidity, enregistres avec le lock
+    #[storage_mapper("lp_egld_contributed")]
+    fn lp_egld_contributed(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("lp_xcirclex_contributed")]
+    fn lp_xcirclex_contributed(&self) -> SingleValueMapper<BigUint>;
+
+    /// Locks de liquidite detenue par le protocole (ordre de creation)
+    #[storage_mapper("protocol_locks")]
+    fn protocol_locks(&self) -> VecMapper<ProtocolLock<Self::Api>>;
+
+    /// Position (1-based) d'un lock_id dans protocol_locks
+    #[storage_mapper("protocol_lock_position")]
+    fn protocol_lock_position(&self, lock_id: u64) -> SingleValueMapper<usize>;
+
+    /// LP tokens detenus par le protocole (verrouilles ou debloques, pas encore retires)
+    #[storage_mapper("total_protocol_lp")]
+    fn total_protocol_lp(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_protocol_egld_contributed")]
+    fn total_protocol_egld_contributed(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_protocol_xcirclex_contributed")]
+    fn total_protocol_xcirclex_contributed(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_protocol_egld_recovered")]
+    fn total_protocol_egld_recovered(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total_protocol_xcirclex_recovered")]
+    fn total_protocol_xcirclex_recovered(&self) -> SingleValueMapper<BigUint>;
+
+    /// LP tokens debloques dans SC0, en attente de removeProtocolLiquidity
+    #[storage_mapper("protocol_lp_unlocked")]
+    fn protocol_lp_unlocked(&self) -> SingleValueMapper<BigUint>;
+
+    /// WEGLD recuperes par removeProtocolLiquidity, en attente de distributeLiquidityProceeds
+    #[storage_mapper("pending_proceeds_wegld")]
+    fn pending_proceeds_wegld(&self) -> SingleValueMapper<BigUint>;
+
+    /// Appel async sur la liquidite protocolaire en attente de callback
+    #[storage_mapper("protocol_withdrawal_in_flight")]
+    fn protocol_withdrawal_in_flight(&self) -> SingleValueMapper<bool>;
 
     // ═══════════════════════════════════════════════════════════════
     // REJOINDRE LE CERCLE - Deployer un vrai SC
@@ -1070,16 +2795,24 @@ pub trait CircleOfLifeCenter {
 
     /// Rejoindre le cercle en payant les frais d'entree
     /// SC0 deploie un nouveau smart contract pour le membre
-    #[payable("EGLD")]
+    /// `ring_id` choisit le cercle a rejoindre (cercle principal par defaut)
+    /// Les frais sont payes dans le token de circulation du cercle (EGLD ou ESDT)
+    #[payable("*")]
     #[endpoint(joinCircle)]
-    fn join_circle(&self) -> ManagedAddress {
+    fn join_circle(&self, ring_id: OptionalValue<u64>) -> ManagedAddress {
         self.require_not_paused();
 
         let caller = self.blockchain().get_caller();
-        let payment = self.call_value().egld().clone_value();
+        let (payment_token, payment) = self.call_value().egld_or_single_fungible_esdt();
+        let ring_id = self.resolve_ring(ring_id);
+        self.require_ring_has_room(ring_id);
 
         // Verifier le paiement
-        let fee = self.entry_fee().get();
+        require!(
+            payment_token == self.get_circulation_token(ring_id),
+            "Token de paiement invalide"
+        );
+        let fee = self.entry_fee(ring_id).get();
         require!(payment >= fee, "Paiement insuffisant");
 
         // Verifier que l'utilisateur n'est pas deja membre
@@ -1095,8 +2828,35 @@ pub trait CircleOfLifeCenter {
         );
 
         // === DISTRIBUTION V4 ===
-        // Distribuer les frais d'entree: 3.14% treasury, 70% liquidite, 30% DAO
-        self.process_egld_distribution(&payment);
+        // Distribuer les frais d'entree selon la table de distribution
+        if payment_token.is_egld() {
+            self.process_egld_distribution(&payment);
+        } else {
+            self.process_esdt_distribution(&payment_token, &payment);
+        }
+
+        // Un ancien membre retrouve son SC retire (historique, ban et index conserves)
+        if !self.retired_contract(&caller).is_empty() {
+            let sc = self.retired_contract(&caller).take();
+            let ban_until = self.sc_ban_until(&sc).get();
+            require!(
+                self.blockchain().get_block_timestamp() >= ban_until,
+                "Votre SC est temporairement banni"
+            );
+
+            self.contract_retired(&sc).clear();
+            self.member_contract(&caller).set(&sc);
+            if ring_id != DEFAULT_RING_ID {
+                self.sc_ring(&sc).set(ring_id);
+            } else {
+                self.sc_ring(&sc).clear();
+            }
+            self.record_entry(&sc, payment_token, payment);
+            self.set_contract_active(&sc, true);
+
+            self.member_rejoined_event(&caller, &sc);
+            return sc;
+        }
 
         let template = self.peripheral_template().get();
         let sc0_address = self.blockchain().get_sc_address();
@@ -1105,12 +2865,13 @@ pub trait CircleOfLifeCenter {
         let mut args = ManagedArgBuffer::new();
         args.push_arg(&caller);
         args.push_arg(&sc0_address);
+        args.push_arg(self.peripheral_template_version().get());
 
         let (new_sc_address, _) = self.send_raw().deploy_from_source_contract(
             self.blockchain().get_gas_left(),
             &BigUint::zero(),
             &template,
-            CodeMetadata::UPGRADEABLE | CodeMetadata::READABLE | CodeMetadata::PAYABLE | CodeMetadata::PAYABLE_BY_SC,
+            self.peripheral_code_metadata(),
             &args
         );
 
@@ -1118,7 +2879,11 @@ pub trait CircleOfLifeCenter {
         self.peripheral_contracts().push(&new_sc_address);
         self.member_contract(&caller).set(&new_sc_address);
         self.contract_owner(&new_sc_address).set(&caller);
-        self.contract_active(&new_sc_address).set(true);
+        if ring_id != DEFAULT_RING_ID {
+            self.sc_ring(&new_sc_address).set(ring_id);
+        }
+        self.record_entry(&new_sc_address, payment_token, payment);
+        self.set_contract_active(&new_sc_address, true);
 
         // Enregistrer l'index du SC (1-based: le premier SC a l'index 1)
         let sc_index = self.peripheral_contracts().len() as u64;
@@ -1140,42 +2905,63 @@ pub trait CircleOfLifeCenter {
 
     /// Demarre le cycle quotidien - envoie le circulation_amount au premier SC
     /// Le caller recoit un bonus XCIRCLEX si le cycle se termine avec succes
+    /// Chaque cercle a son propre cycle quotidien, independant des autres
     #[endpoint(startDailyCycle)]
-    fn start_daily_cycle(&self) {
+    fn start_daily_cycle(&self, ring_id: OptionalValue<u64>) {
         self.require_not_paused();
+        let ring_id = self.resolve_ring(ring_id);
 
         let caller = self.blockchain().get_caller();
         let current_day = self.get_current_day();
-        let last_day = self.cycle_day().get();
+        let last_day = self.cycle_day(ring_id).get();
 
         require!(current_day > last_day, "Cycle deja demarre aujourd'hui");
+        require!(
+            !self.settlement_pending(ring_id).get(),
+            "Reglement du cycle precedent en cours - appelez settleCycle"
+        );
 
-        let active_contracts = self.get_active_contracts();
-        require!(!active_contracts.is_empty(), "Aucun SC actif");
+        // Figer l'ordre de passage de l'epoch: les entrees/sorties pendant
+        // le cycle ne decalent plus la rotation
+        let rotation_len = self.freeze_rotation(ring_id, self.cycle_epoch(ring_id).get());
+        require!(rotation_len > 0, "Aucun SC actif");
+
+        // Politique dynamique: montant recalcule pour ce cycle selon la rotation figee
+        let policy = self.get_circulation_policy(ring_id);
+        if policy != CirculationPolicy::Fixed {
+            let computed = self.compute_circulation_amount(ring_id, &policy, rotation_len as u64);
+            self.circulation_amount(ring_id).set(&computed);
+        }
 
         // Utiliser le circulation_amount defini (pas le solde total)
-        let circulation = self.circulation_amount().get();
+        let circulation = self.circulation_amount(ring_id).get();
         require!(circulation > BigUint::zero(), "Montant circulant non defini");
 
-        let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
-        require!(balance >= circulation, "Solde SC0 insuffisant pour le montant circulant");
+        // Hors montants circulants des autres cercles et EGLD en attente de distribution
+        let token = self.get_circulation_token(ring_id);
+        require!(
+            self.get_ring_treasury(ring_id) >= circulation,
+            "Solde SC0 insuffisant pour le montant circulant"
+        );
 
         // Enregistrer qui a demarre le cycle (pour le bonus)
-        self.cycle_starter().set(&caller);
+        self.cycle_starter(ring_id).set(&caller);
 
         // NOTE: L'epoch est incremente quand le cycle se termine (succes ou echec)
         // donc PAS besoin de l'incrementer ici - les pre-signatures faites apres
         // la fin du cycle precedent restent valides pour ce nouveau cycle
 
-        // Envoyer uniquement le circulation_amount au premier SC actif
-        let first_sc = active_contracts.get(0).clone();
-        self.send().direct_egld(&first_sc, &circulation);
+        // Envoyer uniquement le circulation_amount au premier SC de la rotation
+        let first_sc = self.rotation_sc_at(ring_id, 0);
+        self.send().direct(&first_sc, &token, 0, &circulation);
 
-        self.current_cycle_index().set(0usize);
-        self.cycle_day().set(current_day);
-        self.cycle_holder().set(&first_sc);
+        self.current_cycle_index(ring_id).set(0usize);
+        self.cycle_day(ring_id).set(current_day);
+        self.cycle_holder(ring_id).set(&first_sc);
+        self.hop_started_at(ring_id).set(self.blockchain().get_block_timestamp());
+        self.open_cycle_record(ring_id, &caller, current_day);
 
-        self.cycle_started_event(current_day, &circulation);
+        self.cycle_started_event(current_day, ring_id, &circulation);
         self.cycle_starter_event(&caller, current_day);
         self.transfer_event(&self.blockchain().get_sc_address(), &first_sc, &circulation);
     }
@@ -1183,11 +2969,11 @@ pub trait CircleOfLifeCenter {
     /// Pre-signe pour participer au cycle (peut etre fait a l'avance)
     /// Le transfert s'executera automatiquement quand c'est le tour du membre
     #[endpoint(preSign)]
-    fn pre_sign(&self) {
+    fn pre_sign(&self, on_behalf_of: OptionalValue<ManagedAddress>) {
         self.require_not_paused();
 
-        let caller = self.blockchain().get_caller();
-        let current_epoch = self.cycle_epoch().get();
+        // Membre pour lequel on agit (le caller, ou le membre qui l'a delegue)
+        let caller = self.get_acting_member(on_behalf_of, DelegateScope::SignOnly);
 
         // Verifier que le caller a un SC
         require!(
@@ -1196,6 +2982,7 @@ pub trait CircleOfLifeCenter {
         );
 
         let caller_sc = self.member_contract(&caller).get();
+        let current_epoch = self.cycle_epoch(self.get_sc_ring(&caller_sc)).get();
 
         // Verifier que le SC est actif
         require!(
@@ -1228,10 +3015,11 @@ pub trait CircleOfLifeCenter {
     /// Active l'auto-sign permanent (pre-signature automatique pour tous les cycles futurs)
     /// Peut etre desactive a tout moment avec disableAutoSign
     #[endpoint(enableAutoSign)]
-    fn enable_auto_sign(&self) {
+    fn enable_auto_sign(&self, on_behalf_of: OptionalValue<ManagedAddress>) {
         self.require_not_paused();
 
-        let caller = self.blockchain().get_caller();
+        // Membre pour lequel on agit (le caller, ou le membre qui l'a delegue)
+        let caller = self.get_acting_member(on_behalf_of, DelegateScope::SignOnly);
 
         // Verifier que le caller a un SC
         require!(
@@ -1258,12 +3046,13 @@ pub trait CircleOfLifeCenter {
     /// Active l'auto-sign pour les N prochains cycles
     /// @param num_cycles: nombre de cycles a pre-signer (1-365)
     #[endpoint(enableAutoSignForCycles)]
-    fn enable_auto_sign_for_cycles(&self, num_cycles: u64) {
+    fn enable_auto_sign_for_cycles(&self, num_cycles: u64, on_behalf_of: OptionalValue<ManagedAddress>) {
         self.require_not_paused();
 
         require!(num_cycles >= 1 && num_cycles <= 365, "Nombre de cycles invalide (1-365)");
 
-        let caller = self.blockchain().get_caller();
+        // Membre pour lequel on agit (le caller, ou le membre qui l'a delegue)
+        let caller = self.get_acting_member(on_behalf_of, DelegateScope::SignOnly);
 
         // Verifier que le caller a un SC
         require!(
@@ -1279,7 +3068,7 @@ pub trait CircleOfLifeCenter {
             "Votre SC n'est pas actif"
         );
 
-        let current_epoch = self.cycle_epoch().get();
+        let current_epoch = self.cycle_epoch(self.get_sc_ring(&caller_sc)).get();
         let until_epoch = current_epoch + num_cycles;
 
         // Desactiver l'auto-sign permanent si actif
@@ -1292,8 +3081,9 @@ pub trait CircleOfLifeCenter {
 
     /// Desactive l'auto-sign (permanent et limite)
     #[endpoint(disableAutoSign)]
-    fn disable_auto_sign(&self) {
-        let caller = self.blockchain().get_caller();
+    fn disable_auto_sign(&self, on_behalf_of: OptionalValue<ManagedAddress>) {
+        // Membre pour lequel on agit (le caller, ou le membre qui l'a delegue)
+        let caller = self.get_acting_member(on_behalf_of, DelegateScope::SignOnly);
 
         // Verifier que le caller a un SC
         require!(
@@ -1334,23 +3124,24 @@ pub trait CircleOfLifeCenter {
     /// Traite les transferts en attente pour les membres qui ont pre-signe
     /// Peut etre appele par n'importe qui (permissionless)
     #[endpoint(processNextTransfer)]
-    fn process_next_transfer(&self) {
+    fn process_next_transfer(&self, ring_id: OptionalValue<u64>) {
         self.require_not_paused();
+        let ring_id = self.resolve_ring(ring_id);
 
-        let current_day = self.cycle_day().get();
+        let current_day = self.cycle_day(ring_id).get();
         require!(current_day > 0, "Cycle non demarre - appelez startDailyCycle");
 
         // Verifier qu'il y a un holder actuel (cycle en cours)
-        require!(!self.cycle_holder().is_empty(), "Pas de cycle en cours");
+        require!(!self.cycle_holder(ring_id).is_empty(), "Pas de cycle en cours");
 
-        let active_contracts = self.get_active_contracts();
-        require!(!active_contracts.is_empty(), "Aucun SC actif");
+        let current_index = self.current_cycle_index(ring_id).get();
+        let current_epoch = self.cycle_epoch(ring_id).get();
 
-        let current_index = self.current_cycle_index().get();
-        let current_epoch = self.cycle_epoch().get();
+        let rotation_len = self.rotation_snapshot(ring_id).len();
+        require!(rotation_len > 0, "Aucun SC actif");
 
-        let expected_index = current_index % active_contracts.len();
-        let current_sc = active_contracts.get(expected_index).clone();
+        let expected_index = current_index % rotation_len;
+        let current_sc = self.rotation_sc_at(ring_id, expected_index);
 
         // Verifier que le SC actuel a pre-signe (manuellement ou auto-sign)
         require!(
@@ -1365,42 +3156,42 @@ pub trait CircleOfLifeCenter {
         );
 
         // Executer le transfert
-        self.execute_transfer(&current_sc, expected_index, &active_contracts, current_epoch, current_day);
+        self.execute_transfer(ring_id, &current_sc, expected_index, current_epoch, current_day);
     }
 
     /// Traite TOUS les transferts en attente en une seule transaction
     /// Boucle sur tous les SC qui ont pre-signe (manuellement ou auto-sign) et execute leurs transferts
     /// Retourne le nombre de transferts effectues
     #[endpoint(processAllPendingTransfers)]
-    fn process_all_pending_transfers(&self) -> u32 {
+    fn process_all_pending_transfers(&self, ring_id: OptionalValue<u64>) -> u32 {
         self.require_not_paused();
+        let ring_id = self.resolve_ring(ring_id);
 
-        let current_day = self.cycle_day().get();
+        let current_day = self.cycle_day(ring_id).get();
         require!(current_day > 0, "Cycle non demarre - appelez startDailyCycle");
 
         // Verifier qu'il y a un holder actuel (cycle en cours)
-        if self.cycle_holder().is_empty() {
+        if self.cycle_holder(ring_id).is_empty() {
             return 0u32; // Cycle termine, rien a faire
         }
 
-        let active_contracts = self.get_active_contracts();
-        if active_contracts.is_empty() {
+        let current_epoch = self.cycle_epoch(ring_id).get();
+        let rotation_len = self.rotation_snapshot(ring_id).len();
+        if rotation_len == 0 {
             return 0u32;
         }
-
-        let current_epoch = self.cycle_epoch().get();
         let mut transfers_done = 0u32;
-        let max_iterations = active_contracts.len(); // Securite: max une iteration par SC actif
+        let max_iterations = rotation_len; // Securite: max une iteration par SC de la rotation
 
         for _ in 0..max_iterations {
             // Verifier si le cycle est toujours en cours
-            if self.cycle_holder().is_empty() {
+            if self.cycle_holder(ring_id).is_empty() {
                 break; // Cycle termine
             }
 
-            let current_index = self.current_cycle_index().get();
-            let expected_index = current_index % active_contracts.len();
-            let current_sc = active_contracts.get(expected_index).clone();
+            let current_index = self.current_cycle_index(ring_id).get();
+            let expected_index = current_index % rotation_len;
+            let current_sc = self.rotation_sc_at(ring_id, expected_index);
 
             // Verifier que le SC actuel a pre-signe (manuellement ou auto-sign)
             if !self.is_effectively_pre_signed(&current_sc, current_epoch) {
@@ -1413,24 +3204,43 @@ pub trait CircleOfLifeCenter {
             }
 
             // Executer le transfert
-            self.execute_transfer(&current_sc, expected_index, &active_contracts, current_epoch, current_day);
+            self.execute_transfer(ring_id, &current_sc, expected_index, current_epoch, current_day);
             transfers_done += 1;
         }
 
+        // Pourboire du keeper, uniquement si des transferts ont ete effectues
+        if transfers_done > 0 {
+            let keeper = self.blockchain().get_caller();
+            let mut stats = self.get_keeper_stats_or_default(&keeper);
+            stats.transfers_processed += transfers_done as u64;
+            self.keeper_stats(&keeper).set(stats);
+            self.pay_keeper_tip(&keeper, transfers_done as u64, ring_id, current_epoch);
+        }
+
         transfers_done
     }
 
+    /// Avance le reglement du dernier cycle complete (synchro des cycles vers le NFT)
+    /// Peut etre appele par n'importe qui (permissionless), par lots de max_items SC
+    /// Retourne le nombre de SC restant a regler
+    #[endpoint(settleCycle)]
+    fn settle_cycle(&self, max_items: usize, ring_id: OptionalValue<u64>) -> usize {
+        self.require_not_paused();
+        let ring_id = self.resolve_ring(ring_id);
+        require!(self.settlement_pending(ring_id).get(), "Aucun reglement en attente");
+        require!(max_items > 0, "max_items doit etre > 0");
+
+        self.settle_cycle_items(ring_id, max_items);
+        self.get_settlement_remaining(OptionalValue::Some(ring_id))
+    }
+
     /// Signe et transfere au prochain SC (version classique - doit etre son tour)
     #[endpoint(signAndForward)]
-    fn sign_and_forward(&self) {
+    fn sign_and_forward(&self, on_behalf_of: OptionalValue<ManagedAddress>) {
         self.require_not_paused();
 
-        let caller = self.blockchain().get_caller();
-        let current_day = self.cycle_day().get();
-        let current_index = self.current_cycle_index().get();
-        let current_epoch = self.cycle_epoch().get();
-
-        require!(current_day > 0, "Cycle non demarre - appelez startDailyCycle");
+        // Membre pour lequel on agit (le caller, ou le membre qui l'a delegue)
+        let caller = self.get_acting_member(on_behalf_of, DelegateScope::SignOnly);
 
         // Verifier que le caller a un SC
         require!(
@@ -1439,13 +3249,19 @@ pub trait CircleOfLifeCenter {
         );
 
         let caller_sc = self.member_contract(&caller).get();
+        let ring_id = self.get_sc_ring(&caller_sc);
+        let current_day = self.cycle_day(ring_id).get();
+        let current_index = self.current_cycle_index(ring_id).get();
+        let current_epoch = self.cycle_epoch(ring_id).get();
+
+        require!(current_day > 0, "Cycle non demarre - appelez startDailyCycle");
 
         // Verifier que c'est le tour de ce SC
-        let active_contracts = self.get_active_contracts();
-        require!(!active_contracts.is_empty(), "Aucun SC actif");
+        let rotation_len = self.rotation_snapshot(ring_id).len();
+        require!(rotation_len > 0, "Aucun SC actif");
 
-        let expected_index = current_index % active_contracts.len();
-        let expected_sc = active_contracts.get(expected_index).clone();
+        let expected_index = current_index % rotation_len;
+        let expected_sc = self.rotation_sc_at(ring_id, expected_index);
 
         require!(caller_sc == expected_sc, "Ce n'est pas votre tour");
 
@@ -1456,54 +3272,84 @@ pub trait CircleOfLifeCenter {
         );
 
         // Executer le transfert
-        self.execute_transfer(&caller_sc, expected_index, &active_contracts, current_epoch, current_day);
+        self.execute_transfer(ring_id, &caller_sc, expected_index, current_epoch, current_day);
 
         // Apres le transfert, verifier si le prochain a deja pre-signe
         // et traiter automatiquement en chaine
-        self.process_pending_transfers();
+        self.process_pending_transfers(ring_id);
     }
 
     /// Execute le transfert pour un SC donne
     fn execute_transfer(
         &self,
+        ring_id: u64,
         from_sc: &ManagedAddress,
         from_index: usize,
-        active_contracts: &ManagedVec<ManagedAddress>,
         current_epoch: u64,
         current_day: u64
     ) {
         // Enregistrer la signature avec l'epoch actuel
         self.last_signature(from_sc, current_epoch).set(self.blockchain().get_block_timestamp());
 
-        // Calculer le suivant
-        let next_index = (from_index + 1) % active_contracts.len();
-        let amount = self.circulation_amount().get();
+        let next_sc = self.forward_circulation(ring_id, from_sc, from_index, current_epoch, current_day, false);
+        self.signature_event(from_sc, &next_sc, &self.circulation_amount(ring_id).get());
+    }
+
+    /// Fait passer le montant circulant du SC a l'index from_index au suivant de la rotation
+    /// (ou a SC0 en fin de cercle, qui complete alors le cycle). Retourne la destination
+    fn forward_circulation(
+        &self,
+        ring_id: u64,
+        from_sc: &ManagedAddress,
+        from_index: usize,
+        current_epoch: u64,
+        current_day: u64,
+        skipped: bool
+    ) -> ManagedAddress {
+        // Calculer le suivant dans l'ordre fige au demarrage du cycle
+        let rotation_len = self.rotation_snapshot(ring_id).len();
+        let next_index = (from_index + 1) % rotation_len;
+        let amount = self.circulation_amount(ring_id).get();
 
         // Determiner la destination
         let next_sc = if next_index == 0 {
             // Cycle complet - retour a SC0
             self.blockchain().get_sc_address()
         } else {
-            active_contracts.get(next_index).clone()
+            self.rotation_sc_at(ring_id, next_index)
         };
 
         // Transferer depuis le SC peripherique vers le suivant
         self.call_peripheral_transfer(from_sc, &next_sc, &amount);
+        self.record_cycle_hop(ring_id, current_epoch, from_sc, &next_sc, skipped);
 
         // Mettre a jour l'index et le holder
         if next_index == 0 {
             // Cycle complet - incrementer le compteur de cycles reussis
-            let completed = self.cycles_completed().get();
-            self.cycles_completed().set(completed + 1);
-
-            // Incrementer le compteur de cycles reussis pour TOUS les SC qui ont participe
-            // ET distribuer les recompenses
-            let reward_per_sc = self.calculate_reward_per_participant(active_contracts.len());
+            let completed = self.cycles_completed(ring_id).get();
+            self.cycles_completed(ring_id).set(completed + 1);
+
+            // Pool et burn avant recompenses, pour l'historique du cycle
+            let pool_before = self.rewards_pool().get();
+            let burned_before = self.total_burned().get();
+
+            // Recompense de base par SC participant: comptabilisee via l'index
+            // cumulatif (voir checkpoint_rewards), sans ecriture par membre
+            // Les SC sautes ne participent pas (voir skipHolder)
+            let participants = rotation_len - self.cycle_skipped_count(ring_id).get();
+            let reward_per_sc = if participants > 0 {
+                self.calculate_reward_per_participant(participants)
+            } else {
+                BigUint::zero()
+            };
 
             // ═══════════════════════════════════════════════════════════════
             // BONUS π% - Pour celui qui complete un cycle "cercle complet" (360, 720, ...)
             // ═══════════════════════════════════════════════════════════════
-            let cycles_now = completed + 1; // Le cycle vient d'etre complete
+            // Les paliers (halving, bonus π%, fenetres de claim) suivent le total tous cercles confondus
+            let cycles_now = self.get_total_cycles_completed(); // Le cycle vient d'etre complete
+            self.open_rolling_claim_window(cycles_now);
+
             if cycles_now > 0 && cycles_now % HALVING_PERIOD == 0 {
                 // C'est un cycle cercle complet! Donner le bonus π% a celui qui l'a complete
                 let reward_per_cycle = self.calculate_option_f_reward();
@@ -1525,44 +3371,19 @@ pub trait CircleOfLifeCenter {
                 }
             }
 
-            // Verifier si le contrat NFT est configure pour la synchronisation
-            let nft_configured = !self.nft_contract().is_empty();
-
-            for i in 0..active_contracts.len() {
-                let sc = active_contracts.get(i).clone();
-                let sc_completed = self.sc_cycles_completed(&sc).get();
-                let new_cycles = sc_completed + 1;
-                self.sc_cycles_completed(&sc).set(new_cycles);
-
-                // Ajouter les recompenses en attente pour ce SC
-                // Bonus appliques:
-                // - Pioneer: +3.14% pour les 360 premiers SC
-                // - Deposit: +1% par EGLD depose (max 360%)
-                if reward_per_sc > BigUint::zero() {
-                    let final_reward = self.calculate_final_reward_with_bonuses(&reward_per_sc, &sc);
-
-                    let current_pending = self.pending_rewards(&sc).get();
-                    self.pending_rewards(&sc).set(&(current_pending + &final_reward));
-                }
-
-                // Synchroniser les cycles vers le contrat NFT si configure
-                if nft_configured {
-                    if !self.contract_owner(&sc).is_empty() {
-                        let member_wallet = self.contract_owner(&sc).get();
-                        let nft_address = self.nft_contract().get();
-                        // Appel async pour ne pas bloquer si le contrat NFT echoue
-                        let _: IgnoreValue = self.nft_proxy(nft_address)
-                            .update_member_cycles(member_wallet, new_cycles)
-                            .execute_on_dest_context();
-                    }
-                }
+            // Ouvrir le reglement du cycle: synchro des cycles vers le contrat NFT
+            // pour chaque SC, traitee par lots via settleCycle pour borner le gas
+            if !self.nft_contract().is_empty() {
+                self.settlement_epoch(ring_id).set(current_epoch);
+                self.settlement_cursor(ring_id).set(1usize);
+                self.settlement_pending(ring_id).set(true);
             }
 
             // ═══════════════════════════════════════════════════════════════
             // STARTER BONUS - Recompense pour celui qui a demarre le cycle
             // ═══════════════════════════════════════════════════════════════
-            if !self.cycle_starter().is_empty() {
-                let starter = self.cycle_starter().get();
+            if !self.cycle_starter(ring_id).is_empty() {
+                let starter = self.cycle_starter(ring_id).get();
                 let bonus_percentage = self.starter_bonus_percentage().get();
 
                 // Verifier que le bonus est configure et que le starter est membre
@@ -1572,7 +3393,7 @@ pub trait CircleOfLifeCenter {
                     // Calculer le bonus: (reward_per_cycle / nb_SC) * bonus_percentage / 10000
                     // Le bonus est base sur la recompense par SC, comme les autres bonus
                     let reward_per_cycle = self.calculate_option_f_reward();
-                    let active_count = self.get_active_contracts().len() as u64;
+                    let active_count = rotation_len as u64;
                     let base_per_sc = if active_count > 0 {
                         &reward_per_cycle / active_count
                     } else {
@@ -1602,48 +3423,187 @@ pub trait CircleOfLifeCenter {
                 }
 
                 // Effacer le starter pour le prochain cycle
-                self.cycle_starter().clear();
+                self.cycle_starter(ring_id).clear();
             }
 
-            self.cycle_completed_event(current_day);
-            self.cycle_holder().clear();
+            let burned = self.total_burned().get() - burned_before;
+            let pool_after = self.rewards_pool().get();
+            let rewards_paid = if pool_before > &pool_after + &burned {
+                pool_before - pool_after - &burned
+            } else {
+                BigUint::zero()
+            };
+            self.close_cycle_record(ring_id, current_epoch, CycleStatus::Completed, None, rewards_paid, burned);
+
+            self.cycle_completed_event(current_day, ring_id);
+            self.cycle_holder(ring_id).clear();
 
             // IMPORTANT: Incrementer l'epoch pour invalider les anciennes pre-signatures
             // Cela permet aux membres de pre-signer pour le prochain cycle
-            let epoch = self.cycle_epoch().get();
-            self.cycle_epoch().set(epoch + 1);
+            self.advance_cycle_epoch(ring_id);
+            let next_epoch = self.cycle_epoch(ring_id).get();
+            self.epoch_reward_index(ring_id, next_epoch).update(|index| {
+                index.reward_per_share += &reward_per_sc;
+                index.completed_cycles += 1;
+            });
+
+            // Premier lot du reglement (suffit pour les petits cercles)
+            self.settle_cycle_items(ring_id, self.get_auto_settlement_items());
         } else {
-            self.current_cycle_index().set(next_index);
-            self.cycle_holder().set(&next_sc);
+            self.current_cycle_index(ring_id).set(next_index);
+            self.cycle_holder(ring_id).set(&next_sc);
+            self.hop_started_at(ring_id).set(self.blockchain().get_block_timestamp());
+        }
+
+        next_sc
+    }
+
+    /// Ouvre l'historique du cycle qui demarre et elague les plus anciens
+    /// (au plus MAX_CYCLE_RECORDS_PRUNED par demarrage, voir setCycleHistoryRetention)
+    fn open_cycle_record(&self, ring_id: u64, starter: &ManagedAddress, day: u64) {
+        let epoch = self.cycle_epoch(ring_id).get();
+        self.cycle_record(ring_id, epoch).set(CycleRecord {
+            day,
+            starter: starter.clone(),
+            started_at: self.blockchain().get_block_timestamp(),
+            status: CycleStatus::Running,
+            ended_at: 0,
+            failed_sc: None,
+            rewards_paid: BigUint::zero(),
+            burned: BigUint::zero(),
+        });
+        self.cycle_hops(ring_id, epoch).clear();
+
+        if self.cycle_history_start(ring_id).is_empty() {
+            self.cycle_history_start(ring_id).set(epoch);
+        }
+        let retention = self.cycle_history_retention().get();
+        if retention == 0 {
+            return;
+        }
+
+        let mut oldest = self.cycle_history_start(ring_id).get();
+        let mut pruned = 0usize;
+        while oldest + retention <= epoch && pruned < MAX_CYCLE_RECORDS_PRUNED {
+            self.cycle_record(ring_id, oldest).clear();
+            self.cycle_hops(ring_id, oldest).clear();
+            oldest += 1;
+            pruned += 1;
+        }
+        self.cycle_history_start(ring_id).set(oldest);
+    }
+
+    fn record_cycle_hop(
+        &self,
+        ring_id: u64,
+        epoch: u64,
+        from: &ManagedAddress,
+        to: &ManagedAddress,
+        skipped: bool
+    ) {
+        // Cycle demarre avant l'historique: rien a completer
+        if self.cycle_record(ring_id, epoch).is_empty() {
+            return;
+        }
+        self.cycle_hops(ring_id, epoch).push(&CycleHop {
+            from: from.clone(),
+            to: to.clone(),
+            timestamp: self.blockchain().get_block_timestamp(),
+            skipped,
+        });
+    }
+
+    fn close_cycle_record(
+        &self,
+        ring_id: u64,
+        epoch: u64,
+        status: CycleStatus,
+        failed_sc: Option<ManagedAddress>,
+        rewards_paid: BigUint,
+        burned: BigUint
+    ) {
+        let record = self.cycle_record(ring_id, epoch);
+        if record.is_empty() {
+            return;
+        }
+        record.update(|record| {
+            record.status = status;
+            record.ended_at = self.blockchain().get_block_timestamp();
+            record.failed_sc = failed_sc;
+            record.rewards_paid = rewards_paid;
+            record.burned = burned;
+        });
+    }
+
+    /// Regle au plus `max_items` SC du dernier cycle complete
+    /// Retourne le nombre de SC regles
+    fn settle_cycle_items(&self, ring_id: u64, max_items: usize) -> usize {
+        if !self.settlement_pending(ring_id).get() {
+            return 0;
+        }
+
+        let total = self.rotation_snapshot(ring_id).len();
+        let nft_address = self.nft_contract().get();
+
+        let mut cursor = self.settlement_cursor(ring_id).get();
+        let mut settled = 0usize;
+        while cursor <= total && settled < max_items {
+            let sc = self.rotation_snapshot(ring_id).get(cursor);
+
+            // Synchroniser les cycles vers le contrat NFT
+            if !self.contract_owner(&sc).is_empty() {
+                let member_wallet = self.contract_owner(&sc).get();
+                let cycles = self.get_sc_cycles_completed(&sc);
+                self.nft_proxy(nft_address.clone())
+                    .update_member_cycles(member_wallet, cycles)
+                    .sync_call();
+            }
+
+            cursor += 1;
+            settled += 1;
+        }
+        self.settlement_cursor(ring_id).set(cursor);
+
+        if cursor > total {
+            self.settlement_pending(ring_id).clear();
+            self.cycle_settled_event(self.settlement_epoch(ring_id).get(), total as u64);
         }
 
-        self.signature_event(from_sc, &next_sc, &amount);
+        settled
+    }
+
+    /// Nombre de SC regles automatiquement a la completion du cycle
+    fn get_auto_settlement_items(&self) -> usize {
+        if self.auto_settlement_items().is_empty() {
+            return DEFAULT_AUTO_SETTLEMENT_ITEMS;
+        }
+        self.auto_settlement_items().get()
     }
 
     /// Traite automatiquement les transferts en chaine pour les membres qui ont pre-signe
-    fn process_pending_transfers(&self) {
-        let current_epoch = self.cycle_epoch().get();
-        let current_day = self.cycle_day().get();
+    fn process_pending_transfers(&self, ring_id: u64) {
+        let current_epoch = self.cycle_epoch(ring_id).get();
+        let current_day = self.cycle_day(ring_id).get();
 
-        // Obtenir le nombre de contrats actifs pour la limite d'iterations
-        let active_contracts = self.get_active_contracts();
-        if active_contracts.is_empty() {
+        // Ordre de rotation fige (et limite d'iterations)
+        let rotation_len = self.rotation_snapshot(ring_id).len();
+        if rotation_len == 0 {
             return;
         }
 
-        // Maximum = nombre de contrats actifs (pour couvrir tout le cycle)
+        // Maximum = nombre de SC de la rotation (pour couvrir tout le cycle)
         let mut iterations = 0usize;
-        let max_iterations = active_contracts.len();
+        let max_iterations = rotation_len;
 
         while iterations < max_iterations {
             // Verifier si le cycle est toujours en cours
-            if self.cycle_holder().is_empty() {
+            if self.cycle_holder(ring_id).is_empty() {
                 break;
             }
 
-            let current_index = self.current_cycle_index().get();
-            let expected_index = current_index % active_contracts.len();
-            let current_sc = active_contracts.get(expected_index).clone();
+            let current_index = self.current_cycle_index(ring_id).get();
+            let expected_index = current_index % rotation_len;
+            let current_sc = self.rotation_sc_at(ring_id, expected_index);
 
             // Verifier si le prochain membre a pre-signe (manuel ou auto) ET n'a pas encore ete traite
             if !self.is_effectively_pre_signed(&current_sc, current_epoch) {
@@ -1655,7 +3615,7 @@ pub trait CircleOfLifeCenter {
             }
 
             // Executer le transfert automatiquement
-            self.execute_transfer(&current_sc, expected_index, &active_contracts, current_epoch, current_day);
+            self.execute_transfer(ring_id, &current_sc, expected_index, current_epoch, current_day);
 
             iterations += 1;
         }
@@ -1689,6 +3649,9 @@ pub trait CircleOfLifeCenter {
     #[proxy]
     fn lp_locker_proxy(&self, sc_address: ManagedAddress) -> lp_locker_proxy::Proxy<Self::Api>;
 
+    #[proxy]
+    fn price_oracle_proxy(&self, sc_address: ManagedAddress) -> price_oracle_proxy::Proxy<Self::Api>;
+
     // ═══════════════════════════════════════════════════════════════
     // DISTRIBUTION V4 - HELPERS
     // ═══════════════════════════════════════════════════════════════
@@ -1768,6 +3731,175 @@ pub trait CircleOfLifeCenter {
         // car les appels async cross-shard necessitent ~200M gas
     }
 
+    /// Repartit un paiement EGLD selon la table de distribution.
+    /// La derniere part recoit le reste des arrondis
+    fn process_distribution_table(&self, payment: &BigUint) {
+        let shares = self.distribution_table().get();
+        let last_index = shares.len() - 1;
+
+        let mut remaining = payment.clone();
+        let mut treasury = BigUint::zero();
+        let mut liquidity = BigUint::zero();
+        let mut dao = BigUint::zero();
+        for (i, share) in shares.iter().enumerate() {
+            let amount = if i == last_index {
+                remaining.clone()
+            } else {
+                payment * share.bps / BPS_BASE
+            };
+            remaining -= &amount;
+            if amount == BigUint::zero() {
+                continue;
+            }
+
+            match &share.target {
+                DistributionTarget::Treasury => {
+                    self.process_treasury_distribution(&amount);
+                    treasury += &amount;
+                },
+                DistributionTarget::Liquidity => {
+                    self.accumulate_for_liquidity(&amount);
+                    liquidity += &amount;
+                },
+                DistributionTarget::Dao => {
+                    self.send_to_dao(&amount);
+                    dao += &amount;
+                },
+                DistributionTarget::StakingRewards => {
+                    if !self.staking_contract_address().is_empty() {
+                        self.send().direct_egld(&self.staking_contract_address().get(), &amount);
+                        self.total_distributed_staking().update(|total| *total += &amount);
+                    }
+                },
+                DistributionTarget::BurnSwap => {
+                    self.pending_burn_swap_egld().update(|pending| *pending += &amount);
+                },
+                DistributionTarget::Address(address) => {
+                    self.send().direct_egld(address, &amount);
+                },
+            }
+            self.distribution_share_event(&share.target, &amount);
+        }
+
+        self.distribution_processed_event(payment, &treasury, &liquidity, &dao);
+    }
+
+    /// Repartit des frais ESDT (cercle a token ESDT) selon la table active.
+    /// Les parts Treasury, Liquidity et BurnSwap (pipelines EGLD) restent dans SC0,
+    /// retirables via withdrawToken; les autres sont envoyees dans le token recu
+    fn process_esdt_distribution(&self, token: &EgldOrEsdtTokenIdentifier, payment: &BigUint) {
+        if !self.distribution_enabled().get() {
+            return;
+        }
+
+        let shares = self.get_active_distribution_table();
+        let last_index = shares.len() - 1;
+        let mut remaining = payment.clone();
+        for (i, share) in shares.iter().enumerate() {
+            let amount = if i == last_index {
+                remaining.clone()
+            } else {
+                payment * share.bps / BPS_BASE
+            };
+            remaining -= &amount;
+            if amount == BigUint::zero() {
+                continue;
+            }
+
+            let recipient = match &share.target {
+                DistributionTarget::Dao if !self.dao_contract_address().is_empty() => {
+                    self.dao_contract_address().get()
+                },
+                DistributionTarget::StakingRewards if !self.staking_contract_address().is_empty() => {
+                    self.staking_contract_address().get()
+                },
+                DistributionTarget::Address(address) => address.clone(),
+                _ => continue,
+            };
+            self.send().direct(&recipient, token, 0, &amount);
+            self.esdt_distribution_share_event(token, &share.target, &amount);
+        }
+    }
+
+    /// Repartit des XCIRCLEX recuperes d'un lock protocolaire selon la table active:
+    /// DAO, staking et adresses les recoivent, BurnSwap les brule directement (pas de rachat).
+    /// Les parts qui resteraient dans SC0 (Treasury, Liquidity, destination non configuree)
+    /// vont au pool de recompenses si XCIRCLEX est le token de recompense, sinon sont brulees
+    fn distribute_recovered_xcirclex(&self, xcirclex_token: &TokenIdentifier, amount: &BigUint) {
+        let token = EgldOrEsdtTokenIdentifier::esdt(xcirclex_token.clone());
+        let shares = self.get_active_distribution_table();
+        let last_index = shares.len() - 1;
+        let mut remaining = amount.clone();
+        let mut kept = BigUint::zero();
+        let mut to_burn = BigUint::zero();
+        for (i, share) in shares.iter().enumerate() {
+            let share_amount = if i == last_index {
+                remaining.clone()
+            } else {
+                amount * share.bps / BPS_BASE
+            };
+            remaining -= &share_amount;
+            if share_amount == BigUint::zero() {
+                continue;
+            }
+
+            let recipient = match &share.target {
+                DistributionTarget::Dao if !self.dao_contract_address().is_empty() => {
+                    Some(self.dao_contract_address().get())
+                },
+                DistributionTarget::StakingRewards if !self.staking_contract_address().is_empty() => {
+                    Some(self.staking_contract_address().get())
+                },
+                DistributionTarget::Address(address) => Some(address.clone()),
+                DistributionTarget::BurnSwap => {
+                    to_burn += &share_amount;
+                    None
+                },
+                _ => {
+                    kept += &share_amount;
+                    None
+                },
+            };
+            if let Some(recipient) = recipient {
+                self.send().direct_esdt(&recipient, xcirclex_token, 0, &share_amount);
+            }
+            self.esdt_distribution_share_event(&token, &share.target, &share_amount);
+        }
+
+        if kept > BigUint::zero() {
+            if !self.reward_token_id().is_empty() && self.reward_token_id().get() == *xcirclex_token {
+                self.rewards_pool().update(|pool| *pool += &kept);
+            } else {
+                to_burn += &kept;
+            }
+        }
+        if to_burn > BigUint::zero() {
+            self.send().esdt_local_burn(xcirclex_token, 0, &to_burn);
+            self.total_burned().update(|total| *total += &to_burn);
+        }
+    }
+
+    /// Table active: celle configuree par la DAO, sinon la repartition historique
+    /// arrondie en BPS du total (3.14% / 67.80% / 29.06%)
+    fn get_active_distribution_table(&self) -> ManagedVec<DistributionShare<Self::Api>> {
+        if !self.distribution_table().is_empty() {
+            return self.distribution_table().get();
+        }
+
+        let remaining_bps = BPS_BASE - TREASURY_PERCENTAGE_BPS;
+        let liquidity_bps = remaining_bps * LIQUIDITY_PERCENTAGE_BPS / BPS_BASE;
+        let mut shares = ManagedVec::new();
+        shares.push(DistributionShare { target: DistributionTarget::Treasury, bps: TREASURY_PERCENTAGE_BPS });
+        shares.push(DistributionShare { target: DistributionTarget::Liquidity, bps: liquidity_bps });
+        shares.push(DistributionShare { target: DistributionTarget::Dao, bps: remaining_bps - liquidity_bps });
+        shares
+    }
+
+    /// EGLD de SC0 deja engages par la distribution (liquidite et rachat en attente)
+    fn get_pending_distribution_egld(&self) -> BigUint {
+        self.pending_liquidity_egld().get() + self.pending_burn_swap_egld().get()
+    }
+
     /// Traite la distribution complete d'un paiement EGLD
     fn process_egld_distribution(&self, payment: &BigUint) {
         // Verifier si la distribution est activee
@@ -1775,6 +3907,12 @@ pub trait CircleOfLifeCenter {
             return;
         }
 
+        // Table configuree par la DAO, sinon repartition historique
+        if !self.distribution_table().is_empty() {
+            self.process_distribution_table(payment);
+            return;
+        }
+
         // Calculer les montants
         let (treasury, liquidity, dao) = self.calculate_distribution(payment);
 
@@ -1790,23 +3928,114 @@ pub trait CircleOfLifeCenter {
         self.distribution_processed_event(payment, &treasury, &liquidity, &dao);
     }
 
+    // ═══════════════════════════════════════════════════════════════
+    // DELEGATION - Signature par un relayer/bot
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Autorise un delegue a agir pour le SC du caller jusqu'a expires_epoch (exclu)
+    /// Remplace la delegation precedente. Le SC peripherique accepte aussi ce delegue
+    #[endpoint(setDelegate)]
+    fn set_delegate(&self, delegate: ManagedAddress, expires_epoch: u64, scope: DelegateScope) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.member_contract(&caller).is_empty(), "Pas membre");
+        require!(delegate != caller, "Impossible de se deleguer a soi-meme");
+        require!(
+            expires_epoch > self.blockchain().get_block_epoch(),
+            "Epoch d'expiration deja passee"
+        );
+
+        let sc = self.member_contract(&caller).get();
+        self.clear_delegation(&caller, &sc);
+
+        self.sc_delegate(&sc).set(Delegation {
+            delegate: delegate.clone(),
+            expires_epoch,
+            scope,
+        });
+        self.delegated_contracts(&delegate).insert(sc.clone());
+
+        self.delegate_set_event(&caller, &delegate, &sc, expires_epoch, scope);
+    }
+
+    /// Revoque la delegation du SC du caller
+    #[endpoint(revokeDelegate)]
+    fn revoke_delegate(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.member_contract(&caller).is_empty(), "Pas membre");
+
+        let sc = self.member_contract(&caller).get();
+        require!(!self.sc_delegate(&sc).is_empty(), "Aucune delegation");
+        self.clear_delegation(&caller, &sc);
+    }
+
+    /// Supprime la delegation d'un SC (et l'index inverse du delegue)
+    fn clear_delegation(&self, member: &ManagedAddress, sc: &ManagedAddress) {
+        if self.sc_delegate(sc).is_empty() {
+            return;
+        }
+
+        let delegation = self.sc_delegate(sc).take();
+        self.delegated_contracts(&delegation.delegate).swap_remove(sc);
+        self.delegate_revoked_event(member, &delegation.delegate, sc);
+    }
+
+    /// Membre pour lequel le caller agit: lui-meme, ou on_behalf_of s'il
+    /// a delegue au caller une portee suffisante (non expiree)
+    fn get_acting_member(
+        &self,
+        on_behalf_of: OptionalValue<ManagedAddress>,
+        scope: DelegateScope
+    ) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        let member = match on_behalf_of.into_option() {
+            Some(member) => member,
+            None => return caller,
+        };
+        if member == caller {
+            return caller;
+        }
+
+        require!(
+            self.is_valid_delegate(&member, &caller, scope),
+            "Delegation invalide ou expiree"
+        );
+        member
+    }
+
+    fn is_valid_delegate(&self, member: &ManagedAddress, delegate: &ManagedAddress, scope: DelegateScope) -> bool {
+        if self.member_contract(member).is_empty() {
+            return false;
+        }
+        let sc = self.member_contract(member).get();
+        if self.sc_delegate(&sc).is_empty() {
+            return false;
+        }
+
+        let delegation = self.sc_delegate(&sc).get();
+        delegation.delegate == *delegate
+            && self.blockchain().get_block_epoch() < delegation.expires_epoch
+            && (scope == DelegateScope::SignOnly || delegation.scope == DelegateScope::SignAndStatus)
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // GESTION DES STATUTS
     // ═══════════════════════════════════════════════════════════════
 
     #[endpoint(setInactive)]
-    fn set_inactive(&self) {
-        let caller = self.blockchain().get_caller();
+    fn set_inactive(&self, on_behalf_of: OptionalValue<ManagedAddress>) {
+        // Membre pour lequel on agit (le caller, ou le membre qui l'a delegue)
+        let caller = self.get_acting_member(on_behalf_of, DelegateScope::SignAndStatus);
         require!(!self.member_contract(&caller).is_empty(), "Pas membre");
 
         let sc = self.member_contract(&caller).get();
-        self.contract_active(&sc).set(false);
+        self.set_contract_active(&sc, false);
         self.status_changed_event(&sc, false);
     }
 
     #[endpoint(setActive)]
-    fn set_active(&self) {
-        let caller = self.blockchain().get_caller();
+    fn set_active(&self, on_behalf_of: OptionalValue<ManagedAddress>) {
+        // Membre pour lequel on agit (le caller, ou le membre qui l'a delegue)
+        let caller = self.get_acting_member(on_behalf_of, DelegateScope::SignAndStatus);
         require!(!self.member_contract(&caller).is_empty(), "Pas membre");
 
         let sc = self.member_contract(&caller).get();
@@ -1815,36 +4044,221 @@ pub trait CircleOfLifeCenter {
         let ban_until = self.sc_ban_until(&sc).get();
         let current_timestamp = self.blockchain().get_block_timestamp();
         require!(current_timestamp >= ban_until, "Votre SC est temporairement banni");
+        if !self.contract_active(&sc).get() {
+            self.require_ring_has_room(self.get_sc_ring(&sc));
+        }
 
-        self.contract_active(&sc).set(true);
+        self.set_contract_active(&sc, true);
         self.status_changed_event(&sc, true);
     }
 
+    /// Quitte le cercle (hors cycle en cours du cercle du membre):
+    /// - recompenses en attente versees ou rendues au pool selon ExitPolicy
+    ///   (versement immediat, hors fenetre de reclamation)
+    /// - solde du SC peripherique en token de circulation renvoye au membre
+    /// - remboursement degressif des frais d'entree, dans la limite du solde libre de SC0
+    /// Le SC est retire et reutilise si le membre revient via joinCircle
     #[endpoint(leaveCircle)]
     fn leave_circle(&self) {
         let caller = self.blockchain().get_caller();
         require!(!self.member_contract(&caller).is_empty(), "Pas membre");
 
         let sc = self.member_contract(&caller).get();
-        self.contract_active(&sc).set(false);
+        let ring_id = self.get_sc_ring(&sc);
+        require!(
+            self.cycle_holder(ring_id).is_empty(),
+            "Impossible de quitter pendant un cycle"
+        );
+
+        // Hors cycle, la desactivation cloture toute la participation du SC
+        self.set_contract_active(&sc, false);
         self.member_contract(&caller).clear();
+        self.clear_delegation(&caller, &sc);
+        self.pending_membership_transfer(&sc).clear();
+
+        // 1. Recompenses en attente
+        let policy = self.get_exit_policy();
+        let pending = self.pending_rewards(&sc).take();
+        let mut rewards_paid = BigUint::zero();
+        if pending > BigUint::zero() {
+            if policy.pay_pending_rewards && !self.reward_token_id().is_empty() {
+                let token_id = self.reward_token_id().get();
+                self.send().direct_esdt(&caller, &token_id, 0, &pending);
+                self.total_rewards_distributed().update(|total| *total += &pending);
+                rewards_paid = pending;
+            } else {
+                self.rewards_pool().update(|pool| *pool += &pending);
+            }
+        }
+
+        // 2. Solde du SC peripherique
+        let swept = self.get_peripheral_balance(&sc);
+        if swept > BigUint::zero() {
+            self.call_peripheral_transfer(&sc, &caller, &swept);
+        }
+
+        // 3. Remboursement des frais d'entree
+        let refund = self.get_entry_fee_refund(&sc);
+        if !self.entry_payment(&sc).is_empty() {
+            let entry = self.entry_payment(&sc).take();
+            if refund > BigUint::zero() {
+                self.send().direct(&caller, &entry.token_identifier, 0, &refund);
+            }
+        }
+        self.member_joined_at(&sc).clear();
+
+        self.contract_retired(&sc).set(true);
+        self.retired_contract(&caller).set(&sc);
 
         self.member_left_event(&caller, &sc);
+        self.member_exit_settled_event(&caller, &sc, &rewards_paid, &refund, &swept);
+    }
+
+    /// Enregistre les frais payes et la date d'entree (base du remboursement au depart)
+    fn record_entry(&self, sc: &ManagedAddress, token: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        self.entry_payment(sc).set(EgldOrEsdtTokenPayment::new(token, 0, amount));
+        self.member_joined_at(sc).set(self.blockchain().get_block_timestamp());
+    }
+
+    /// Remboursement des frais d'entree d'un SC s'il quittait maintenant:
+    /// fee_refund_bps des frais payes, degressif sur refund_period_days,
+    /// plafonne au solde de SC0 non engage (circulation, liquidite en attente)
+    fn get_entry_fee_refund(&self, sc: &ManagedAddress) -> BigUint {
+        let policy = self.get_exit_policy();
+        if self.entry_payment(sc).is_empty() || policy.fee_refund_bps == 0 {
+            return BigUint::zero();
+        }
+
+        let period = policy.refund_period_days * SECONDS_PER_DAY;
+        let elapsed = self.blockchain().get_block_timestamp() - self.member_joined_at(sc).get();
+        if elapsed >= period {
+            return BigUint::zero();
+        }
+
+        let entry = self.entry_payment(sc).get();
+        let refund = entry.amount * policy.fee_refund_bps / BPS_BASE * (period - elapsed) / period;
+
+        let mut committed = self.get_circulation_reserved(&entry.token_identifier);
+        if entry.token_identifier.is_egld() {
+            committed += self.get_pending_distribution_egld();
+        }
+        let balance = self.get_circulation_balance(&entry.token_identifier);
+        if balance <= committed {
+            return BigUint::zero();
+        }
+        core::cmp::min(refund, balance - committed)
+    }
+
+    /// Solde d'un SC peripherique en token de circulation (demande au SC lui-meme)
+    fn get_peripheral_balance(&self, sc: &ManagedAddress) -> BigUint {
+        self.peripheral_proxy(sc.clone())
+            .get_balance()
+            .returns(ReturnsResult)
+            .sync_call()
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // TRANSFERT D'ADHESION - Changement de wallet
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Propose de transferer l'adhesion (SC, index pionnier, depots, historique)
+    /// vers un nouveau wallet, qui doit accepter avec acceptMembershipTransfer
+    /// Remplace la proposition precedente
+    #[endpoint(proposeMembershipTransfer)]
+    fn propose_membership_transfer(&self, new_member: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.member_contract(&caller).is_empty(), "Pas membre");
+        require!(new_member != caller, "Nouveau wallet identique");
+        require!(
+            self.member_contract(&new_member).is_empty(),
+            "Le nouveau wallet est deja membre"
+        );
+
+        let sc = self.member_contract(&caller).get();
+        self.pending_membership_transfer(&sc).set(&new_member);
+        self.membership_transfer_proposed_event(&caller, &new_member, &sc);
+    }
+
+    /// Annule la proposition de transfert d'adhesion en cours
+    #[endpoint(cancelMembershipTransfer)]
+    fn cancel_membership_transfer(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.member_contract(&caller).is_empty(), "Pas membre");
+
+        let sc = self.member_contract(&caller).get();
+        require!(
+            !self.pending_membership_transfer(&sc).is_empty(),
+            "Aucun transfert en attente"
+        );
+        self.pending_membership_transfer(&sc).clear();
+        self.membership_transfer_cancelled_event(&caller, &sc);
+    }
+
+    /// Accepte l'adhesion proposee par old_member (appele par le nouveau wallet)
+    /// Le SC peripherique change d'owner (il doit etre a jour, voir upgradePeripherals),
+    /// la delegation est revoquee et le contrat NFT reporte le membre
+    #[endpoint(acceptMembershipTransfer)]
+    fn accept_membership_transfer(&self, old_member: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.member_contract(&old_member).is_empty(), "Pas membre");
+
+        let sc = self.member_contract(&old_member).get();
+        let pending = self.pending_membership_transfer(&sc);
+        require!(
+            !pending.is_empty() && pending.get() == caller,
+            "Aucun transfert propose a ce wallet"
+        );
+        require!(
+            self.member_contract(&caller).is_empty(),
+            "Vous etes deja membre du cercle"
+        );
+        pending.clear();
+
+        // Recompenses acquises jusqu'ici avec le bonus de depot courant
+        self.checkpoint_rewards(&sc);
+        self.clear_delegation(&old_member, &sc);
+
+        self.member_contract(&old_member).clear();
+        self.member_contract(&caller).set(&sc);
+        self.contract_owner(&sc).set(&caller);
+
+        let deposits = self.member_egld_deposits(&old_member).take();
+        if deposits > BigUint::zero() {
+            self.member_egld_deposits(&caller).update(|total| *total += &deposits);
+        }
+        for token in self.member_deposit_tokens(&old_member).iter() {
+            let deposits = self.member_token_deposits(&old_member, &token).take();
+            self.member_token_deposits(&caller, &token).update(|total| *total += &deposits);
+            self.member_deposit_tokens(&caller).insert(token);
+        }
+        self.member_deposit_tokens(&old_member).clear();
+
+        self.peripheral_proxy(sc.clone())
+            .transfer_ownership(caller.clone())
+            .sync_call();
+
+        if !self.nft_contract().is_empty() {
+            self.nft_proxy(self.nft_contract().get())
+                .transfer_member(old_member.clone(), caller.clone())
+                .sync_call();
+        }
+
+        self.membership_transferred_event(&old_member, &caller, &sc);
     }
 
     // ═══════════════════════════════════════════════════════════════
     // REWARDS - CLAIM
     // ═══════════════════════════════════════════════════════════════
 
-    /// Reclamer les recompenses XCIRCLEX accumulees (uniquement le dimanche)
+    /// Reclamer les recompenses XCIRCLEX accumulees (pendant une fenetre de reclamation)
     #[endpoint(claimRewards)]
     fn claim_rewards(&self) {
         let caller = self.blockchain().get_caller();
 
-        // Verifier que c'est dimanche (jour 0 de la semaine)
+        // Verifier que la fenetre de reclamation est ouverte (voir setClaimSchedule)
         require!(
-            self.is_sunday(),
-            "Les recompenses ne peuvent etre reclamees que le dimanche"
+            self.is_claim_window_open(),
+            "Fenetre de reclamation fermee - voir getNextClaimWindow"
         );
 
         // Verifier que le caller est membre
@@ -1855,6 +4269,9 @@ pub trait CircleOfLifeCenter {
 
         let caller_sc = self.member_contract(&caller).get();
 
+        // Comptabiliser la participation acquise depuis le dernier checkpoint
+        self.checkpoint_rewards(&caller_sc);
+
         // Verifier les recompenses en attente
         let pending = self.pending_rewards(&caller_sc).get();
         require!(pending > BigUint::zero(), "Aucune recompense a reclamer");
@@ -1874,29 +4291,183 @@ pub trait CircleOfLifeCenter {
         let total = self.total_rewards_distributed().get();
         self.total_rewards_distributed().set(&(total + &pending));
 
-        // Reset les recompenses en attente
-        self.pending_rewards(&caller_sc).clear();
+        // Reset les recompenses en attente
+        self.pending_rewards(&caller_sc).clear();
+
+        self.rewards_claimed_event(&caller, &pending);
+    }
+
+    /// Calendrier de reclamation (defaut: dimanche uniquement)
+    fn get_claim_schedule(&self) -> ClaimSchedule {
+        if self.claim_schedule().is_empty() {
+            return ClaimSchedule::Weekdays { days_mask: DEFAULT_CLAIM_DAYS_MASK };
+        }
+        self.claim_schedule().get()
+    }
+
+    /// Verifie si un jour (jours depuis l'epoch Unix) est ouvert a la reclamation
+    /// Non applicable a AfterCycles (fenetre glissante, voir claim_window_opened_at)
+    fn is_claim_day(&self, schedule: &ClaimSchedule, day: u64) -> bool {
+        match schedule {
+            ClaimSchedule::Always => true,
+            ClaimSchedule::Weekdays { days_mask } => {
+                let day_of_week = (day + 4) % 7;
+                days_mask & (1u8 << day_of_week) != 0
+            },
+            ClaimSchedule::EveryNDays { interval_days, anchor_day } => {
+                day >= *anchor_day && (day - anchor_day).is_multiple_of(*interval_days)
+            },
+            ClaimSchedule::AfterCycles { .. } => false,
+        }
+    }
+
+    /// Verifie si la fenetre de reclamation est ouverte maintenant
+    fn is_claim_window_open(&self) -> bool {
+        let (start, end) = self.get_next_claim_window().into_tuple();
+        let timestamp = self.blockchain().get_block_timestamp();
+        end > 0 && start <= timestamp && timestamp < end
+    }
+
+    /// Ouvre la fenetre glissante AfterCycles apres chaque multiple de every_cycles
+    fn open_rolling_claim_window(&self, cycles_now: u64) {
+        let opens_window = match self.get_claim_schedule() {
+            ClaimSchedule::AfterCycles { every_cycles, .. } => cycles_now.is_multiple_of(every_cycles),
+            _ => false,
+        };
+        if opens_window {
+            self.claim_window_opened_at().set(self.blockchain().get_block_timestamp());
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // HELPERS
+    // ═══════════════════════════════════════════════════════════════
+
+    fn get_current_day(&self) -> u64 {
+        self.blockchain().get_block_timestamp() / 86400
+    }
+
+    /// Cercle vise par un endpoint (cercle principal si non precise)
+    fn resolve_ring(&self, ring_id: OptionalValue<u64>) -> u64 {
+        let ring_id = ring_id.into_option().unwrap_or(DEFAULT_RING_ID);
+        require!(ring_id <= self.ring_count().get(), "Cercle inconnu");
+        ring_id
+    }
+
+    /// Cercle d'un SC peripherique
+    fn get_sc_ring(&self, sc: &ManagedAddress) -> u64 {
+        self.sc_ring(sc).get()
+    }
+
+    /// Verifie qu'un SC de plus peut entrer dans la rotation du cercle
+    fn require_ring_has_room(&self, ring_id: u64) {
+        let max_members = self.ring_max_members(ring_id).get();
+        require!(
+            max_members == 0 || (self.active_contracts_index(ring_id).len() as u64) < max_members,
+            "Cercle complet"
+        );
+    }
+
+    /// Cycles reussis tous cercles confondus (pilote le halving et les bonus globaux)
+    fn get_total_cycles_completed(&self) -> u64 {
+        let mut total = 0u64;
+        for ring_id in 0..=self.ring_count().get() {
+            total += self.cycles_completed(ring_id).get();
+        }
+        total
+    }
+
+    /// Token de circulation d'un cercle (EGLD par defaut)
+    fn get_circulation_token(&self, ring_id: u64) -> EgldOrEsdtTokenIdentifier {
+        if self.circulation_token(ring_id).is_empty() {
+            return EgldOrEsdtTokenIdentifier::egld();
+        }
+        self.circulation_token(ring_id).get()
+    }
+
+    /// Somme des montants circulants des cercles utilisant `token` (a garder dans SC0)
+    fn get_circulation_reserved(&self, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        let mut total = BigUint::zero();
+        for ring_id in 0..=self.ring_count().get() {
+            if &self.get_circulation_token(ring_id) == token {
+                total += self.circulation_amount(ring_id).get();
+            }
+        }
+        total
+    }
 
-        self.rewards_claimed_event(&caller, &pending);
+    fn get_circulation_policy(&self, ring_id: u64) -> CirculationPolicy<Self::Api> {
+        if self.circulation_policy(ring_id).is_empty() {
+            return CirculationPolicy::Fixed;
+        }
+        self.circulation_policy(ring_id).get()
     }
 
-    /// Verifie si le jour actuel est un dimanche
-    /// Epoch (1 Jan 1970) etait un jeudi, donc (days + 4) % 7 = jour de la semaine
-    /// 0 = Dimanche, 1 = Lundi, ..., 6 = Samedi
-    fn is_sunday(&self) -> bool {
-        let timestamp = self.blockchain().get_block_timestamp();
-        let days_since_epoch = timestamp / 86400;
-        // Epoch etait jeudi (4), donc on ajoute 4 et on module par 7
-        let day_of_week = (days_since_epoch + 4) % 7;
-        day_of_week == 0 // 0 = Dimanche
+    /// Montant circulant selon la politique pour `active_members` SC actifs
+    fn compute_circulation_amount(
+        &self,
+        ring_id: u64,
+        policy: &CirculationPolicy<Self::Api>,
+        active_members: u64
+    ) -> BigUint {
+        let (amount, cap) = match policy {
+            CirculationPolicy::Fixed => return self.circulation_amount(ring_id).get(),
+            CirculationPolicy::TreasuryShare { bps, cap } => {
+                (self.get_ring_treasury(ring_id) * *bps / BPS_BASE, cap)
+            },
+            CirculationPolicy::PerMember { amount_per_member, cap } => {
+                (amount_per_member * active_members, cap)
+            },
+            CirculationPolicy::Tiers { tiers, cap } => {
+                let mut amount = BigUint::zero();
+                for tier in tiers.iter() {
+                    if tier.min_members <= active_members {
+                        amount = tier.amount.clone();
+                    }
+                }
+                (amount, cap)
+            },
+        };
+
+        if *cap > 0u64 && amount > *cap {
+            cap.clone()
+        } else {
+            amount
+        }
     }
 
-    // ═══════════════════════════════════════════════════════════════
-    // HELPERS
-    // ═══════════════════════════════════════════════════════════════
+    /// Tresorerie de SC0 disponible pour un cercle: solde en token de circulation,
+    /// hors montants circulants des autres cercles et EGLD en attente de liquidite
+    fn get_ring_treasury(&self, ring_id: u64) -> BigUint {
+        let token = self.get_circulation_token(ring_id);
+        let mut committed = self.get_circulation_reserved(&token) - self.circulation_amount(ring_id).get();
+        if token.is_egld() {
+            committed += self.get_pending_distribution_egld();
+        }
+        let balance = self.get_circulation_balance(&token);
+        if balance > committed {
+            balance - committed
+        } else {
+            BigUint::zero()
+        }
+    }
 
-    fn get_current_day(&self) -> u64 {
-        self.blockchain().get_block_timestamp() / 86400
+    /// Solde de SC0 en `token` utilisable pour la circulation
+    /// (le pool de recompenses n'est jamais engage si le cercle circule en XCIRCLEX)
+    fn get_circulation_balance(&self, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        let balance = self.blockchain().get_sc_balance(token, 0);
+        if self.reward_token_id().is_empty() || !token.is_esdt() {
+            return balance;
+        }
+        if token.clone().unwrap_esdt() != self.reward_token_id().get() {
+            return balance;
+        }
+        let pool = self.rewards_pool().get();
+        if balance > pool {
+            balance - pool
+        } else {
+            BigUint::zero()
+        }
     }
 
     /// Calcule la recompense par participant pour un cycle
@@ -1952,7 +4523,7 @@ pub trait CircleOfLifeCenter {
     /// Calcule la recompense pour le cycle actuel selon π × 360
     /// Formule: base_reward = 36000 * 10^18 / 2^era où era = floor(cycles_completed / 360)
     fn calculate_option_f_reward(&self) -> BigUint {
-        let cycles_completed = self.cycles_completed().get();
+        let cycles_completed = self.get_total_cycles_completed();
 
         // Calculer l'ere (nombre de halvings effectues)
         // era = cycles_completed / 360
@@ -1976,7 +4547,7 @@ pub trait CircleOfLifeCenter {
     /// Verifie si le cycle qui vient d'etre complete est un "cercle complet" (multiple de 360)
     /// Si oui, retourne le bonus π% a ajouter
     fn calculate_pi_bonus(&self, base_reward: &BigUint) -> BigUint {
-        let cycles_completed = self.cycles_completed().get();
+        let cycles_completed = self.get_total_cycles_completed();
 
         // Verifier si c'est un cycle cercle complet (360, 720, 1080, ...)
         if cycles_completed > 0 && cycles_completed % HALVING_PERIOD == 0 {
@@ -1990,21 +4561,286 @@ pub trait CircleOfLifeCenter {
 
     /// Retourne l'ere actuelle (nombre de halvings effectues)
     fn get_current_era(&self) -> u64 {
-        let cycles_completed = self.cycles_completed().get();
+        let cycles_completed = self.get_total_cycles_completed();
         cycles_completed / HALVING_PERIOD
     }
 
-    fn get_active_contracts(&self) -> ManagedVec<ManagedAddress> {
+    /// SC actifs dans l'ordre d'entree dans l'index (lecture directe, sans
+    /// parcourir tous les peripheral_contracts)
+    fn get_active_contracts(&self, ring_id: u64) -> ManagedVec<ManagedAddress> {
         let mut active = ManagedVec::new();
-        for i in 1..=self.peripheral_contracts().len() {
-            let sc = self.peripheral_contracts().get(i);
-            if self.contract_active(&sc).get() {
-                active.push(sc);
-            }
+        for sc in self.active_contracts_index(ring_id).iter() {
+            active.push(sc);
         }
         active
     }
 
+    /// Change le statut d'un SC en maintenant l'index des SC actifs
+    /// et son intervalle de participation aux recompenses
+    fn set_contract_active(&self, sc: &ManagedAddress, active: bool) {
+        let ring_id = self.get_sc_ring(sc);
+        self.contract_active(sc).set(active);
+        if active {
+            self.active_contracts_index(ring_id).insert(sc.clone());
+        } else {
+            self.active_contracts_index(ring_id).remove(sc);
+        }
+
+        self.checkpoint_rewards(sc);
+        let current_epoch = self.cycle_epoch(ring_id).get();
+        let cycle_running = !self.cycle_holder(ring_id).is_empty();
+        let checkpoint = self.reward_checkpoint(sc);
+
+        if active {
+            if checkpoint.is_empty() {
+                // La rotation du cycle en cours est figee: participation au suivant
+                let from_epoch = if cycle_running { current_epoch + 1 } else { current_epoch };
+                checkpoint.set(RewardCheckpoint { from_epoch, until_epoch: u64::MAX });
+            } else {
+                // Toujours dans la rotation figee: l'intervalle reste ouvert
+                checkpoint.update(|cp| cp.until_epoch = u64::MAX);
+            }
+        } else if !checkpoint.is_empty() {
+            // Un SC de la rotation figee participe jusqu'a la fin du cycle en cours
+            let from_epoch = checkpoint.get().from_epoch;
+            if cycle_running && from_epoch <= current_epoch {
+                checkpoint.update(|cp| cp.until_epoch = current_epoch + 1);
+            } else {
+                checkpoint.clear();
+            }
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // REWARDS - COMPTABILITE PAR INDEX CUMULATIF
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Index cumulatifs a l'ouverture d'un epoch du cercle (zero avant la migration)
+    fn get_epoch_reward_index(&self, ring_id: u64, epoch: u64) -> EpochRewardIndex<Self::Api> {
+        if self.epoch_reward_index(ring_id, epoch).is_empty() {
+            return EpochRewardIndex {
+                reward_per_share: BigUint::zero(),
+                completed_cycles: 0,
+            };
+        }
+        self.epoch_reward_index(ring_id, epoch).get()
+    }
+
+    /// Participation acquise mais pas encore comptabilisee: (cycles reussis, recompense de base)
+    /// Les bonus (pionnier, depot) sont appliques au moment de la comptabilisation
+    fn get_unrealized_participation(&self, sc: &ManagedAddress) -> (u64, BigUint) {
+        if self.reward_checkpoint(sc).is_empty() {
+            return (0, BigUint::zero());
+        }
+
+        // Les epochs du checkpoint sont ceux du cercle du SC
+        let ring_id = self.get_sc_ring(sc);
+        let checkpoint = self.reward_checkpoint(sc).get();
+        let end_epoch = core::cmp::min(checkpoint.until_epoch, self.cycle_epoch(ring_id).get());
+        if end_epoch <= checkpoint.from_epoch {
+            return (0, BigUint::zero());
+        }
+
+        let from = self.get_epoch_reward_index(ring_id, checkpoint.from_epoch);
+        let to = self.get_epoch_reward_index(ring_id, end_epoch);
+        (
+            to.completed_cycles - from.completed_cycles,
+            to.reward_per_share - from.reward_per_share,
+        )
+    }
+
+    /// Comptabilise la participation acquise dans sc_cycles_completed et pending_rewards
+    fn checkpoint_rewards(&self, sc: &ManagedAddress) {
+        if self.reward_checkpoint(sc).is_empty() {
+            return;
+        }
+
+        let (cycles, base_reward) = self.get_unrealized_participation(sc);
+        if cycles > 0 {
+            self.sc_cycles_completed(sc).update(|completed| *completed += cycles);
+        }
+        if base_reward > BigUint::zero() {
+            // Bonus appliques:
+            // - Pioneer: +3.14% pour les 360 premiers SC
+            // - Deposit: +1% par EGLD depose (max 360%)
+            let final_reward = self.calculate_final_reward_with_bonuses(&base_reward, sc);
+            self.pending_rewards(sc).update(|pending| *pending += &final_reward);
+        }
+
+        let current_epoch = self.cycle_epoch(self.get_sc_ring(sc)).get();
+        let mut checkpoint = self.reward_checkpoint(sc).get();
+        if checkpoint.until_epoch <= current_epoch {
+            self.reward_checkpoint(sc).clear();
+        } else {
+            if checkpoint.from_epoch < current_epoch {
+                checkpoint.from_epoch = current_epoch;
+            }
+            self.reward_checkpoint(sc).set(checkpoint);
+        }
+    }
+
+    /// Retire un epoch de l'intervalle de participation d'un SC: la participation acquise
+    /// est comptabilisee, puis l'intervalle reprend a l'epoch suivant
+    fn exclude_from_epoch_rewards(&self, sc: &ManagedAddress, epoch: u64) {
+        self.checkpoint_rewards(sc);
+        if self.reward_checkpoint(sc).is_empty() {
+            return;
+        }
+
+        let mut checkpoint = self.reward_checkpoint(sc).get();
+        if checkpoint.from_epoch > epoch {
+            return;
+        }
+        if checkpoint.until_epoch <= epoch + 1 {
+            self.reward_checkpoint(sc).clear();
+        } else {
+            checkpoint.from_epoch = epoch + 1;
+            self.reward_checkpoint(sc).set(checkpoint);
+        }
+    }
+
+    /// Cycles reussis d'un SC, participation non comptabilisee incluse
+    fn get_sc_cycles_completed(&self, sc: &ManagedAddress) -> u64 {
+        let (cycles, _) = self.get_unrealized_participation(sc);
+        self.sc_cycles_completed(sc).get() + cycles
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // ROTATION - ORDRE DE PASSAGE
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Retourne les SC actifs d'un cercle dans l'ordre de passage pour un epoch
+    /// Index 0 = premier a recevoir, dernier = celui qui ferme le cercle
+    fn get_rotation_order(&self, ring_id: u64, epoch: u64) -> ManagedVec<ManagedAddress> {
+        let active = self.get_active_contracts(ring_id);
+        if active.len() <= 1 {
+            return active;
+        }
+
+        match self.rotation_strategy(ring_id).get() {
+            RotationStrategy::InsertionOrder => active,
+            RotationStrategy::Reverse => {
+                let mut reversed = ManagedVec::new();
+                for i in (0..active.len()).rev() {
+                    reversed.push(active.get(i).clone());
+                }
+                reversed
+            },
+            RotationStrategy::EpochShuffle => {
+                let mut rng_state = self.rotation_seed(ring_id, epoch).get() ^ epoch;
+                self.shuffle_rotation(active, &mut rng_state)
+            },
+            RotationStrategy::ReputationWeighted => {
+                let mut rng_state = self.rotation_seed(ring_id, epoch).get() ^ epoch;
+                self.reputation_weighted_rotation(active, &mut rng_state)
+            },
+        }
+    }
+
+    /// Fige l'ordre de passage de l'epoch au demarrage du cycle
+    /// Retourne le nombre de SC de la rotation
+    fn freeze_rotation(&self, ring_id: u64, epoch: u64) -> usize {
+        let order = self.get_rotation_order(ring_id, epoch);
+        let mut snapshot = self.rotation_snapshot(ring_id);
+        snapshot.clear();
+        for sc in order.iter() {
+            snapshot.push(&sc);
+        }
+        self.rotation_snapshot_epoch(ring_id).set(epoch);
+        order.len()
+    }
+
+    /// SC a la position `index` (0-based) de la rotation figee du cercle
+    fn rotation_sc_at(&self, ring_id: u64, index: usize) -> ManagedAddress {
+        self.rotation_snapshot(ring_id).get(index + 1)
+    }
+
+    /// Melange de Fisher-Yates deterministe (meme graine = meme ordre)
+    fn shuffle_rotation(
+        &self,
+        mut contracts: ManagedVec<ManagedAddress>,
+        rng_state: &mut u64
+    ) -> ManagedVec<ManagedAddress> {
+        for i in (1..contracts.len()).rev() {
+            let j = (self.next_rotation_random(rng_state) % (i as u64 + 1)) as usize;
+            if i != j {
+                let a = contracts.get(i).clone();
+                let b = contracts.get(j).clone();
+                let _ = contracts.set(i, b);
+                let _ = contracts.set(j, a);
+            }
+        }
+        contracts
+    }
+
+    /// Tirage pondere sans remise: poids = sc_cycles_completed + 1
+    /// Les positions sont remplies depuis la fin, donc les SC les plus fiables
+    /// ont plus de chances de fermer le cercle (et le bonus π% des cycles #360, #720...)
+    fn reputation_weighted_rotation(
+        &self,
+        mut remaining: ManagedVec<ManagedAddress>,
+        rng_state: &mut u64
+    ) -> ManagedVec<ManagedAddress> {
+        let mut weights: ManagedVec<u64> = ManagedVec::new();
+        let mut total_weight = 0u64;
+        for sc in remaining.iter() {
+            let weight = self.get_sc_cycles_completed(&sc) + 1;
+            weights.push(weight);
+            total_weight += weight;
+        }
+
+        let mut picked_from_end: ManagedVec<ManagedAddress> = ManagedVec::new();
+        while !remaining.is_empty() {
+            let mut target = self.next_rotation_random(rng_state) % total_weight;
+            let mut index = 0usize;
+            loop {
+                let weight = weights.get(index);
+                if target < weight {
+                    break;
+                }
+                target -= weight;
+                index += 1;
+            }
+
+            total_weight -= weights.get(index);
+            picked_from_end.push(remaining.take(index));
+            weights.remove(index);
+        }
+
+        let mut order = ManagedVec::new();
+        for i in (0..picked_from_end.len()).rev() {
+            order.push(picked_from_end.get(i).clone());
+        }
+        order
+    }
+
+    /// Generateur pseudo-aleatoire splitmix64 (deterministe, pas de stockage)
+    fn next_rotation_random(&self, state: &mut u64) -> u64 {
+        *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Passe le cercle a l'epoch suivant et tire la graine de rotation du nouvel epoch
+    fn advance_cycle_epoch(&self, ring_id: u64) {
+        let previous_epoch = self.cycle_epoch(ring_id).get();
+        let next_epoch = previous_epoch + 1;
+        self.cycle_epoch(ring_id).set(next_epoch);
+
+        let random_seed = self.blockchain().get_block_random_seed().to_byte_array();
+        let mut seed_bytes = [0u8; 8];
+        seed_bytes.copy_from_slice(&random_seed[..8]);
+        self.rotation_seed(ring_id, next_epoch).set(u64::from_be_bytes(seed_bytes));
+        self.rotation_seed(ring_id, previous_epoch).clear();
+        self.cycle_skipped_count(ring_id).clear();
+
+        // Reporter les index cumulatifs (la completion y ajoute ensuite le cycle reussi)
+        let index = self.get_epoch_reward_index(ring_id, previous_epoch);
+        self.epoch_reward_index(ring_id, next_epoch).set(index);
+    }
+
     /// Calcule le bonus de depot en pourcentage (1-360%)
     /// 1 EGLD = 1%, max 360 EGLD = 360%
     fn calculate_deposit_bonus_percent(&self, deposits: &BigUint) -> u64 {
@@ -2076,13 +4912,14 @@ pub trait CircleOfLifeCenter {
     // ═══════════════════════════════════════════════════════════════
 
     #[view(getCircleInfo)]
-    fn get_circle_info(&self) -> MultiValue6<usize, usize, BigUint, BigUint, u64, usize> {
+    fn get_circle_info(&self, ring_id: OptionalValue<u64>) -> MultiValue6<usize, usize, BigUint, BigUint, u64, usize> {
+        let ring_id = self.resolve_ring(ring_id);
         let total = self.peripheral_contracts().len();
-        let active = self.get_active_contracts().len();
-        let fee = self.entry_fee().get();
-        let amount = self.circulation_amount().get();
-        let day = self.cycle_day().get();
-        let index = self.current_cycle_index().get();
+        let active = self.active_contracts_index(ring_id).len();
+        let fee = self.entry_fee(ring_id).get();
+        let amount = self.circulation_amount(ring_id).get();
+        let day = self.cycle_day(ring_id).get();
+        let index = self.current_cycle_index(ring_id).get();
 
         (total, active, fee, amount, day, index).into()
     }
@@ -2112,33 +4949,28 @@ pub trait CircleOfLifeCenter {
 
     #[view(isMyTurn)]
     fn is_my_turn(&self, member: ManagedAddress) -> bool {
-        // Verifier que le cycle est actif (cycle_holder est set)
-        if self.cycle_holder().is_empty() {
-            return false;
-        }
-
         if self.member_contract(&member).is_empty() {
             return false;
         }
 
         let sc = self.member_contract(&member).get();
-        if !self.contract_active(&sc).get() {
-            return false;
-        }
+        let ring_id = self.get_sc_ring(&sc);
 
-        let active_contracts = self.get_active_contracts();
-        if active_contracts.is_empty() {
+        // Verifier que le cycle est actif (cycle_holder est set)
+        if self.cycle_holder(ring_id).is_empty() {
             return false;
         }
 
-        // Verifier que c'est bien le tour de ce SC
-        let cycle_holder = self.cycle_holder().get();
+        // Verifier que c'est bien le tour de ce SC (la rotation est figee:
+        // un SC passe inactif en cours de cycle doit quand meme transmettre)
+        let cycle_holder = self.cycle_holder(ring_id).get();
         sc == cycle_holder
     }
 
     #[view(getActiveContracts)]
-    fn get_active_contracts_view(&self) -> MultiValueEncoded<ManagedAddress> {
-        let active = self.get_active_contracts();
+    fn get_active_contracts_view(&self, ring_id: OptionalValue<u64>) -> MultiValueEncoded<ManagedAddress> {
+        let ring_id = self.resolve_ring(ring_id);
+        let active = self.get_active_contracts(ring_id);
         let mut result = MultiValueEncoded::new();
         for i in 0..active.len() {
             result.push(active.get(i).clone());
@@ -2146,6 +4978,59 @@ pub trait CircleOfLifeCenter {
         result
     }
 
+    /// Retourne la strategie de rotation active
+    #[view(getRotationStrategy)]
+    fn get_rotation_strategy(&self, ring_id: OptionalValue<u64>) -> RotationStrategy {
+        let ring_id = self.resolve_ring(ring_id);
+        self.rotation_strategy(ring_id).get()
+    }
+
+    /// Retourne l'etat du reglement: (en attente, epoch, SC regles, total SC)
+    #[view(getSettlementInfo)]
+    fn get_settlement_info(&self, ring_id: OptionalValue<u64>) -> MultiValue4<bool, u64, usize, usize> {
+        let ring_id = self.resolve_ring(ring_id);
+        let pending = self.settlement_pending(ring_id).get();
+        if !pending {
+            return (false, self.settlement_epoch(ring_id).get(), 0usize, 0usize).into();
+        }
+
+        let total = self.rotation_snapshot(ring_id).len();
+        let settled = self.settlement_cursor(ring_id).get() - 1;
+        (true, self.settlement_epoch(ring_id).get(), settled, total).into()
+    }
+
+    /// Nombre de SC restant a regler pour le dernier cycle complete
+    #[view(getSettlementRemaining)]
+    fn get_settlement_remaining(&self, ring_id: OptionalValue<u64>) -> usize {
+        let ring_id = self.resolve_ring(ring_id);
+        if !self.settlement_pending(ring_id).get() {
+            return 0;
+        }
+        self.rotation_snapshot(ring_id).len() + 1 - self.settlement_cursor(ring_id).get()
+    }
+
+    /// Retourne l'ordre de passage prevu pour l'epoch actuel
+    /// (premier = recoit de SC0, dernier = ferme le cercle)
+    #[view(getRotationOrder)]
+    fn get_rotation_order_view(&self, ring_id: OptionalValue<u64>) -> MultiValueEncoded<ManagedAddress> {
+        let ring_id = self.resolve_ring(ring_id);
+        let epoch = self.cycle_epoch(ring_id).get();
+        let mut result = MultiValueEncoded::new();
+
+        // Cycle en cours: ordre fige au demarrage
+        if !self.cycle_holder(ring_id).is_empty() && self.rotation_snapshot_epoch(ring_id).get() == epoch {
+            for sc in self.rotation_snapshot(ring_id).iter() {
+                result.push(sc);
+            }
+            return result;
+        }
+
+        for sc in self.get_rotation_order(ring_id, epoch).iter() {
+            result.push(sc.clone());
+        }
+        result
+    }
+
     #[view(getAllContracts)]
     fn get_all_contracts(&self) -> MultiValueEncoded<ManagedAddress> {
         let mut result = MultiValueEncoded::new();
@@ -2164,6 +5049,28 @@ pub trait CircleOfLifeCenter {
         }
     }
 
+    /// Retourne (version du template, version du code deployee sur le SC)
+    #[view(getPeripheralCodeVersion)]
+    fn get_peripheral_code_version(&self, sc: ManagedAddress) -> MultiValue2<u64, u64> {
+        (self.peripheral_template_version().get(), self.peripheral_code_version(sc).get()).into()
+    }
+
+    /// SC peripheriques [start, start + count) (1-based) dont le code est plus ancien que le template
+    #[view(getOutdatedPeripherals)]
+    fn get_outdated_peripherals(&self, start: usize, count: usize) -> MultiValueEncoded<ManagedAddress> {
+        let version = self.peripheral_template_version().get();
+        let total = self.peripheral_contracts().len();
+        let end = core::cmp::min(start.max(1).saturating_add(count), total + 1);
+        let mut result = MultiValueEncoded::new();
+        for i in start.max(1)..end {
+            let sc = self.peripheral_contracts().get(i);
+            if self.peripheral_code_version(sc.clone()).get() < version {
+                result.push(sc);
+            }
+        }
+        result
+    }
+
     #[view(getOwner)]
     fn get_owner(&self) -> ManagedAddress {
         self.owner().get()
@@ -2180,17 +5087,83 @@ pub trait CircleOfLifeCenter {
     }
 
     #[view(getCycleHolder)]
-    fn get_cycle_holder(&self) -> OptionalValue<ManagedAddress> {
-        if self.cycle_holder().is_empty() {
+    fn get_cycle_holder(&self, ring_id: OptionalValue<u64>) -> OptionalValue<ManagedAddress> {
+        let ring_id = self.resolve_ring(ring_id);
+        if self.cycle_holder(ring_id).is_empty() {
             OptionalValue::None
         } else {
-            OptionalValue::Some(self.cycle_holder().get())
+            OptionalValue::Some(self.cycle_holder(ring_id).get())
+        }
+    }
+
+    /// Historique du cycle d'un epoch: (enregistrement, passages dans l'ordre)
+    #[view(getCycleRecord)]
+    fn get_cycle_record(
+        &self,
+        epoch: u64,
+        ring_id: OptionalValue<u64>,
+    ) -> OptionalValue<MultiValue2<CycleRecord<Self::Api>, ManagedVec<CycleHop<Self::Api>>>> {
+        let ring_id = self.resolve_ring(ring_id);
+        if self.cycle_record(ring_id, epoch).is_empty() {
+            return OptionalValue::None;
+        }
+
+        let mut hops = ManagedVec::new();
+        for hop in self.cycle_hops(ring_id, epoch).iter() {
+            hops.push(hop);
+        }
+        OptionalValue::Some((self.cycle_record(ring_id, epoch).get(), hops).into())
+    }
+
+    /// Historique des cycles des epochs [from_epoch, from_epoch + count) encore conserves
+    #[view(getCycleHistory)]
+    fn get_cycle_history(
+        &self,
+        from_epoch: u64,
+        count: u64,
+        ring_id: OptionalValue<u64>,
+    ) -> MultiValueEncoded<MultiValue2<u64, CycleRecord<Self::Api>>> {
+        let ring_id = self.resolve_ring(ring_id);
+        let mut result = MultiValueEncoded::new();
+        for epoch in from_epoch..from_epoch.saturating_add(count) {
+            if !self.cycle_record(ring_id, epoch).is_empty() {
+                result.push((epoch, self.cycle_record(ring_id, epoch).get()).into());
+            }
         }
+        result
+    }
+
+    /// Retourne (epochs conserves, plus ancien epoch conserve) pour un cercle
+    #[view(getCycleHistoryInfo)]
+    fn get_cycle_history_info(&self, ring_id: OptionalValue<u64>) -> MultiValue2<u64, u64> {
+        let ring_id = self.resolve_ring(ring_id);
+        (self.cycle_history_retention().get(), self.cycle_history_start(ring_id).get()).into()
+    }
+
+    /// Retourne (delai par tour, sauts par infraction, debut du tour, skipHolder possible a partir de)
+    /// Le dernier champ vaut 0 hors cycle ou si le saut de tour est desactive
+    #[view(getHopInfo)]
+    fn get_hop_info(&self, ring_id: OptionalValue<u64>) -> MultiValue4<u64, u64, u64, u64> {
+        let ring_id = self.resolve_ring(ring_id);
+        let timeout = self.hop_timeout_seconds().get();
+        let started_at = self.hop_started_at(ring_id).get();
+        let skip_at = if timeout > 0 && !self.cycle_holder(ring_id).is_empty() {
+            started_at + timeout
+        } else {
+            0
+        };
+        (timeout, self.get_skips_per_infraction(), started_at, skip_at).into()
+    }
+
+    #[view(getSkippedHops)]
+    fn get_skipped_hops(&self, sc_address: ManagedAddress) -> u64 {
+        self.sc_skipped_hops(&sc_address).get()
     }
 
     #[view(getCycleDay)]
-    fn get_cycle_day(&self) -> u64 {
-        self.cycle_day().get()
+    fn get_cycle_day(&self, ring_id: OptionalValue<u64>) -> u64 {
+        let ring_id = self.resolve_ring(ring_id);
+        self.cycle_day(ring_id).get()
     }
 
     #[view(getCurrentDay)]
@@ -2199,28 +5172,140 @@ pub trait CircleOfLifeCenter {
     }
 
     #[view(getCycleEpoch)]
-    fn get_cycle_epoch(&self) -> u64 {
-        self.cycle_epoch().get()
+    fn get_cycle_epoch(&self, ring_id: OptionalValue<u64>) -> u64 {
+        let ring_id = self.resolve_ring(ring_id);
+        self.cycle_epoch(ring_id).get()
     }
 
     #[view(getCyclesCompleted)]
-    fn get_cycles_completed(&self) -> u64 {
-        self.cycles_completed().get()
+    fn get_cycles_completed(&self, ring_id: OptionalValue<u64>) -> u64 {
+        let ring_id = self.resolve_ring(ring_id);
+        self.cycles_completed(ring_id).get()
     }
 
     #[view(getCyclesFailed)]
-    fn get_cycles_failed(&self) -> u64 {
-        self.cycles_failed().get()
+    fn get_cycles_failed(&self, ring_id: OptionalValue<u64>) -> u64 {
+        let ring_id = self.resolve_ring(ring_id);
+        self.cycles_failed(ring_id).get()
     }
 
     #[view(getCycleStats)]
-    fn get_cycle_stats(&self) -> MultiValue3<u64, u64, u64> {
-        let completed = self.cycles_completed().get();
-        let failed = self.cycles_failed().get();
+    fn get_cycle_stats(&self, ring_id: OptionalValue<u64>) -> MultiValue3<u64, u64, u64> {
+        let ring_id = self.resolve_ring(ring_id);
+        let completed = self.cycles_completed(ring_id).get();
+        let failed = self.cycles_failed(ring_id).get();
         let total = completed + failed;
         (completed, failed, total).into()
     }
 
+    // ═══════════════════════════════════════════════════════════════
+    // VIEWS - CERCLES (RINGS)
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Nombre total de cercles, cercle principal inclus
+    #[view(getRingCount)]
+    fn get_ring_count(&self) -> u64 {
+        self.ring_count().get() + 1
+    }
+
+    /// Retourne la configuration et l'etat d'un cercle:
+    /// (nom, frais d'entree, montant circulant, taille max, SC actifs, cycles reussis)
+    #[view(getRingInfo)]
+    fn get_ring_info(&self, ring_id: u64) -> MultiValue6<ManagedBuffer, BigUint, BigUint, u64, usize, u64> {
+        let ring_id = self.resolve_ring(OptionalValue::Some(ring_id));
+        (
+            self.ring_name(ring_id).get(),
+            self.entry_fee(ring_id).get(),
+            self.circulation_amount(ring_id).get(),
+            self.ring_max_members(ring_id).get(),
+            self.active_contracts_index(ring_id).len(),
+            self.cycles_completed(ring_id).get(),
+        )
+            .into()
+    }
+
+    #[view(getCirculationPolicy)]
+    fn get_circulation_policy_view(&self, ring_id: OptionalValue<u64>) -> CirculationPolicy<Self::Api> {
+        let ring_id = self.resolve_ring(ring_id);
+        self.get_circulation_policy(ring_id)
+    }
+
+    /// Montant circulant du prochain cycle d'un cercle, calcule avec les SC actifs
+    /// et la tresorerie actuels (le montant du cycle en cours ne change pas)
+    #[view(getNextCirculationAmount)]
+    fn get_next_circulation_amount(&self, ring_id: OptionalValue<u64>) -> BigUint {
+        let ring_id = self.resolve_ring(ring_id);
+        let active_members = self.active_contracts_index(ring_id).len() as u64;
+        self.compute_circulation_amount(ring_id, &self.get_circulation_policy(ring_id), active_members)
+    }
+
+    /// Token de circulation d'un cercle (EGLD par defaut)
+    #[view(getCirculationToken)]
+    fn get_circulation_token_view(&self, ring_id: OptionalValue<u64>) -> EgldOrEsdtTokenIdentifier {
+        let ring_id = self.resolve_ring(ring_id);
+        self.get_circulation_token(ring_id)
+    }
+
+    /// Retourne (recompenses en attente, remboursement des frais, solde du SC)
+    /// qu'un membre recevrait en quittant le cercle maintenant
+    #[view(getExitQuote)]
+    fn get_exit_quote(&self, member: ManagedAddress) -> MultiValue3<BigUint, BigUint, BigUint> {
+        if self.member_contract(&member).is_empty() {
+            return (BigUint::zero(), BigUint::zero(), BigUint::zero()).into();
+        }
+        let sc = self.member_contract(&member).get();
+        let rewards = if self.get_exit_policy().pay_pending_rewards {
+            self.get_pending_rewards(member)
+        } else {
+            BigUint::zero()
+        };
+        (rewards, self.get_entry_fee_refund(&sc), self.get_peripheral_balance(&sc)).into()
+    }
+
+    #[view(getExitPolicy)]
+    fn get_exit_policy_view(&self) -> ExitPolicy {
+        self.get_exit_policy()
+    }
+
+    /// Nouveau wallet propose pour le SC d'un membre (transfert d'adhesion en attente)
+    #[view(getPendingMembershipTransfer)]
+    fn get_pending_membership_transfer(&self, member: ManagedAddress) -> OptionalValue<ManagedAddress> {
+        if self.member_contract(&member).is_empty() {
+            return OptionalValue::None;
+        }
+        let pending = self.pending_membership_transfer(&self.member_contract(&member).get());
+        if pending.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(pending.get())
+        }
+    }
+
+    /// SC retire d'un ancien membre (reutilise s'il revient)
+    #[view(getRetiredContract)]
+    fn get_retired_contract(&self, member: ManagedAddress) -> OptionalValue<ManagedAddress> {
+        if self.retired_contract(&member).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.retired_contract(&member).get())
+        }
+    }
+
+    #[view(isContractRetired)]
+    fn is_contract_retired(&self, sc: ManagedAddress) -> bool {
+        self.contract_retired(&sc).get()
+    }
+
+    /// Cercle auquel appartient un membre
+    #[view(getMemberRing)]
+    fn get_member_ring(&self, member: ManagedAddress) -> OptionalValue<u64> {
+        if self.member_contract(&member).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.get_sc_ring(&self.member_contract(&member).get()))
+        }
+    }
+
     /// Verifie si un membre a pre-signe pour le cycle actuel (manuel ou auto-sign)
     #[view(hasPreSigned)]
     fn has_pre_signed(&self, member: ManagedAddress) -> bool {
@@ -2228,7 +5313,7 @@ pub trait CircleOfLifeCenter {
             return false;
         }
         let sc = self.member_contract(&member).get();
-        let current_epoch = self.cycle_epoch().get();
+        let current_epoch = self.cycle_epoch(self.get_sc_ring(&sc)).get();
         self.is_effectively_pre_signed(&sc, current_epoch)
     }
 
@@ -2239,7 +5324,7 @@ pub trait CircleOfLifeCenter {
             return false;
         }
         let sc = self.member_contract(&member).get();
-        let current_epoch = self.cycle_epoch().get();
+        let current_epoch = self.cycle_epoch(self.get_sc_ring(&sc)).get();
         !self.last_signature(&sc, current_epoch).is_empty()
     }
 
@@ -2258,7 +5343,7 @@ pub trait CircleOfLifeCenter {
         let is_permanent = self.auto_sign_enabled(&sc).get();
         let until_epoch = self.auto_sign_until(&sc).get();
 
-        let current_epoch = self.cycle_epoch().get();
+        let current_epoch = self.cycle_epoch(self.get_sc_ring(&sc)).get();
         let remaining = if until_epoch > current_epoch {
             until_epoch - current_epoch
         } else {
@@ -2296,7 +5381,7 @@ pub trait CircleOfLifeCenter {
         }
         let sc = self.member_contract(&member).get();
         let until_epoch = self.auto_sign_until(&sc).get();
-        let current_epoch = self.cycle_epoch().get();
+        let current_epoch = self.cycle_epoch(self.get_sc_ring(&sc)).get();
 
         if until_epoch > current_epoch {
             until_epoch - current_epoch
@@ -2331,12 +5416,16 @@ pub trait CircleOfLifeCenter {
 
     /// Retourne la liste des membres qui ont pre-signe pour le cycle actuel
     #[view(getPreSignedMembers)]
-    fn get_pre_signed_members(&self) -> MultiValueEncoded<ManagedAddress> {
+    fn get_pre_signed_members(&self, ring_id: OptionalValue<u64>) -> MultiValueEncoded<ManagedAddress> {
+        let ring_id = self.resolve_ring(ring_id);
         let mut result = MultiValueEncoded::new();
-        let current_epoch = self.cycle_epoch().get();
+        let current_epoch = self.cycle_epoch(ring_id).get();
 
         for i in 1..=self.peripheral_contracts().len() {
             let sc = self.peripheral_contracts().get(i);
+            if self.get_sc_ring(&sc) != ring_id {
+                continue;
+            }
             if self.is_effectively_pre_signed(&sc, current_epoch) {
                 // Retourner l'adresse du owner du SC
                 if !self.contract_owner(&sc).is_empty() {
@@ -2350,7 +5439,7 @@ pub trait CircleOfLifeCenter {
     /// Retourne les statistiques d'un SC peripherique (cycles reussis, echoues, ban until, is_banned, infraction_count)
     #[view(getScStats)]
     fn get_sc_stats(&self, sc_address: ManagedAddress) -> MultiValue5<u64, u64, u64, bool, u64> {
-        let completed = self.sc_cycles_completed(&sc_address).get();
+        let completed = self.get_sc_cycles_completed(&sc_address);
         let failed = self.sc_cycles_failed(&sc_address).get();
         let ban_until = self.sc_ban_until(&sc_address).get();
         let current_timestamp = self.blockchain().get_block_timestamp();
@@ -2359,10 +5448,22 @@ pub trait CircleOfLifeCenter {
         (completed, failed, ban_until, is_banned, infraction_count).into()
     }
 
+    /// Retourne (proposition DAO de l'appel en cours ou 0, caution exigee pour un appel)
+    #[view(getAppealInfo)]
+    fn get_appeal_info(&self, sc_address: ManagedAddress) -> MultiValue2<u64, BigUint> {
+        (self.sc_appeal_proposal(&sc_address).get(), self.appeal_bond_amount().get()).into()
+    }
+
     /// Retourne les informations detaillees du ban progressif pour un SC
     /// (infraction_count, last_infraction_timestamp, ban_until, remaining_ban_days, days_until_reset)
+    /// Les 5 dernieres valeurs sont les seuils de FailurePolicy: (delai de grace,
+    /// avertissements, jours de ban par infraction, jours avant reset, credit partiel)
     #[view(getInfractionInfo)]
-    fn get_infraction_info(&self, sc_address: ManagedAddress) -> MultiValue5<u64, u64, u64, u64, u64> {
+    fn get_infraction_info(
+        &self,
+        sc_address: ManagedAddress
+    ) -> MultiValue10<u64, u64, u64, u64, u64, u64, u64, u64, u64, bool> {
+        let policy = self.get_failure_policy();
         let infraction_count = self.sc_infraction_count(&sc_address).get();
         let last_infraction = self.sc_last_infraction(&sc_address).get();
         let ban_until = self.sc_ban_until(&sc_address).get();
@@ -2375,22 +5476,31 @@ pub trait CircleOfLifeCenter {
             0
         };
 
-        // Calculer les jours restants avant reset du compteur (360 jours sans infraction)
+        // Calculer les jours restants avant reset du compteur (N jours sans infraction)
         let days_until_reset = if last_infraction > 0 {
             let days_since = (current_timestamp - last_infraction) / 86400;
-            if days_since >= 360 {
-                0 // Deja reset
-            } else {
-                360 - days_since
-            }
+            // 0 si deja reset
+            policy.infraction_reset_days.saturating_sub(days_since)
         } else {
             0 // Pas d'infraction
         };
 
-        (infraction_count, last_infraction, ban_until, remaining_ban_days, days_until_reset).into()
-    }
-
-    /// Verifie si le compteur d'infractions sera remis a zero (360 jours sans infraction)
+        (
+            infraction_count,
+            last_infraction,
+            ban_until,
+            remaining_ban_days,
+            days_until_reset,
+            policy.grace_period_seconds,
+            policy.warning_infractions,
+            policy.ban_days_per_infraction,
+            policy.infraction_reset_days,
+            policy.credit_signed_members,
+        )
+            .into()
+    }
+
+    /// Verifie si le compteur d'infractions sera remis a zero (N jours sans infraction)
     #[view(willInfractionReset)]
     fn will_infraction_reset(&self, sc_address: ManagedAddress) -> bool {
         let last_infraction = self.sc_last_infraction(&sc_address).get();
@@ -2399,7 +5509,7 @@ pub trait CircleOfLifeCenter {
         }
         let current_timestamp = self.blockchain().get_block_timestamp();
         let days_since = (current_timestamp - last_infraction) / 86400;
-        days_since >= 360
+        days_since >= self.get_failure_policy().infraction_reset_days
     }
 
     /// Verifie si un SC est banni et retourne la date de fin de ban
@@ -2419,7 +5529,7 @@ pub trait CircleOfLifeCenter {
 
         for i in 1..=self.peripheral_contracts().len() {
             let sc = self.peripheral_contracts().get(i);
-            let completed = self.sc_cycles_completed(&sc).get();
+            let completed = self.get_sc_cycles_completed(&sc);
             let failed = self.sc_cycles_failed(&sc).get();
             let ban_until = self.sc_ban_until(&sc).get();
             let is_banned = ban_until > current_timestamp;
@@ -2469,7 +5579,13 @@ pub trait CircleOfLifeCenter {
             return BigUint::zero();
         }
         let sc = self.member_contract(&member).get();
-        self.pending_rewards(&sc).get()
+        let (_, base_reward) = self.get_unrealized_participation(&sc);
+        let unrealized = if base_reward > BigUint::zero() {
+            self.calculate_final_reward_with_bonuses(&base_reward, &sc)
+        } else {
+            BigUint::zero()
+        };
+        self.pending_rewards(&sc).get() + unrealized
     }
 
     /// Retourne le total des recompenses distribuees
@@ -2491,7 +5607,7 @@ pub trait CircleOfLifeCenter {
     /// Verifie si c'est dimanche (jour ou on peut claim les recompenses)
     #[view(isSunday)]
     fn is_sunday_view(&self) -> bool {
-        self.is_sunday()
+        self.get_day_of_week() == 0
     }
 
     /// Retourne le jour de la semaine (0=Dimanche, 1=Lundi, ..., 6=Samedi)
@@ -2503,12 +5619,129 @@ pub trait CircleOfLifeCenter {
     }
 
     /// Verifie si un membre peut claim ses recompenses maintenant
+    /// (fenetre ouverte, montant en attente, a des recompenses)
     #[view(canClaimRewards)]
     fn can_claim_rewards(&self, member: ManagedAddress) -> MultiValue3<bool, BigUint, bool> {
-        let is_sunday = self.is_sunday();
+        let is_open = self.is_claim_window_open();
         let pending = self.get_pending_rewards(member);
         let has_rewards = pending > BigUint::zero();
-        (is_sunday, pending, has_rewards).into()
+        (is_open, pending, has_rewards).into()
+    }
+
+    /// Retourne la configuration des pourboires: (source, pourboire par transfert,
+    /// plafond par cycle, deja verse pour le cycle actuel)
+    #[view(getKeeperRewardConfig)]
+    fn get_keeper_reward_config(
+        &self,
+        ring_id: OptionalValue<u64>
+    ) -> MultiValue4<KeeperTipSource, BigUint, BigUint, BigUint> {
+        let ring_id = self.resolve_ring(ring_id);
+        (
+            self.keeper_tip_source().get(),
+            self.keeper_tip_per_transfer().get(),
+            self.keeper_tip_cap_per_cycle().get(),
+            self.keeper_tips_paid(ring_id, self.cycle_epoch(ring_id).get()).get(),
+        )
+            .into()
+    }
+
+    /// Retourne les statistiques d'un keeper:
+    /// (transferts traites, cycles echoues declenches, pourboires XCIRCLEX, pourboires EGLD)
+    #[view(getKeeperStats)]
+    fn get_keeper_stats(&self, keeper: ManagedAddress) -> MultiValue4<u64, u64, BigUint, BigUint> {
+        let stats = self.get_keeper_stats_or_default(&keeper);
+        (stats.transfers_processed, stats.cycles_failed, stats.tips_reward_token, stats.tips_egld).into()
+    }
+
+    /// Retourne la delegation d'un membre: (delegue, epoch d'expiration, portee, valide)
+    #[view(getDelegation)]
+    fn get_delegation(
+        &self,
+        member: ManagedAddress
+    ) -> OptionalValue<MultiValue4<ManagedAddress, u64, DelegateScope, bool>> {
+        if self.member_contract(&member).is_empty() {
+            return OptionalValue::None;
+        }
+        let sc = self.member_contract(&member).get();
+        if self.sc_delegate(&sc).is_empty() {
+            return OptionalValue::None;
+        }
+
+        let delegation = self.sc_delegate(&sc).get();
+        let is_valid = self.blockchain().get_block_epoch() < delegation.expires_epoch;
+        OptionalValue::Some((delegation.delegate, delegation.expires_epoch, delegation.scope, is_valid).into())
+    }
+
+    /// Retourne les membres pour lesquels une adresse peut agir (delegations non expirees)
+    #[view(getDelegatedMembers)]
+    fn get_delegated_members(&self, delegate: ManagedAddress) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        let current_epoch = self.blockchain().get_block_epoch();
+        for sc in self.delegated_contracts(&delegate).iter() {
+            if self.sc_delegate(&sc).get().expires_epoch > current_epoch {
+                result.push(self.contract_owner(&sc).get());
+            }
+        }
+        result
+    }
+
+    /// Retourne le calendrier de reclamation actuel
+    #[view(getClaimSchedule)]
+    fn get_claim_schedule_view(&self) -> ClaimSchedule {
+        self.get_claim_schedule()
+    }
+
+    /// Retourne la fenetre de reclamation en cours ou la prochaine: (debut, fin) en timestamps
+    /// Always = (0, u64::MAX). AfterCycles sans fenetre ouverte = (0, 0): la prochaine
+    /// s'ouvrira a la completion du prochain multiple de every_cycles cycles
+    #[view(getNextClaimWindow)]
+    fn get_next_claim_window(&self) -> MultiValue2<u64, u64> {
+        let schedule = self.get_claim_schedule();
+
+        match schedule {
+            ClaimSchedule::Always => (0u64, u64::MAX).into(),
+            ClaimSchedule::AfterCycles { window_seconds, .. } => {
+                if self.claim_window_opened_at().is_empty() {
+                    return (0u64, 0u64).into();
+                }
+                let opened_at = self.claim_window_opened_at().get();
+                let closes_at = opened_at.saturating_add(window_seconds);
+                if self.blockchain().get_block_timestamp() >= closes_at {
+                    return (0u64, 0u64).into();
+                }
+                (opened_at, closes_at).into()
+            },
+            ClaimSchedule::Weekdays { .. } | ClaimSchedule::EveryNDays { .. } => {
+                // Premier jour ouvert a partir d'aujourd'hui (inclus)
+                let today = self.get_current_day();
+                let start_day = match &schedule {
+                    ClaimSchedule::EveryNDays { interval_days, anchor_day } => {
+                        if today <= *anchor_day {
+                            *anchor_day
+                        } else {
+                            anchor_day + (today - anchor_day).div_ceil(*interval_days) * interval_days
+                        }
+                    },
+                    _ => {
+                        let mut day = today;
+                        while !self.is_claim_day(&schedule, day) && day < today + 7 {
+                            day += 1;
+                        }
+                        if day == today + 7 {
+                            return (0u64, 0u64).into();
+                        }
+                        day
+                    },
+                };
+
+                // Fusionner les jours ouverts consecutifs (max une semaine)
+                let mut end_day = start_day + 1;
+                while end_day < start_day + 7 && self.is_claim_day(&schedule, end_day) {
+                    end_day += 1;
+                }
+                (start_day.saturating_mul(SECONDS_PER_DAY), end_day.saturating_mul(SECONDS_PER_DAY)).into()
+            },
+        }
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -2530,7 +5763,7 @@ pub trait CircleOfLifeCenter {
     /// Retourne le prochain cycle qui declenchera un bonus cercle complet
     #[view(getNextCircleCompleteCycle)]
     fn get_next_circle_complete_cycle(&self) -> u64 {
-        let cycles_completed = self.cycles_completed().get();
+        let cycles_completed = self.get_total_cycles_completed();
         let current_circle = cycles_completed / HALVING_PERIOD;
         (current_circle + 1) * HALVING_PERIOD
     }
@@ -2538,7 +5771,7 @@ pub trait CircleOfLifeCenter {
     /// Retourne les cycles restants avant le prochain halving
     #[view(getCyclesUntilNextHalving)]
     fn get_cycles_until_next_halving(&self) -> u64 {
-        let cycles_completed = self.cycles_completed().get();
+        let cycles_completed = self.get_total_cycles_completed();
         let next_halving = ((cycles_completed / HALVING_PERIOD) + 1) * HALVING_PERIOD;
         next_halving - cycles_completed
     }
@@ -2561,7 +5794,7 @@ pub trait CircleOfLifeCenter {
     /// Verifie si le prochain cycle complete sera un "cercle complet" (360, 720, ...)
     #[view(isNextCycleCircleComplete)]
     fn is_next_cycle_circle_complete(&self) -> bool {
-        let cycles_completed = self.cycles_completed().get();
+        let cycles_completed = self.get_total_cycles_completed();
         (cycles_completed + 1) % HALVING_PERIOD == 0
     }
 
@@ -2601,10 +5834,11 @@ pub trait CircleOfLifeCenter {
     /// Retourne les statistiques de burn completes
     /// (total_burned, burn_per_sc, estimated_next_burn basé sur le nombre de SC actifs)
     #[view(getBurnStats)]
-    fn get_burn_stats(&self) -> MultiValue3<BigUint, BigUint, BigUint> {
+    fn get_burn_stats(&self, ring_id: OptionalValue<u64>) -> MultiValue3<BigUint, BigUint, BigUint> {
+        let ring_id = self.resolve_ring(ring_id);
         let total_burned = self.total_burned().get();
         let burn_per_sc = self.burn_per_sc().get();
-        let active_count = self.get_active_contracts().len() as u64;
+        let active_count = self.active_contracts_index(ring_id).len() as u64;
         let estimated_next_burn = &burn_per_sc * active_count;
         (total_burned, burn_per_sc, estimated_next_burn).into()
     }
@@ -2640,10 +5874,137 @@ pub trait CircleOfLifeCenter {
         let pending_wegld_lp = self.pending_wegld_for_lp().get();
         let pending_xcirclex = self.pending_xcirclex_for_lp().get();
         let pending_lp = self.pending_lp_tokens().get();
-        let in_progress = self.liquidity_processing_in_progress().get();
+        let in_progress = self.liquidity_stage().get().is_in_flight();
         (pending_egld, pending_wegld_swap, pending_wegld_lp, pending_xcirclex, pending_lp, in_progress).into()
     }
 
+    /// Table de distribution active. Sans table configuree, retourne la
+    /// repartition historique arrondie en BPS du total (3.14% / 67.80% / 29.06%)
+    #[view(getDistributionTable)]
+    fn get_distribution_table(&self) -> MultiValueEncoded<DistributionShare<Self::Api>> {
+        self.get_active_distribution_table().into()
+    }
+
+    /// Historique des changements de la table de distribution (du plus ancien au plus recent)
+    #[view(getDistributionTableHistory)]
+    fn get_distribution_table_history(
+        &self,
+        from: usize,
+        count: usize
+    ) -> MultiValueEncoded<DistributionTableChange<Self::Api>> {
+        let history = self.distribution_table_history();
+        let mut result = MultiValueEncoded::new();
+        let start = from + 1;
+        let end = core::cmp::min(history.len() + 1, start.saturating_add(count));
+        for index in start..end {
+            result.push(history.get(index));
+        }
+        result
+    }
+
+    /// Nombre de changements de la table de distribution
+    #[view(getDistributionTableHistoryCount)]
+    fn get_distribution_table_history_count(&self) -> usize {
+        self.distribution_table_history().len()
+    }
+
+    /// Retourne le total distribue au contrat de staking
+    #[view(getTotalDistributedStaking)]
+    fn get_total_distributed_staking(&self) -> BigUint {
+        self.total_distributed_staking().get()
+    }
+
+    /// Retourne les EGLD en attente pour le rachat + burn de XCIRCLEX
+    #[view(getPendingBurnSwapEgld)]
+    fn get_pending_burn_swap_egld(&self) -> BigUint {
+        self.pending_burn_swap_egld().get()
+    }
+
+    /// WEGLD wrappes par processBurnSwap en attente du swap
+    #[view(getPendingBurnSwapWegld)]
+    fn get_pending_burn_swap_wegld(&self) -> BigUint {
+        self.burn_swap_wegld().get()
+    }
+
+    /// Etape du pipeline de liquidite et nombre d'echecs consecutifs de cette etape
+    #[view(getLiquidityStage)]
+    fn get_liquidity_stage(&self) -> MultiValue2<LiquidityStage, u64> {
+        (self.liquidity_stage().get(), self.liquidity_failed_attempts().get()).into()
+    }
+
+    /// Locks de liquidite detenue par le protocole, a partir de l'index from (0-based)
+    #[view(getProtocolLocks)]
+    fn get_protocol_locks(&self, from: usize, count: usize) -> MultiValueEncoded<ProtocolLock<Self::Api>> {
+        let locks = self.protocol_locks();
+        let mut result = MultiValueEncoded::new();
+        let start = from + 1;
+        let end = core::cmp::min(locks.len() + 1, start.saturating_add(count));
+        for index in start..end {
+            result.push(locks.get(index));
+        }
+        result
+    }
+
+    /// Lock protocolaire par ID du LP Locker
+    #[view(getProtocolLock)]
+    fn get_protocol_lock_view(&self, lock_id: u64) -> ProtocolLock<Self::Api> {
+        self.get_protocol_lock(lock_id)
+    }
+
+    /// Totaux de la liquidite detenue par le protocole
+    /// (lp_tokens, egld_contributed, xcirclex_contributed, egld_recovered, xcirclex_recovered, nb_locks)
+    #[view(getProtocolLiquidity)]
+    fn get_protocol_liquidity(&self) -> MultiValue6<BigUint, BigUint, BigUint, BigUint, BigUint, usize> {
+        (
+            self.total_protocol_lp().get(),
+            self.total_protocol_egld_contributed().get(),
+            self.total_protocol_xcirclex_contributed().get(),
+            self.total_protocol_egld_recovered().get(),
+            self.total_protocol_xcirclex_recovered().get(),
+            self.protocol_locks().len(),
+        ).into()
+    }
+
+    /// Valeur actuelle des LP tokens du protocole dans la paire: (XCIRCLEX, WEGLD)
+    #[view(getProtocolLiquidityValue)]
+    fn get_protocol_liquidity_value(&self) -> MultiValue2<BigUint, BigUint> {
+        let total_lp = self.total_protocol_lp().get();
+        if total_lp == 0u32 || self.xexchange_pair_address().is_empty() {
+            return (BigUint::zero(), BigUint::zero()).into();
+        }
+        let (xcirclex_reserve, wegld_reserve, lp_supply) = self
+            .xexchange_proxy(self.xexchange_pair_address().get())
+            .get_reserves_and_total_supply()
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+            .into_tuple();
+        if lp_supply == 0u32 {
+            return (BigUint::zero(), BigUint::zero()).into();
+        }
+        (&total_lp * &xcirclex_reserve / &lp_supply, &total_lp * &wegld_reserve / &lp_supply).into()
+    }
+
+    /// LP tokens debloques et WEGLD recuperes en attente de la prochaine etape DAO
+    #[view(getPendingProtocolWithdrawal)]
+    fn get_pending_protocol_withdrawal(&self) -> MultiValue3<BigUint, BigUint, bool> {
+        (
+            self.protocol_lp_unlocked().get(),
+            self.pending_proceeds_wegld().get(),
+            self.protocol_withdrawal_in_flight().get(),
+        ).into()
+    }
+
+    #[view(getPriceGuardPolicy)]
+    fn get_price_guard_policy_view(&self) -> PriceGuardPolicy<Self::Api> {
+        self.get_price_guard_policy()
+    }
+
+    /// Moyenne mobile du prix XCIRCLEX par WEGLD (x 10^18) et timestamp de la derniere observation
+    #[view(getTwapPrice)]
+    fn get_twap_price(&self) -> MultiValue2<BigUint, u64> {
+        (self.twap_price().get(), self.twap_updated_at().get()).into()
+    }
+
     /// Verifie si la distribution est activee
     #[view(isDistributionEnabled)]
     fn is_distribution_enabled(&self) -> bool {
@@ -2657,18 +6018,22 @@ pub trait CircleOfLifeCenter {
     /// Retourne les informations du bonus starter
     /// (percentage, current_starter, potential_bonus, total_distributed)
     #[view(getStarterBonusInfo)]
-    fn get_starter_bonus_info(&self) -> MultiValue4<u64, OptionalValue<ManagedAddress>, BigUint, BigUint> {
+    fn get_starter_bonus_info(
+        &self,
+        ring_id: OptionalValue<u64>
+    ) -> MultiValue4<u64, OptionalValue<ManagedAddress>, BigUint, BigUint> {
+        let ring_id = self.resolve_ring(ring_id);
         let percentage = self.starter_bonus_percentage().get();
-        let starter = if self.cycle_starter().is_empty() {
+        let starter = if self.cycle_starter(ring_id).is_empty() {
             OptionalValue::None
         } else {
-            OptionalValue::Some(self.cycle_starter().get())
+            OptionalValue::Some(self.cycle_starter(ring_id).get())
         };
 
         // Calculer le bonus potentiel: (reward_per_cycle / nb_SC) * percentage / 10000
         let potential_bonus = if percentage > 0 {
             let reward_per_cycle = self.calculate_option_f_reward();
-            let active_count = self.get_active_contracts().len() as u64;
+            let active_count = self.active_contracts_index(ring_id).len() as u64;
             let base_per_sc = if active_count > 0 {
                 &reward_per_cycle / active_count
             } else {
@@ -2692,11 +6057,12 @@ pub trait CircleOfLifeCenter {
 
     /// Retourne l'adresse de celui qui a demarre le cycle actuel
     #[view(getCycleStarter)]
-    fn get_cycle_starter(&self) -> OptionalValue<ManagedAddress> {
-        if self.cycle_starter().is_empty() {
+    fn get_cycle_starter(&self, ring_id: OptionalValue<u64>) -> OptionalValue<ManagedAddress> {
+        let ring_id = self.resolve_ring(ring_id);
+        if self.cycle_starter(ring_id).is_empty() {
             OptionalValue::None
         } else {
-            OptionalValue::Some(self.cycle_starter().get())
+            OptionalValue::Some(self.cycle_starter(ring_id).get())
         }
     }
 
@@ -2790,6 +6156,12 @@ pub trait CircleOfLifeCenter {
         (deposits, bonus_percent, bonus_bps, 360u64).into()
     }
 
+    /// Retourne le total depose par un membre dans un token ESDT
+    #[view(getMemberTokenDeposits)]
+    fn get_member_token_deposits(&self, member: ManagedAddress, token: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.member_token_deposits(&member, &token).get()
+    }
+
     /// Retourne le total des EGLD deposes par un membre
     #[view(getMemberEgldDeposits)]
     fn get_member_egld_deposits(&self, member: ManagedAddress) -> BigUint {
@@ -2832,24 +6204,25 @@ pub trait CircleOfLifeCenter {
 
     /// Verifie combien de transferts peuvent etre traites automatiquement
     #[view(getPendingAutoTransfers)]
-    fn get_pending_auto_transfers(&self) -> usize {
-        if self.cycle_holder().is_empty() {
+    fn get_pending_auto_transfers(&self, ring_id: OptionalValue<u64>) -> usize {
+        let ring_id = self.resolve_ring(ring_id);
+        if self.cycle_holder(ring_id).is_empty() {
             return 0;
         }
 
-        let active_contracts = self.get_active_contracts();
-        if active_contracts.is_empty() {
+        let current_epoch = self.cycle_epoch(ring_id).get();
+        let rotation_len = self.rotation_snapshot(ring_id).len();
+        if rotation_len == 0 {
             return 0;
         }
 
-        let current_epoch = self.cycle_epoch().get();
-        let mut current_index = self.current_cycle_index().get();
+        let mut current_index = self.current_cycle_index(ring_id).get();
         let mut count = 0usize;
 
         // Compter combien de transferts consecutifs peuvent etre traites
-        for _ in 0..active_contracts.len() {
-            let expected_index = current_index % active_contracts.len();
-            let current_sc = active_contracts.get(expected_index).clone();
+        for _ in 0..rotation_len {
+            let expected_index = current_index % rotation_len;
+            let current_sc = self.rotation_sc_at(ring_id, expected_index);
 
             // Verifier si pre-signe (manuel ou auto) ET pas encore traite
             if self.is_effectively_pre_signed(&current_sc, current_epoch)
@@ -2873,13 +6246,41 @@ pub trait CircleOfLifeCenter {
     fn contract_created_event(&self, #[indexed] sc_address: &ManagedAddress, #[indexed] owner: &ManagedAddress);
 
     #[event("cycle_started")]
-    fn cycle_started_event(&self, #[indexed] day: u64, amount: &BigUint);
+    fn cycle_started_event(&self, #[indexed] day: u64, #[indexed] ring_id: u64, amount: &BigUint);
 
     #[event("cycle_completed")]
-    fn cycle_completed_event(&self, #[indexed] day: u64);
+    fn cycle_completed_event(&self, #[indexed] day: u64, #[indexed] ring_id: u64);
+
+    #[event("cycle_settled")]
+    fn cycle_settled_event(&self, #[indexed] epoch: u64, settled_count: u64);
 
     #[event("cycle_failed")]
-    fn cycle_failed_event(&self, #[indexed] day: u64, #[indexed] failed_at: &ManagedAddress);
+    fn cycle_failed_event(
+        &self,
+        #[indexed] day: u64,
+        #[indexed] failed_at: &ManagedAddress,
+        #[indexed] ring_id: u64
+    );
+
+    #[event("cycle_history_retention_changed")]
+    fn cycle_history_retention_changed_event(&self, #[indexed] changed_by: &ManagedAddress, epochs: u64);
+
+    #[event("holder_skipped")]
+    fn holder_skipped_event(
+        &self,
+        #[indexed] skipped: &ManagedAddress,
+        #[indexed] next: &ManagedAddress,
+        #[indexed] ring_id: u64,
+        skipped_hops: u64
+    );
+
+    #[event("hop_timeout_changed")]
+    fn hop_timeout_changed_event(
+        &self,
+        #[indexed] changed_by: &ManagedAddress,
+        #[indexed] timeout_seconds: u64,
+        skips_per_infraction: u64
+    );
 
     #[event("signature")]
     fn signature_event(&self, #[indexed] from: &ManagedAddress, #[indexed] next: &ManagedAddress, amount: &BigUint);
@@ -2893,9 +6294,49 @@ pub trait CircleOfLifeCenter {
     #[event("member_left")]
     fn member_left_event(&self, #[indexed] member: &ManagedAddress, #[indexed] sc: &ManagedAddress);
 
+    #[event("member_exit_settled")]
+    fn member_exit_settled_event(
+        &self,
+        #[indexed] member: &ManagedAddress,
+        #[indexed] sc: &ManagedAddress,
+        #[indexed] rewards_paid: &BigUint,
+        #[indexed] fee_refund: &BigUint,
+        swept: &BigUint,
+    );
+
+    #[event("membership_transfer_proposed")]
+    fn membership_transfer_proposed_event(
+        &self,
+        #[indexed] member: &ManagedAddress,
+        #[indexed] new_member: &ManagedAddress,
+        sc: &ManagedAddress,
+    );
+
+    #[event("membership_transfer_cancelled")]
+    fn membership_transfer_cancelled_event(&self, #[indexed] member: &ManagedAddress, sc: &ManagedAddress);
+
+    #[event("membership_transferred")]
+    fn membership_transferred_event(
+        &self,
+        #[indexed] old_member: &ManagedAddress,
+        #[indexed] new_member: &ManagedAddress,
+        sc: &ManagedAddress,
+    );
+
+    #[event("member_rejoined")]
+    fn member_rejoined_event(&self, #[indexed] member: &ManagedAddress, #[indexed] sc: &ManagedAddress);
+
     #[event("deposit")]
     fn deposit_event(&self, #[indexed] from: &ManagedAddress, amount: &BigUint);
 
+    #[event("token_deposit")]
+    fn token_deposit_event(
+        &self,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint
+    );
+
     #[event("deposit_bonus")]
     fn deposit_bonus_event(
         &self,
@@ -2913,6 +6354,47 @@ pub trait CircleOfLifeCenter {
     #[event("sc_banned")]
     fn sc_banned_event(&self, #[indexed] sc: &ManagedAddress, #[indexed] ban_until: u64);
 
+    #[event("sc_warned")]
+    fn sc_warned_event(
+        &self,
+        #[indexed] sc: &ManagedAddress,
+        #[indexed] infraction_count: u64,
+        warning_infractions: u64
+    );
+
+    #[event("partial_credit")]
+    fn partial_credit_event(&self, #[indexed] epoch: u64, signed_before_failure: u64);
+
+    #[event("exit_policy_changed")]
+    fn exit_policy_changed_event(&self, #[indexed] changed_by: &ManagedAddress, policy: &ExitPolicy);
+
+    #[event("failure_policy_changed")]
+    fn failure_policy_changed_event(&self, #[indexed] changed_by: &ManagedAddress, policy: &FailurePolicy);
+
+    #[event("ban_appealed")]
+    fn ban_appealed_event(
+        &self,
+        #[indexed] sc: &ManagedAddress,
+        #[indexed] proposal_id: u64,
+        bond: &BigUint
+    );
+
+    #[event("ban_appeal_resolved")]
+    fn ban_appeal_resolved_event(
+        &self,
+        #[indexed] sc: &ManagedAddress,
+        #[indexed] proposal_id: u64,
+        approved: bool
+    );
+
+    #[event("ban_appeal_cleared")]
+    fn ban_appeal_cleared_event(
+        &self,
+        #[indexed] sc: &ManagedAddress,
+        #[indexed] proposal_id: u64,
+        #[indexed] cleared_by: &ManagedAddress
+    );
+
     #[event("sc_banned_progressive")]
     fn sc_banned_progressive_event(
         &self,
@@ -2952,12 +6434,108 @@ pub trait CircleOfLifeCenter {
     #[event("circle_complete_bonus")]
     fn circle_complete_bonus_event(&self, #[indexed] completer: &ManagedAddress, #[indexed] cycle_number: u64, amount: &BigUint);
 
+    #[event("keeper_reward_changed")]
+    fn keeper_reward_changed_event(
+        &self,
+        #[indexed] source: KeeperTipSource,
+        #[indexed] tip_per_transfer: &BigUint,
+        cap_per_cycle: &BigUint
+    );
+
+    #[event("keeper_tipped")]
+    fn keeper_tipped_event(
+        &self,
+        #[indexed] keeper: &ManagedAddress,
+        #[indexed] epoch: u64,
+        #[indexed] source: KeeperTipSource,
+        amount: &BigUint
+    );
+
+    #[event("delegate_set")]
+    fn delegate_set_event(
+        &self,
+        #[indexed] member: &ManagedAddress,
+        #[indexed] delegate: &ManagedAddress,
+        #[indexed] sc: &ManagedAddress,
+        #[indexed] expires_epoch: u64,
+        scope: DelegateScope
+    );
+
+    #[event("delegate_revoked")]
+    fn delegate_revoked_event(
+        &self,
+        #[indexed] member: &ManagedAddress,
+        #[indexed] delegate: &ManagedAddress,
+        #[indexed] sc: &ManagedAddress
+    );
+
+    #[event("claim_schedule_changed")]
+    fn claim_schedule_changed_event(&self, #[indexed] changed_by: &ManagedAddress, schedule: &ClaimSchedule);
+
+    #[event("rotation_strategy_changed")]
+    fn rotation_strategy_changed_event(&self, #[indexed] changed_by: &ManagedAddress, strategy: RotationStrategy);
+
     #[event("pioneer_registered")]
     fn pioneer_registered_event(&self, #[indexed] sc: &ManagedAddress, #[indexed] index: u64);
 
     #[event("pioneer_indices_initialized")]
     fn pioneer_indices_initialized_event(&self, #[indexed] total_count: u64);
 
+    #[event("ring_created")]
+    fn ring_created_event(
+        &self,
+        #[indexed] ring_id: u64,
+        #[indexed] entry_fee: &BigUint,
+        #[indexed] circulation_amount: &BigUint,
+        name: &ManagedBuffer
+    );
+
+    #[event("circulation_policy_changed")]
+    fn circulation_policy_changed_event(
+        &self,
+        #[indexed] changed_by: &ManagedAddress,
+        #[indexed] ring_id: u64,
+        policy: &CirculationPolicy<Self::Api>
+    );
+
+    #[event("circulation_token_changed")]
+    fn circulation_token_changed_event(&self, #[indexed] ring_id: u64, token: &EgldOrEsdtTokenIdentifier);
+
+    #[event("circulation_funded")]
+    fn circulation_funded_event(
+        &self,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint
+    );
+
+    #[event("peripheral_template_changed")]
+    fn peripheral_template_changed_event(&self, #[indexed] template: &ManagedAddress, version: u64);
+
+    #[event("peripheral_upgrade_started")]
+    fn peripheral_upgrade_started_event(
+        &self,
+        #[indexed] sc: &ManagedAddress,
+        #[indexed] index: u64,
+        version: u64
+    );
+
+    #[event("peripherals_up_to_date")]
+    fn peripherals_up_to_date_event(
+        &self,
+        #[indexed] start: u64,
+        #[indexed] count: u64,
+        version: u64
+    );
+
+    #[event("active_index_rebuilt")]
+    fn active_index_rebuilt_event(
+        &self,
+        #[indexed] from_index: u64,
+        #[indexed] to_index: u64,
+        active_count: u64
+    );
+
     // ═══════════════════════════════════════════════════════════════
     // EVENTS - DISTRIBUTION V4
     // ═══════════════════════════════════════════════════════════════
@@ -2974,6 +6552,27 @@ pub trait CircleOfLifeCenter {
     #[event("liquidity_withdrawn")]
     fn liquidity_withdrawn_event(&self, #[indexed] to: &ManagedAddress, #[indexed] amount: &BigUint);
 
+    #[event("distribution_table_changed")]
+    fn distribution_table_changed_event(
+        &self,
+        #[indexed] changed_by: &ManagedAddress,
+        shares: &ManagedVec<DistributionShare<Self::Api>>
+    );
+
+    #[event("distribution_share")]
+    fn distribution_share_event(&self, #[indexed] target: &DistributionTarget<Self::Api>, amount: &BigUint);
+
+    #[event("esdt_distribution_share")]
+    fn esdt_distribution_share_event(
+        &self,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] target: &DistributionTarget<Self::Api>,
+        amount: &BigUint
+    );
+
+    #[event("burn_swap_processed")]
+    fn burn_swap_processed_event(&self, #[indexed] egld_amount: &BigUint, burned: &BigUint);
+
     #[event("distribution_processed")]
     fn distribution_processed_event(
         &self,
@@ -3014,77 +6613,155 @@ pub trait CircleOfLifeCenter {
     #[event("liquidity_processing_error")]
     fn liquidity_processing_error_event(&self, #[indexed] step: &ManagedBuffer, error: &ManagedBuffer);
 
+    #[event("price_guard_policy_changed")]
+    fn price_guard_policy_changed_event(
+        &self,
+        #[indexed] changed_by: &ManagedAddress,
+        policy: &PriceGuardPolicy<Self::Api>
+    );
+
+    #[event("price_reference_updated")]
+    fn price_reference_updated_event(&self, #[indexed] spot_price: &BigUint, twap_price: &BigUint);
+
+    #[event("swap_blocked")]
+    fn swap_blocked_event(
+        &self,
+        #[indexed] spot_price: &BigUint,
+        #[indexed] reference_price: &BigUint,
+        deviation_bps: &BigUint
+    );
+
+    #[event("liquidity_stage_changed")]
+    fn liquidity_stage_changed_event(&self, #[indexed] stage: LiquidityStage);
+
     #[event("liquidity_step_completed")]
     fn liquidity_step_completed_event(&self, #[indexed] step: &ManagedBuffer);
 
+    #[event("protocol_liquidity_locked")]
+    fn protocol_liquidity_locked_event(&self, #[indexed] lock_id: u64, lock: &ProtocolLock<Self::Api>);
+
+    #[event("protocol_liquidity_unlocked")]
+    fn protocol_liquidity_unlocked_event(&self, #[indexed] lock_id: u64, lp_amount: &BigUint);
+
+    #[event("protocol_liquidity_removed")]
+    fn protocol_liquidity_removed_event(
+        &self,
+        #[indexed] lock_id: u64,
+        #[indexed] egld_recovered: &BigUint,
+        xcirclex_recovered: &BigUint
+    );
+
+    #[event("protocol_liquidity_proceeds_distributed")]
+    fn protocol_liquidity_proceeds_distributed_event(&self, amount: &BigUint);
+
     // ═══════════════════════════════════════════════════════════════
     // CALLBACKS - XEXCHANGE LIQUIDITY PROCESSING (Promises API)
     // ═══════════════════════════════════════════════════════════════
+    // Les valeurs retournees (paiements renvoyes par WEGLD / xExchange / LP Locker)
+    // sont ignorees: les soldes du SC font foi
+
+    /// Callback du wrap de processBurnSwap: les WEGLD attendent l'etape de swap
+    #[promises_callback]
+    fn burn_swap_wrap_callback(&self, egld_amount: BigUint, #[call_result] result: ManagedAsyncCallResult<IgnoreValue>) {
+        self.burn_swap_in_flight().clear();
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                self.burn_swap_wegld().update(|wegld| *wegld += &egld_amount);
+            },
+            ManagedAsyncCallResult::Err(err) => {
+                // Les EGLD sont revenus dans SC0: ils redeviennent en attente
+                self.pending_burn_swap_egld().update(|pending| *pending += &egld_amount);
+                self.liquidity_processing_error_event(&ManagedBuffer::from(b"burnSwapWrap"), &err.err_msg);
+            }
+        }
+    }
+
+    /// Callback du swap de processBurnSwap: brule les XCIRCLEX rachetes.
+    /// Le montant vient du paiement retourne par la paire, pas du solde de SC0
+    /// qui peut contenir d'autres XCIRCLEX (pool de recompenses, pipeline de liquidite)
+    #[promises_callback]
+    fn burn_swap_callback(
+        &self,
+        wegld_amount: BigUint,
+        #[call_result] result: ManagedAsyncCallResult<EsdtTokenPayment>
+    ) {
+        self.burn_swap_in_flight().clear();
+        match result {
+            ManagedAsyncCallResult::Ok(payment) => {
+                let bought = payment.amount;
+                if bought > BigUint::zero() {
+                    self.send().esdt_local_burn(&payment.token_identifier, 0, &bought);
+                    self.total_burned().update(|total| *total += &bought);
+                }
+                self.burn_swap_processed_event(&wegld_amount, &bought);
+            },
+            ManagedAsyncCallResult::Err(err) => {
+                // Les WEGLD sont revenus dans SC0: le swap peut etre relance
+                self.burn_swap_wegld().update(|wegld| *wegld += &wegld_amount);
+                self.liquidity_processing_error_event(&ManagedBuffer::from(b"burnSwap"), &err.err_msg);
+            }
+        }
+    }
 
     /// Callback apres wrap EGLD -> WEGLD
-    /// NOTE: Ne chaine PAS d'autre appel async - l'utilisateur doit appeler l'etape suivante
+    /// NOTE: Ne chaine PAS d'autre appel async - appeler advanceLiquidity pour l'etape suivante
     #[promises_callback]
-    fn wrap_egld_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>) {
+    fn wrap_egld_callback(&self, #[call_result] result: ManagedAsyncCallResult<IgnoreValue>) {
         match result {
-            ManagedAsyncCallResult::Ok(()) => {
+            ManagedAsyncCallResult::Ok(_) => {
                 // WEGLD recu avec succes
                 let wegld_for_swap = self.pending_wegld_for_swap().get();
                 let total_wegld = &wegld_for_swap + &self.pending_wegld_for_lp().get();
                 self.wegld_wrapped_event(&total_wegld);
 
-                // Marquer l'etape comme terminee - PAS de chaining async
-                self.liquidity_processing_in_progress().set(false);
+                self.liquidity_failed_attempts().clear();
+                self.set_stage(LiquidityStage::Wrapped);
                 self.liquidity_step_completed_event(&ManagedBuffer::from(b"wrap"));
-                // L'utilisateur doit maintenant appeler liquidityStep2_Swap
             },
             ManagedAsyncCallResult::Err(err) => {
-                self.liquidity_processing_error_event(
-                    &ManagedBuffer::from(b"wrap"),
-                    &err.err_msg
-                );
-                self.cleanup_failed_processing();
+                // Les EGLD sont revenus dans SC0: ils redeviennent en attente
+                let refunded = self.pending_wegld_for_swap().take() + self.pending_wegld_for_lp().take();
+                self.pending_liquidity_egld().update(|pending| *pending += &refunded);
+                self.rewind_liquidity_stage(LiquidityStage::Idle, b"wrap", &err.err_msg);
             }
         }
     }
 
     /// Callback apres swap WEGLD -> XCIRCLEX
-    /// NOTE: Ne chaine PAS d'autre appel async - l'utilisateur doit appeler l'etape suivante
+    /// NOTE: Ne chaine PAS d'autre appel async - appeler advanceLiquidity pour l'etape suivante
     #[promises_callback]
-    fn swap_xcirclex_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>) {
+    fn swap_xcirclex_callback(&self, #[call_result] result: ManagedAsyncCallResult<IgnoreValue>) {
         match result {
-            ManagedAsyncCallResult::Ok(()) => {
-                // Recuperer le XCIRCLEX recu (balance du SC)
+            ManagedAsyncCallResult::Ok(_) => {
+                // XCIRCLEX recus = solde actuel - solde avant swap
                 let xcirclex_token = self.xcirclex_token_id().get();
                 let xcirclex_balance = self.blockchain().get_sc_balance(
                     &EgldOrEsdtTokenIdentifier::esdt(xcirclex_token.clone()), 0
                 );
+                let xcirclex_received = xcirclex_balance - self.pending_xcirclex_for_lp().get();
 
                 let wegld_swapped = self.pending_wegld_for_swap().get();
-                self.swap_executed_event(&wegld_swapped, &xcirclex_balance);
+                self.swap_executed_event(&wegld_swapped, &xcirclex_received);
 
                 // Stocker pour LP
-                self.pending_xcirclex_for_lp().set(&xcirclex_balance);
+                self.pending_xcirclex_for_lp().set(&xcirclex_received);
 
-                // Marquer l'etape comme terminee - PAS de chaining async
-                self.liquidity_processing_in_progress().set(false);
+                self.liquidity_failed_attempts().clear();
+                self.set_stage(LiquidityStage::Swapped);
                 self.liquidity_step_completed_event(&ManagedBuffer::from(b"swap"));
-                // L'utilisateur doit maintenant appeler liquidityStep3_AddLiquidity
             },
             ManagedAsyncCallResult::Err(err) => {
-                self.liquidity_processing_error_event(
-                    &ManagedBuffer::from(b"swap"),
-                    &err.err_msg
-                );
-                self.cleanup_failed_processing();
+                self.pending_xcirclex_for_lp().clear();
+                self.rewind_liquidity_stage(LiquidityStage::Wrapped, b"swap", &err.err_msg);
             }
         }
     }
 
     /// Callback apres addLiquidity
     #[promises_callback]
-    fn add_liquidity_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>) {
+    fn add_liquidity_callback(&self, #[call_result] result: ManagedAsyncCallResult<IgnoreValue>) {
         match result {
-            ManagedAsyncCallResult::Ok(()) => {
+            ManagedAsyncCallResult::Ok(_) => {
                 // Recuperer les LP tokens recus
                 let lp_token = self.lp_token_id().get();
                 let lp_balance = self.blockchain().get_sc_balance(
@@ -3096,40 +6773,157 @@ pub trait CircleOfLifeCenter {
                 // Stocker les LP tokens en attente de lock (multi-level async pas permis)
                 self.pending_lp_tokens().set(&lp_balance);
 
+                // Apports reels = montants envoyes - excedents rendus, conserves pour le lock
+                let wegld_refund = self.blockchain().get_sc_balance(
+                    &EgldOrEsdtTokenIdentifier::esdt(self.wegld_token_id().get()), 0
+                );
+                let xcirclex_refund = self.blockchain().get_sc_balance(
+                    &EgldOrEsdtTokenIdentifier::esdt(self.xcirclex_token_id().get()), 0
+                ) - self.xcirclex_balance_outside_lp().take();
+                let egld_contributed = self.pending_wegld_for_lp().get() - wegld_refund;
+                let xcirclex_contributed = self.pending_xcirclex_for_lp().get() - xcirclex_refund;
+
                 self.cleanup_processing();
-                // Note: Appeler lockPendingLpTokens() manuellement apres
-                self.liquidity_processing_completed_event();
+                self.lp_egld_contributed().set(&egld_contributed);
+                self.lp_xcirclex_contributed().set(&xcirclex_contributed);
+                self.liquidity_failed_attempts().clear();
+                self.set_stage(LiquidityStage::LiquidityAdded);
+                self.liquidity_step_completed_event(&ManagedBuffer::from(b"addLiquidity"));
             },
             ManagedAsyncCallResult::Err(err) => {
-                self.liquidity_processing_error_event(
-                    &ManagedBuffer::from(b"addLiquidity"),
-                    &err.err_msg
-                );
-                self.cleanup_failed_processing();
+                self.xcirclex_balance_outside_lp().clear();
+                self.rewind_liquidity_stage(LiquidityStage::Swapped, b"addLiquidity", &err.err_msg);
             }
         }
     }
 
-    /// Callback apres lock LP tokens
+    /// Callback apres lock LP tokens: enregistre le lock dans la liquidite du protocole
     #[promises_callback]
     fn lock_lp_callback(
         &self,
         lp_amount: BigUint,
         duration: u64,
-        #[call_result] result: ManagedAsyncCallResult<()>
+        #[call_result] result: ManagedAsyncCallResult<OptionalValue<u64>>
     ) {
         match result {
-            ManagedAsyncCallResult::Ok(()) => {
+            ManagedAsyncCallResult::Ok(lock_id) => {
+                // Sans ID valide (le locker les attribue a partir de 1) le lock ne peut pas
+                // etre suivi: l'etape reste a LiquidityAdded pour reprise manuelle
+                // (le lock non enregistre reste recuperable via unlockLpTokens)
+                let lock_id = lock_id.into_option().unwrap_or_default();
+                if lock_id == 0 || !self.protocol_lock_position(lock_id).is_empty() {
+                    self.rewind_liquidity_stage(
+                        LiquidityStage::LiquidityAdded,
+                        b"lockLp",
+                        &ManagedBuffer::from(b"ID de lock invalide"),
+                    );
+                    return;
+                }
+
                 self.lp_locked_event(&lp_amount, duration);
+                self.record_protocol_lock(lock_id, &lp_amount, duration);
                 self.cleanup_processing();
+                self.pending_lp_tokens().clear();
+                self.liquidity_failed_attempts().clear();
+                self.set_stage(LiquidityStage::Idle);
                 self.liquidity_processing_completed_event();
             },
             ManagedAsyncCallResult::Err(err) => {
-                self.liquidity_processing_error_event(
-                    &ManagedBuffer::from(b"lockLp"),
-                    &err.err_msg
-                );
-                self.cleanup_failed_processing();
+                self.rewind_liquidity_stage(LiquidityStage::LiquidityAdded, b"lockLp", &err.err_msg);
+            }
+        }
+    }
+
+    /// Callback apres unlock d'un lock protocolaire: les LP tokens restent dans SC0
+    #[promises_callback]
+    fn protocol_unlock_callback(
+        &self,
+        lock_id: u64,
+        proposal_id: u64,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>
+    ) {
+        self.protocol_withdrawal_in_flight().clear();
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                let mut lock = self.get_protocol_lock(lock_id);
+                lock.status = ProtocolLockStatus::Unlocked;
+                self.protocol_lp_unlocked().update(|unlocked| *unlocked += &lock.lp_amount);
+                self.protocol_liquidity_unlocked_event(lock_id, &lock.lp_amount);
+                self.set_protocol_lock(&lock);
+            },
+            ManagedAsyncCallResult::Err(err) => {
+                self.liquidity_processing_error_event(&ManagedBuffer::from(b"protocolUnlock"), &err.err_msg);
+                self.reopen_dao_proposal(proposal_id, &err.err_msg);
+            }
+        }
+    }
+
+    /// Callback apres removeLiquidity d'un lock protocolaire.
+    /// XCIRCLEX recuperes -> repartis selon la table de distribution (distribute_recovered_xcirclex),
+    /// WEGLD recuperes -> en attente de distributeLiquidityProceeds
+    #[promises_callback]
+    fn protocol_remove_callback(
+        &self,
+        lock_id: u64,
+        proposal_id: u64,
+        wegld_before: BigUint,
+        xcirclex_before: BigUint,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>
+    ) {
+        self.protocol_withdrawal_in_flight().clear();
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                let xcirclex_token = self.xcirclex_token_id().get();
+                let egld_recovered = self.blockchain().get_sc_balance(
+                    &EgldOrEsdtTokenIdentifier::esdt(self.wegld_token_id().get()), 0
+                ) - wegld_before;
+                let xcirclex_recovered = self.blockchain().get_sc_balance(
+                    &EgldOrEsdtTokenIdentifier::esdt(xcirclex_token.clone()), 0
+                ) - xcirclex_before;
+
+                let mut lock = self.get_protocol_lock(lock_id);
+                lock.status = ProtocolLockStatus::Removed;
+                lock.egld_recovered = egld_recovered.clone();
+                lock.xcirclex_recovered = xcirclex_recovered.clone();
+                self.set_protocol_lock(&lock);
+
+                self.protocol_lp_unlocked().update(|unlocked| *unlocked -= &lock.lp_amount);
+                self.total_protocol_lp().update(|total| *total -= &lock.lp_amount);
+                self.total_protocol_egld_recovered().update(|total| *total += &egld_recovered);
+                self.total_protocol_xcirclex_recovered().update(|total| *total += &xcirclex_recovered);
+                self.pending_proceeds_wegld().update(|pending| *pending += &egld_recovered);
+
+                if xcirclex_recovered > BigUint::zero() {
+                    self.distribute_recovered_xcirclex(&xcirclex_token, &xcirclex_recovered);
+                }
+
+                self.protocol_liquidity_removed_event(lock_id, &egld_recovered, &xcirclex_recovered);
+            },
+            ManagedAsyncCallResult::Err(err) => {
+                self.liquidity_processing_error_event(&ManagedBuffer::from(b"protocolRemove"), &err.err_msg);
+                self.reopen_dao_proposal(proposal_id, &err.err_msg);
+            }
+        }
+    }
+
+    /// Callback apres unwrap des WEGLD recuperes: les EGLD suivent la table de distribution
+    #[promises_callback]
+    fn proceeds_unwrap_callback(
+        &self,
+        amount: BigUint,
+        proposal_id: u64,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>
+    ) {
+        self.protocol_withdrawal_in_flight().clear();
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                self.process_egld_distribution(&amount);
+                self.protocol_liquidity_proceeds_distributed_event(&amount);
+            },
+            ManagedAsyncCallResult::Err(err) => {
+                self.pending_proceeds_wegld().update(|pending| *pending += &amount);
+                self.liquidity_processing_error_event(&ManagedBuffer::from(b"proceedsUnwrap"), &err.err_msg);
+                self.reopen_dao_proposal(proposal_id, &err.err_msg);
             }
         }
     }
@@ -3139,10 +6933,10 @@ pub trait CircleOfLifeCenter {
     fn unlock_lp_tokens_callback(
         &self,
         recipient: ManagedAddress,
-        #[call_result] result: ManagedAsyncCallResult<()>
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>
     ) {
         match result {
-            ManagedAsyncCallResult::Ok(()) => {
+            ManagedAsyncCallResult::Ok(_) => {
                 // Les LP tokens ont été envoyés à SC0 par le LP Locker
                 // Maintenant on les transfère au recipient
                 let lp_token = self.lp_token_id().get();
@@ -3169,14 +6963,69 @@ pub trait CircleOfLifeCenter {
         self.pending_wegld_for_swap().clear();
         self.pending_wegld_for_lp().clear();
         self.pending_xcirclex_for_lp().clear();
-        self.liquidity_processing_in_progress().set(false);
     }
 
-    /// Nettoyer apres echec (garde les tokens dans le SC pour retry manuel)
-    fn cleanup_failed_processing(&self) {
-        self.liquidity_processing_in_progress().set(false);
-        // Note: Les tokens WEGLD/XCIRCLEX restent dans le SC
-        // L'admin peut les recuperer ou retenter
+    /// Enregistre un lock cree par le pipeline avec les apports de addLiquidity
+    fn record_protocol_lock(&self, lock_id: u64, lp_amount: &BigUint, duration_days: u64) {
+        let now = self.blockchain().get_block_timestamp();
+        let lock = ProtocolLock {
+            lock_id,
+            lp_amount: lp_amount.clone(),
+            egld_contributed: self.lp_egld_contributed().take(),
+            xcirclex_contributed: self.lp_xcirclex_contributed().take(),
+            locked_at: now,
+            unlock_at: now + duration_days * 24 * 60 * 60,
+            status: ProtocolLockStatus::Locked,
+            egld_recovered: BigUint::zero(),
+            xcirclex_recovered: BigUint::zero(),
+        };
+
+        self.total_protocol_lp().update(|total| *total += lp_amount);
+        self.total_protocol_egld_contributed().update(|total| *total += &lock.egld_contributed);
+        self.total_protocol_xcirclex_contributed().update(|total| *total += &lock.xcirclex_contributed);
+
+        self.protocol_locks().push(&lock);
+        self.protocol_lock_position(lock_id).set(self.protocol_locks().len());
+        self.protocol_liquidity_locked_event(lock_id, &lock);
+    }
+
+    /// Signale au DAO V2 l'echec d'une etape de liquidite protocolaire
+    /// pour que sa proposition puisse etre executee a nouveau
+    fn reopen_dao_proposal(&self, proposal_id: u64, reason: &ManagedBuffer) {
+        if self.dao_contract_address().is_empty() {
+            return;
+        }
+        self.dao_proxy(self.dao_contract_address().get())
+            .reopen_circle_of_life_proposal(proposal_id, reason)
+            .sync_call();
+    }
+
+    fn get_protocol_lock(&self, lock_id: u64) -> ProtocolLock<Self::Api> {
+        require!(!self.protocol_lock_position(lock_id).is_empty(), "Lock protocolaire inconnu");
+        self.protocol_locks().get(self.protocol_lock_position(lock_id).get())
+    }
+
+    fn set_protocol_lock(&self, lock: &ProtocolLock<Self::Api>) {
+        self.protocol_locks().set(self.protocol_lock_position(lock.lock_id).get(), lock);
+    }
+
+    /// Operations sur la liquidite protocolaire: DAO V2 seulement, pipeline au repos
+    /// et aucune autre operation en attente de callback
+    fn require_protocol_liquidity_dao(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.dao_contract_address().is_empty() && caller == self.dao_contract_address().get(),
+            "DAO seulement"
+        );
+        require!(
+            self.liquidity_stage().get() == LiquidityStage::Idle,
+            "Etape de liquidite en cours"
+        );
+        require!(
+            !self.protocol_withdrawal_in_flight().get(),
+            "Retrait de liquidite protocolaire en cours"
+        );
+        self.require_no_burn_swap();
     }
 }
 
@@ -3191,6 +7040,13 @@ mod peripheral_proxy {
 
         #[endpoint(forceTransferToSC0)]
         fn force_transfer_to_sc0(&self);
+
+        #[endpoint(transferOwnership)]
+        fn transfer_ownership(&self, new_owner: ManagedAddress);
+
+        /// Solde du SC peripherique en token de circulation de son cercle
+        #[view(getBalance)]
+        fn get_balance(&self) -> BigUint;
     }
 }
 
@@ -3202,6 +7058,9 @@ mod nft_proxy {
     pub trait NftContract {
         #[endpoint(updateMemberCycles)]
         fn update_member_cycles(&self, member: ManagedAddress, cycles: u64);
+
+        #[endpoint(transferMember)]
+        fn transfer_member(&self, old_member: ManagedAddress, new_member: ManagedAddress);
     }
 }
 
@@ -3215,6 +7074,20 @@ mod dao_proxy {
         #[payable("EGLD")]
         #[endpoint(receiveFromCircleOfLife)]
         fn receive_from_circle_of_life(&self);
+
+        /// Creer une proposition d'appel de ban (caution EGLD en sequestre)
+        #[payable("EGLD")]
+        #[endpoint(createBanAppeal)]
+        fn create_ban_appeal(
+            &self,
+            member: &ManagedAddress,
+            sc: &ManagedAddress,
+            reason: ManagedBuffer
+        ) -> u64;
+
+        /// Rouvrir une proposition de liquidite protocolaire dont l'etape a echoue
+        #[endpoint(reopenCircleOfLifeProposal)]
+        fn reopen_circle_of_life_proposal(&self, proposal_id: u64, reason: &ManagedBuffer);
     }
 }
 
@@ -3260,12 +7133,33 @@ mod xexchange_proxy {
             second_token_amount_min: BigUint,
         );
 
+        /// Retirer de la liquidite (paiement en LP tokens)
+        #[payable("*")]
+        #[endpoint(removeLiquidity)]
+        fn remove_liquidity(
+            &self,
+            first_token_amount_min: BigUint,
+            second_token_amount_min: BigUint,
+        );
+
         /// Obtenir les reserves de la paire
         #[view(getReservesAndTotalSupply)]
         fn get_reserves_and_total_supply(&self) -> MultiValue3<BigUint, BigUint, BigUint>;
     }
 }
 
+// Module proxy pour un oracle de prix XCIRCLEX/WEGLD
+mod price_oracle_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait PriceOracleContract {
+        /// Prix XCIRCLEX par WEGLD (precision 10^18)
+        #[view(getPrice)]
+        fn get_price(&self) -> BigUint;
+    }
+}
+
 // Module proxy pour le LP Locker
 mod lp_locker_proxy {
     multiversx_sc::imports!();
@@ -3275,7 +7169,7 @@ mod lp_locker_proxy {
         /// Verrouiller des LP tokens
         #[payable("*")]
         #[endpoint(lockLpTokens)]
-        fn lock_lp_tokens(&self, lock_duration_days: u64);
+        fn lock_lp_tokens(&self, lock_duration_days: u64) -> u64;
 
         /// Déverrouiller des LP tokens après expiration
         #[endpoint(unlock)]
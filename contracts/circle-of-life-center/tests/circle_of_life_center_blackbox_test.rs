@@ -0,0 +1,3280 @@
+use multiversx_sc_scenario::imports::*;
+use multiversx_sc_scenario::scenario_model::Log;
+
+use circle_of_life_center::circle_of_life_center_proxy::{
+    CircleOfLifeCenterProxy, CirculationPolicy, CirculationTier, ClaimSchedule, CycleHop, CycleRecord, CycleStatus,
+    DelegateScope, DistributionShare, DistributionTarget, KeeperTipSource, LiquidityStage, PriceGuardPolicy,
+    RotationStrategy,
+};
+use circle_peripheral::circle_peripheral_proxy::CirclePeripheralProxy;
+
+const OWNER: TestAddress = TestAddress::new("owner");
+const MEMBER_1: TestAddress = TestAddress::new("member-1");
+const MEMBER_2: TestAddress = TestAddress::new("member-2");
+const MEMBER_3: TestAddress = TestAddress::new("member-3");
+const MEMBER_4: TestAddress = TestAddress::new("member-4");
+const DAO_ADDRESS: TestAddress = TestAddress::new("dao");
+const KEEPER_BOT: TestAddress = TestAddress::new("keeper-bot");
+const SC0_ADDRESS: TestSCAddress = TestSCAddress::new("circle-of-life-center");
+const NFT_ADDRESS: TestSCAddress = TestSCAddress::new("xcirclex-nft");
+const DAO_V2_ADDRESS: TestSCAddress = TestSCAddress::new("xcirclex-dao-v2");
+const TEMPLATE_ADDRESS: TestSCAddress = TestSCAddress::new("peripheral-template");
+const TEMPLATE_V2_ADDRESS: TestSCAddress = TestSCAddress::new("peripheral-template-v2");
+const SC0_CODE_PATH: MxscPath = MxscPath::new("output/circle-of-life-center.mxsc.json");
+const PERIPHERAL_CODE_PATH: MxscPath =
+    MxscPath::new("../circle-peripheral/output/circle-peripheral.mxsc.json");
+const NFT_CODE_PATH: MxscPath = MxscPath::new("../xcirclex-nft/output/xcirclex-nft.mxsc.json");
+const DAO_V2_CODE_PATH: MxscPath =
+    MxscPath::new("../xcirclex-dao-v2/output/xcirclex-dao-v2.mxsc.json");
+const REWARD_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("XCX-123456");
+const USDC_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("USDC-123456");
+
+const ONE_EGLD: u64 = 1_000_000_000_000_000_000;
+const CIRCULATION_AMOUNT: u64 = 1_000_000_000_000_000;
+const DAY: u64 = 86_400;
+/// Type de proposition du DAO V2 remplacant la table de distribution de SC0
+const SET_DISTRIBUTION_TABLE: u8 = 11;
+/// Type de proposition du DAO V2 changeant la politique de circulation d'un cercle
+const SET_CIRCULATION_POLICY: u8 = 12;
+/// joinCircle transmet tout le gas restant au deploiement du SC peripherique
+const JOIN_GAS_LIMIT: u64 = 100_000_000;
+/// Appel par le membre lui-meme (pas de delegation)
+const NOT_DELEGATED: OptionalValue<ManagedAddress<StaticApi>> = OptionalValue::None;
+const MAIN_RING: OptionalValue<u64> = OptionalValue::None;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.register_contract(SC0_CODE_PATH, circle_of_life_center::ContractBuilder);
+    blockchain.register_contract(PERIPHERAL_CODE_PATH, circle_peripheral::ContractBuilder);
+    blockchain.register_contract(NFT_CODE_PATH, xcirclex_nft::ContractBuilder);
+    blockchain.register_contract(DAO_V2_CODE_PATH, xcirclex_dao_v2::ContractBuilder);
+    blockchain
+}
+
+fn xcx(amount: u64) -> BigUint<StaticApi> {
+    BigUint::from(amount) * BigUint::from(10u64).pow(18)
+}
+
+fn set_day(world: &mut ScenarioWorld, day: u64) {
+    world.current_block().block_timestamp(day * DAY);
+}
+
+/// Deploie SC0 + le template peripherique et fait rejoindre les membres donnes.
+/// Retourne les adresses des SC peripheriques, dans l'ordre d'adhesion.
+fn setup(world: &mut ScenarioWorld, members: &[TestAddress]) -> Vec<Address> {
+    world
+        .account(OWNER)
+        .nonce(1)
+        .balance(5 * ONE_EGLD)
+        .esdt_balance(REWARD_TOKEN, xcx(200_000));
+    for member in members {
+        world.account(*member).nonce(1).balance(5 * ONE_EGLD);
+    }
+
+    world
+        .tx()
+        .from(OWNER)
+        .typed(CircleOfLifeCenterProxy)
+        .init()
+        .code(SC0_CODE_PATH)
+        .code_metadata(CodeMetadata::UPGRADEABLE | CodeMetadata::PAYABLE_BY_SC)
+        .new_address(SC0_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .typed(CirclePeripheralProxy)
+        .init(OWNER, SC0_ADDRESS, OptionalValue::<u64>::None)
+        .code(PERIPHERAL_CODE_PATH)
+        .new_address(TEMPLATE_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_peripheral_template(TEMPLATE_ADDRESS)
+        .run();
+
+    members
+        .iter()
+        .map(|member| {
+            world
+                .tx()
+                .from(*member)
+                .to(SC0_ADDRESS)
+                .typed(CircleOfLifeCenterProxy)
+                .join_circle(MAIN_RING)
+                .egld(ONE_EGLD)
+                .gas(JOIN_GAS_LIMIT)
+                .returns(ReturnsResultUnmanaged)
+                .run()
+        })
+        .collect()
+}
+
+fn setup_rewards(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_reward_token(REWARD_TOKEN)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .deposit_rewards()
+        .payment(EsdtTokenPayment::new(REWARD_TOKEN.to_token_identifier(), 0, xcx(200_000)))
+        .run();
+}
+
+fn start_cycle(world: &mut ScenarioWorld, starter: TestAddress) {
+    world
+        .tx()
+        .from(starter)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .start_daily_cycle(MAIN_RING)
+        .run();
+}
+
+fn pre_sign(world: &mut ScenarioWorld, member: TestAddress) {
+    world
+        .tx()
+        .from(member)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .pre_sign(NOT_DELEGATED)
+        .run();
+}
+
+fn fail_cycle(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .fail_cycle(MAIN_RING)
+        .run();
+}
+
+fn set_active(world: &mut ScenarioWorld, member: TestAddress) {
+    world
+        .tx()
+        .from(member)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_active(NOT_DELEGATED)
+        .run();
+}
+
+fn cycles_completed(world: &mut ScenarioWorld) -> u64 {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_cycles_completed(MAIN_RING)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+fn sc_cycles_completed(world: &mut ScenarioWorld, sc: &Address) -> u64 {
+    let (completed, _failed, _ban_until, _is_banned, _infractions) = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_sc_stats(sc)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple();
+    completed
+}
+
+/// (infraction_count, last_infraction, ban_until, remaining_ban_days, days_until_reset)
+fn infraction_info(world: &mut ScenarioWorld, sc: &Address) -> (u64, u64, u64, u64, u64) {
+    let (count, last, ban_until, remaining, until_reset, _grace, _warnings, _ban_days, _reset_days, _credit) =
+        world
+            .query()
+            .to(SC0_ADDRESS)
+            .typed(CircleOfLifeCenterProxy)
+            .get_infraction_info(sc)
+            .returns(ReturnsResultUnmanaged)
+            .run()
+            .into_tuple();
+    (count, last, ban_until, remaining, until_reset)
+}
+
+fn pending_rewards(world: &mut ScenarioWorld, member: TestAddress) -> RustBigUint {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_pending_rewards(member)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+fn has_event(logs: &[Log], identifier: &str) -> bool {
+    logs.iter()
+        .any(|log| log.topics.first().map(|t| t.as_slice()) == Some(identifier.as_bytes()))
+}
+
+#[test]
+fn join_circle_deploys_peripherals() {
+    let mut world = world();
+    let peripherals = setup(&mut world, &[MEMBER_1, MEMBER_2, MEMBER_3]);
+
+    assert_eq!(peripherals.len(), 3);
+
+    for (member, sc) in [MEMBER_1, MEMBER_2, MEMBER_3].iter().zip(peripherals.iter()) {
+        world
+            .query()
+            .to(SC0_ADDRESS)
+            .typed(CircleOfLifeCenterProxy)
+            .get_contract_owner(sc)
+            .returns(ExpectValue(OptionalValue::Some(member.to_managed_address())))
+            .run();
+
+        world
+            .query()
+            .to(sc)
+            .typed(CirclePeripheralProxy)
+            .get_sc0_address()
+            .returns(ExpectValue(SC0_ADDRESS))
+            .run();
+    }
+
+    // Distribution desactivee par defaut: les frais d'entree restent dans SC0
+    world
+        .check_account(SC0_ADDRESS)
+        .balance(3 * ONE_EGLD);
+}
+
+#[test]
+fn full_daily_cycle_with_pre_signatures() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+    setup_rewards(&mut world);
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, MEMBER_1);
+
+    world
+        .check_account(&peripherals[0])
+        .balance(CIRCULATION_AMOUNT);
+
+    for member in members {
+        pre_sign(&mut world, member);
+    }
+
+    let (_, logs) = world
+        .tx()
+        .from(MEMBER_2)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(MAIN_RING)
+        .returns(ExpectValue(3u32))
+        .returns(ReturnsLogs)
+        .run();
+
+    assert!(has_event(&logs, "cycle_completed"));
+    assert_eq!(cycles_completed(&mut world), 1);
+
+    for sc in peripherals.iter() {
+        assert_eq!(sc_cycles_completed(&mut world, sc), 1);
+        world.check_account(sc).balance(0u64);
+    }
+
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_cycle_holder(MAIN_RING)
+        .returns(ExpectValue(OptionalValue::<ManagedAddress<StaticApi>>::None))
+        .run();
+
+    // 36 000 XCX / 3 membres = 12 000 XCX, + 3.14% de bonus pionnier = 12 376.8 XCX
+    let expected = xcx(12_000) + xcx(12_000) * 314u64 / 10_000u64;
+    for member in members {
+        assert_eq!(
+            pending_rewards(&mut world, member),
+            expected.to_alloc()
+        );
+    }
+}
+
+#[test]
+fn sign_and_forward_chains_pre_signed_members() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+
+    // Seuls les membres 2 et 3 pre-signent: le membre 1 doit signer lui-meme
+    pre_sign(&mut world, MEMBER_2);
+    pre_sign(&mut world, MEMBER_3);
+
+    world
+        .tx()
+        .from(MEMBER_2)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .sign_and_forward(NOT_DELEGATED)
+        .with_result(ExpectError(4, "Ce n'est pas votre tour"))
+        .run();
+
+    let logs = world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .sign_and_forward(NOT_DELEGATED)
+        .returns(ReturnsLogs)
+        .run();
+
+    assert!(has_event(&logs, "cycle_completed"));
+    assert_eq!(cycles_completed(&mut world), 1);
+    for sc in peripherals.iter() {
+        assert_eq!(sc_cycles_completed(&mut world, sc), 1);
+    }
+
+    // Le montant circulant est revenu a SC0
+    world
+        .check_account(SC0_ADDRESS)
+        .balance(3 * ONE_EGLD);
+
+    // Un seul cycle par jour
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .start_daily_cycle(MAIN_RING)
+        .with_result(ExpectError(4, "Cycle deja demarre aujourd'hui"))
+        .run();
+
+    set_day(&mut world, 2);
+    start_cycle(&mut world, OWNER);
+    for member in members {
+        pre_sign(&mut world, member);
+    }
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(MAIN_RING)
+        .returns(ExpectValue(3u32))
+        .run();
+
+    assert_eq!(cycles_completed(&mut world), 2);
+    for sc in peripherals.iter() {
+        assert_eq!(sc_cycles_completed(&mut world, sc), 2);
+    }
+}
+
+#[test]
+fn fail_cycle_requires_timeout() {
+    let mut world = world();
+    setup(&mut world, &[MEMBER_1, MEMBER_2]);
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .fail_cycle(MAIN_RING)
+        .with_result(ExpectError(4, "Le cycle n'est pas encore en timeout"))
+        .run();
+}
+
+#[test]
+fn fail_cycle_progressive_ban() {
+    let mut world = world();
+    let peripherals = setup(&mut world, &[MEMBER_1]);
+    let sc = &peripherals[0];
+
+    // 1ere infraction: 30 jours
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+    set_day(&mut world, 2);
+
+    let logs = world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .fail_cycle(MAIN_RING)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "cycle_failed"));
+    assert!(has_event(&logs, "sc_banned_progressive"));
+
+    let (count, last, ban_until, remaining, until_reset) = infraction_info(&mut world, sc);
+    assert_eq!(count, 1);
+    assert_eq!(last, 2 * DAY);
+    assert_eq!(ban_until, 32 * DAY);
+    assert_eq!(remaining, 30);
+    assert_eq!(until_reset, 360);
+
+    // Le montant circulant est recupere par SC0
+    world.check_account(sc).balance(0u64);
+    world.check_account(SC0_ADDRESS).balance(ONE_EGLD);
+
+    // Impossible de se reactiver pendant le ban
+    set_day(&mut world, 31);
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_active(NOT_DELEGATED)
+        .with_result(ExpectError(4, "Votre SC est temporairement banni"))
+        .run();
+
+    // 2eme infraction: 60 jours
+    set_day(&mut world, 32);
+    set_active(&mut world, MEMBER_1);
+    start_cycle(&mut world, OWNER);
+    set_day(&mut world, 33);
+    fail_cycle(&mut world);
+
+    let (count, _, ban_until, _, _) = infraction_info(&mut world, sc);
+    assert_eq!(count, 2);
+    assert_eq!(ban_until, (33 + 60) * DAY);
+
+    // 3eme infraction: 90 jours
+    set_day(&mut world, 93);
+    set_active(&mut world, MEMBER_1);
+    start_cycle(&mut world, OWNER);
+    set_day(&mut world, 94);
+    fail_cycle(&mut world);
+
+    let (count, _, ban_until, _, _) = infraction_info(&mut world, sc);
+    assert_eq!(count, 3);
+    assert_eq!(ban_until, (94 + 90) * DAY);
+
+    // Apres 360 jours sans infraction, le compteur repart de zero
+    set_day(&mut world, 94 + 360);
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .will_infraction_reset(sc)
+        .returns(ExpectValue(true))
+        .run();
+
+    set_active(&mut world, MEMBER_1);
+    start_cycle(&mut world, OWNER);
+    set_day(&mut world, 94 + 361);
+    fail_cycle(&mut world);
+
+    let (count, last, ban_until, _, _) = infraction_info(&mut world, sc);
+    assert_eq!(count, 1);
+    assert_eq!(last, (94 + 361) * DAY);
+    assert_eq!(ban_until, (94 + 361 + 30) * DAY);
+
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_cycle_stats(MAIN_RING)
+        .returns(ExpectValue(MultiValue3::from((0u64, 4u64, 4u64))))
+        .run();
+}
+
+fn sorted(addresses: &[Address]) -> Vec<Address> {
+    let mut sorted = addresses.to_vec();
+    sorted.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
+    sorted
+}
+
+fn rotation_order(world: &mut ScenarioWorld) -> Vec<Address> {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_rotation_order_view(MAIN_RING)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+#[test]
+fn reverse_rotation_starts_with_last_member() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_rotation_strategy(RotationStrategy::Reverse, MAIN_RING)
+        .run();
+
+    let expected: Vec<Address> = peripherals.iter().rev().cloned().collect();
+    assert_eq!(rotation_order(&mut world), expected);
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+    world.check_account(&peripherals[2]).balance(CIRCULATION_AMOUNT);
+
+    // Le tour suit l'ordre inverse: le membre 1 ferme le cercle
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .sign_and_forward(NOT_DELEGATED)
+        .with_result(ExpectError(4, "Ce n'est pas votre tour"))
+        .run();
+
+    for member in [MEMBER_3, MEMBER_2, MEMBER_1] {
+        world
+            .tx()
+            .from(member)
+            .to(SC0_ADDRESS)
+            .typed(CircleOfLifeCenterProxy)
+            .sign_and_forward(NOT_DELEGATED)
+            .run();
+    }
+    assert_eq!(cycles_completed(&mut world), 1);
+}
+
+#[test]
+fn rotation_strategy_governance() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_rotation_strategy(RotationStrategy::Reverse, MAIN_RING)
+        .with_result(ExpectError(4, "Owner ou DAO seulement"))
+        .run();
+
+    world.account(DAO_ADDRESS).nonce(1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_dao_contract(DAO_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(DAO_ADDRESS)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_rotation_strategy(RotationStrategy::EpochShuffle, MAIN_RING)
+        .run();
+
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_rotation_strategy(MAIN_RING)
+        .returns(ExpectValue(RotationStrategy::EpochShuffle))
+        .run();
+
+    // Le melange est une permutation des SC actifs, stable pendant l'epoch
+    let order = rotation_order(&mut world);
+    assert_eq!(order, rotation_order(&mut world));
+    assert_eq!(sorted(&order), sorted(&peripherals));
+
+    set_day(&mut world, 1);
+    let planned = rotation_order(&mut world);
+    start_cycle(&mut world, OWNER);
+    world.check_account(&planned[0]).balance(CIRCULATION_AMOUNT);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_rotation_strategy(RotationStrategy::InsertionOrder, MAIN_RING)
+        .with_result(ExpectError(4, "Impossible de changer la rotation pendant un cycle"))
+        .run();
+
+    for member in members {
+        pre_sign(&mut world, member);
+    }
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(MAIN_RING)
+        .returns(ExpectValue(3u32))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_rotation_strategy(RotationStrategy::ReputationWeighted, MAIN_RING)
+        .run();
+
+    let order = rotation_order(&mut world);
+    assert_eq!(sorted(&order), sorted(&peripherals));
+}
+
+fn active_contracts(world: &mut ScenarioWorld) -> Vec<Address> {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_active_contracts_view(MAIN_RING)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+#[test]
+fn rotation_is_frozen_for_the_running_cycle() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+
+    // Le membre 2 passe inactif en plein cycle: la rotation ne bouge pas
+    world
+        .tx()
+        .from(MEMBER_2)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_inactive(NOT_DELEGATED)
+        .run();
+
+    assert_eq!(active_contracts(&mut world), vec![peripherals[0].clone(), peripherals[2].clone()]);
+    assert_eq!(rotation_order(&mut world), peripherals);
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .sign_and_forward(NOT_DELEGATED)
+        .run();
+    world
+        .tx()
+        .from(MEMBER_3)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .sign_and_forward(NOT_DELEGATED)
+        .with_result(ExpectError(4, "Ce n'est pas votre tour"))
+        .run();
+
+    for member in [MEMBER_2, MEMBER_3] {
+        world
+            .tx()
+            .from(member)
+            .to(SC0_ADDRESS)
+            .typed(CircleOfLifeCenterProxy)
+            .sign_and_forward(NOT_DELEGATED)
+            .run();
+    }
+    assert_eq!(cycles_completed(&mut world), 1);
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[1]), 1);
+
+    // Le cycle suivant ne contient plus que les SC actifs
+    assert_eq!(rotation_order(&mut world), vec![peripherals[0].clone(), peripherals[2].clone()]);
+}
+
+#[test]
+fn rebuild_active_index_in_batches() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+    set_active(&mut world, MEMBER_1);
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .rebuild_active_index(1usize, 2usize)
+        .with_result(ExpectError(4, "Owner only"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .rebuild_active_index(1usize, 2usize)
+        .returns(ExpectValue(3usize))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .rebuild_active_index(3usize, 10usize)
+        .returns(ExpectValue(4usize))
+        .run();
+
+    // Idempotent: l'index reste coherent avec contract_active
+    assert_eq!(active_contracts(&mut world), peripherals);
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .rebuild_active_index(1usize, 3usize)
+        .with_result(ExpectError(4, "Impossible de reconstruire l'index pendant un cycle"))
+        .run();
+}
+
+fn settlement_info(world: &mut ScenarioWorld) -> (bool, u64, usize, usize) {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_settlement_info(MAIN_RING)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple()
+}
+
+fn setup_nft(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(NFT_CODE_PATH)
+        .new_address(NFT_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(NFT_ADDRESS)
+        .raw_call("setCircleOfLifeContract")
+        .argument(&SC0_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_nft_contract(NFT_ADDRESS)
+        .run();
+}
+
+fn nft_member_cycles_key(member: TestAddress) -> String {
+    format!("str:member_cycles_completed|{}", member.eval_to_expr())
+}
+
+#[test]
+fn cycle_settlement_in_batches() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+    setup_rewards(&mut world);
+    setup_nft(&mut world);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_auto_settlement_items(1usize)
+        .run();
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+    for member in members {
+        pre_sign(&mut world, member);
+    }
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(MAIN_RING)
+        .returns(ExpectValue(3u32))
+        .run();
+
+    // Cycle complete, mais un seul SC synchronise dans la transaction finale
+    assert_eq!(cycles_completed(&mut world), 1);
+    assert_eq!(settlement_info(&mut world), (true, 1, 1, 3));
+    world
+        .check_account(NFT_ADDRESS)
+        .check_storage(&nft_member_cycles_key(MEMBER_1), "1");
+
+    // Les recompenses et les cycles sont deja acquis, sans attendre le reglement
+    let expected = xcx(12_000) + xcx(12_000) * 314u64 / 10_000u64;
+    for (member, sc) in members.iter().zip(peripherals.iter()) {
+        assert_eq!(sc_cycles_completed(&mut world, sc), 1);
+        assert_eq!(pending_rewards(&mut world, *member), expected.to_alloc());
+    }
+
+    // Le cycle suivant ne peut pas demarrer avant la fin du reglement
+    set_day(&mut world, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .start_daily_cycle(MAIN_RING)
+        .with_result(ExpectError(4, "Reglement du cycle precedent en cours - appelez settleCycle"))
+        .run();
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .settle_cycle(1usize, MAIN_RING)
+        .returns(ExpectValue(1usize))
+        .run();
+    let (_, logs) = world
+        .tx()
+        .from(MEMBER_2)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .settle_cycle(10usize, MAIN_RING)
+        .returns(ExpectValue(0usize))
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "cycle_settled"));
+    assert_eq!(settlement_info(&mut world), (false, 1, 0, 0));
+    for member in members {
+        world
+            .check_account(NFT_ADDRESS)
+            .check_storage(&nft_member_cycles_key(member), "1");
+    }
+
+    world
+        .tx()
+        .from(MEMBER_2)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .settle_cycle(10usize, MAIN_RING)
+        .with_result(ExpectError(4, "Aucun reglement en attente"))
+        .run();
+
+    start_cycle(&mut world, OWNER);
+}
+
+fn complete_cycle(world: &mut ScenarioWorld, members: &[TestAddress]) {
+    for member in members {
+        pre_sign(world, *member);
+    }
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(MAIN_RING)
+        .run();
+}
+
+#[test]
+fn rewards_accrue_lazily_per_participation() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+    setup_rewards(&mut world);
+
+    // Cycle 1: les trois membres
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+
+    // Le membre 3 se desactive apres avoir pre-signe: il participe quand meme a ce cycle
+    pre_sign(&mut world, MEMBER_3);
+    world
+        .tx()
+        .from(MEMBER_3)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_inactive(NOT_DELEGATED)
+        .run();
+    complete_cycle(&mut world, &[MEMBER_1, MEMBER_2]);
+
+    // Cycle 2: seulement les membres 1 et 2 (18 000 XCX chacun)
+    set_day(&mut world, 2);
+    start_cycle(&mut world, OWNER);
+
+    // Le membre 3 revient en cours de cycle: il participera au cycle suivant
+    set_active(&mut world, MEMBER_3);
+    complete_cycle(&mut world, &[MEMBER_1, MEMBER_2]);
+
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[0]), 2);
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[2]), 1);
+
+    let with_pioneer = |base: BigUint<StaticApi>| (base.clone() + base * 314u64 / 10_000u64).to_alloc();
+    assert_eq!(pending_rewards(&mut world, MEMBER_1), with_pioneer(xcx(12_000) + xcx(18_000)));
+    assert_eq!(pending_rewards(&mut world, MEMBER_3), with_pioneer(xcx(12_000)));
+
+    // Cycle 3: le membre 3 participe de nouveau
+    set_day(&mut world, 3);
+    start_cycle(&mut world, OWNER);
+    complete_cycle(&mut world, &members);
+
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[2]), 2);
+    assert_eq!(
+        pending_rewards(&mut world, MEMBER_3),
+        with_pioneer(xcx(24_000))
+    );
+}
+
+fn next_claim_window(world: &mut ScenarioWorld) -> (u64, u64) {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_next_claim_window()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple()
+}
+
+fn set_claim_schedule(world: &mut ScenarioWorld, schedule: ClaimSchedule) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_claim_schedule(schedule)
+        .run();
+}
+
+fn claim_rewards(world: &mut ScenarioWorld, member: TestAddress) {
+    world
+        .tx()
+        .from(member)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .claim_rewards()
+        .run();
+}
+
+#[test]
+fn claim_schedule_windows() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    setup(&mut world, &members);
+    setup_rewards(&mut world);
+
+    // Jour 1 = vendredi: cycle complet, mais par defaut seul le dimanche (jour 3) est ouvert
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+    complete_cycle(&mut world, &members);
+
+    assert_eq!(next_claim_window(&mut world), (3 * DAY, 4 * DAY));
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .claim_rewards()
+        .with_result(ExpectError(4, "Fenetre de reclamation fermee - voir getNextClaimWindow"))
+        .run();
+
+    // Vendredi + samedi: une seule fenetre de deux jours
+    set_claim_schedule(&mut world, ClaimSchedule::Weekdays { days_mask: 0b0110_0000 });
+    assert_eq!(next_claim_window(&mut world), (DAY, 3 * DAY));
+
+    let expected = xcx(12_000) + xcx(12_000) * 314u64 / 10_000u64;
+    claim_rewards(&mut world, MEMBER_1);
+    world.check_account(MEMBER_1).esdt_balance(REWARD_TOKEN, expected.clone());
+
+    // Un jour sur 5 a partir du jour 4
+    set_claim_schedule(
+        &mut world,
+        ClaimSchedule::EveryNDays { interval_days: 5, anchor_day: 4 },
+    );
+    assert_eq!(next_claim_window(&mut world), (4 * DAY, 5 * DAY));
+    set_day(&mut world, 5);
+    assert_eq!(next_claim_window(&mut world), (9 * DAY, 10 * DAY));
+
+    // Fenetre glissante d'un jour apres chaque cycle reussi
+    set_claim_schedule(
+        &mut world,
+        ClaimSchedule::AfterCycles { every_cycles: 1, window_seconds: DAY },
+    );
+    assert_eq!(next_claim_window(&mut world), (0, 0));
+
+    start_cycle(&mut world, OWNER);
+    complete_cycle(&mut world, &members);
+    assert_eq!(next_claim_window(&mut world), (5 * DAY, 6 * DAY));
+    claim_rewards(&mut world, MEMBER_2);
+    world.check_account(MEMBER_2).esdt_balance(REWARD_TOKEN, expected * 2u64);
+
+    set_day(&mut world, 6);
+    assert_eq!(next_claim_window(&mut world), (0, 0));
+
+    // Seul l'owner ou le DAO peut changer le calendrier
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_claim_schedule(ClaimSchedule::Always)
+        .with_result(ExpectError(4, "Owner ou DAO seulement"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_claim_schedule(ClaimSchedule::Weekdays { days_mask: 0 })
+        .with_result(ExpectError(4, "Masque de jours invalide"))
+        .run();
+
+    // Bornes: claimRewards calcule la fenetre sans boucle sur l'intervalle
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_claim_schedule(ClaimSchedule::EveryNDays { interval_days: 366, anchor_day: 0 })
+        .with_result(ExpectError(4, "Intervalle de jours invalide"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_claim_schedule(ClaimSchedule::AfterCycles { every_cycles: 1, window_seconds: u64::MAX })
+        .with_result(ExpectError(4, "Duree de fenetre invalide"))
+        .run();
+    set_claim_schedule(
+        &mut world,
+        ClaimSchedule::EveryNDays { interval_days: 365, anchor_day: 2 },
+    );
+    assert_eq!(next_claim_window(&mut world), (367 * DAY, 368 * DAY));
+}
+
+fn set_delegate(world: &mut ScenarioWorld, member: TestAddress, expires_epoch: u64, scope: DelegateScope) {
+    world
+        .tx()
+        .from(member)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_delegate(KEEPER_BOT, expires_epoch, scope)
+        .run();
+}
+
+fn delegated_members(world: &mut ScenarioWorld) -> Vec<Address> {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_delegated_members(KEEPER_BOT)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+#[test]
+fn delegated_signing_by_keeper_bot() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+    world.account(KEEPER_BOT).nonce(1);
+    world.current_block().block_epoch(5);
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .sign_and_forward(OptionalValue::Some(MEMBER_1.to_managed_address()))
+        .with_result(ExpectError(4, "Delegation invalide ou expiree"))
+        .run();
+
+    // Le membre 1 delegue la signature uniquement
+    set_delegate(&mut world, MEMBER_1, 10, DelegateScope::SignOnly);
+    assert_eq!(delegated_members(&mut world), vec![MEMBER_1.to_address()]);
+
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .sign_and_forward(OptionalValue::Some(MEMBER_1.to_managed_address()))
+        .run();
+    world.check_account(&peripherals[1]).balance(CIRCULATION_AMOUNT);
+
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_inactive(OptionalValue::Some(MEMBER_1.to_managed_address()))
+        .with_result(ExpectError(4, "Delegation invalide ou expiree"))
+        .run();
+
+    // Le SC peripherique applique la meme delegation (lue dans SC0)
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(&peripherals[1])
+        .typed(CirclePeripheralProxy)
+        .deactivate()
+        .with_result(ExpectError(4, "Owner ou delegue seulement"))
+        .run();
+
+    set_delegate(&mut world, MEMBER_2, 10, DelegateScope::SignAndStatus);
+
+    // Le SC peripherique ne transfere que sur appel de SC0: le delegue signe via SC0,
+    // qui enregistre le passage et fait avancer le cycle
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(&peripherals[1])
+        .typed(CirclePeripheralProxy)
+        .sign_and_forward(&peripherals[2], CIRCULATION_AMOUNT)
+        .with_result(ExpectError(4, "SC0 seulement"))
+        .run();
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .sign_and_forward(OptionalValue::Some(MEMBER_2.to_managed_address()))
+        .run();
+    world.check_account(&peripherals[1]).balance(0u64);
+    world.check_account(&peripherals[2]).balance(CIRCULATION_AMOUNT);
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_cycle_holder(MAIN_RING)
+        .returns(ExpectValue(OptionalValue::Some(peripherals[2].clone())))
+        .run();
+
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(&peripherals[1])
+        .typed(CirclePeripheralProxy)
+        .deactivate()
+        .run();
+
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_inactive(OptionalValue::Some(MEMBER_2.to_managed_address()))
+        .run();
+    assert_eq!(active_contracts(&mut world), vec![peripherals[0].clone(), peripherals[2].clone()]);
+
+    // Expiration: plus aucun droit a partir de l'epoch 10
+    world.current_block().block_epoch(10);
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_active(OptionalValue::Some(MEMBER_2.to_managed_address()))
+        .with_result(ExpectError(4, "Delegation invalide ou expiree"))
+        .run();
+    assert!(delegated_members(&mut world).is_empty());
+
+    // Revocation par le membre
+    let logs = world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .revoke_delegate()
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "delegate_revoked"));
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_delegation(MEMBER_1)
+        .returns(ExpectValue(OptionalValue::<MultiValue4<ManagedAddress<StaticApi>, u64, DelegateScope, bool>>::None))
+        .run();
+}
+
+fn keeper_stats(world: &mut ScenarioWorld) -> (u64, u64, RustBigUint, RustBigUint) {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_keeper_stats(KEEPER_BOT)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple()
+}
+
+#[test]
+fn keeper_tips_for_processing_and_failing_cycles() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    setup(&mut world, &members);
+    setup_rewards(&mut world);
+    world.account(KEEPER_BOT).nonce(1);
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_keeper_reward(KeeperTipSource::RewardToken, xcx(10), xcx(25))
+        .with_result(ExpectError(4, "Owner ou DAO seulement"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_keeper_reward(KeeperTipSource::RewardToken, xcx(10), xcx(25))
+        .run();
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+    for member in members {
+        pre_sign(&mut world, member);
+    }
+
+    // 3 transferts x 10 XCX, plafonne a 25 XCX pour le cycle
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(MAIN_RING)
+        .returns(ExpectValue(3u32))
+        .run();
+    world.check_account(KEEPER_BOT).esdt_balance(REWARD_TOKEN, xcx(25));
+
+    // Rien a faire: pas de pourboire
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(MAIN_RING)
+        .returns(ExpectValue(0u32))
+        .run();
+    assert_eq!(
+        keeper_stats(&mut world),
+        (3, 0, xcx(25).to_alloc(), RustBigUint::from(0u32))
+    );
+
+    // Pourboire EGLD pour le keeper qui debloque un cycle en timeout
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_keeper_reward(KeeperTipSource::Egld, 1_000u64, 5_000u64)
+        .run();
+
+    set_day(&mut world, 2);
+    start_cycle(&mut world, OWNER);
+    set_day(&mut world, 3);
+    fail_cycle(&mut world);
+    world.check_account(KEEPER_BOT).balance(0u64);
+
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .fail_cycle(MAIN_RING)
+        .with_result(ExpectError(4, "Pas de cycle en cours"))
+        .run();
+
+    set_day(&mut world, 4);
+    start_cycle(&mut world, OWNER);
+    set_day(&mut world, 5);
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .fail_cycle(MAIN_RING)
+        .run();
+    world.check_account(KEEPER_BOT).balance(1_000u64);
+    assert_eq!(
+        keeper_stats(&mut world),
+        (3, 1, xcx(25).to_alloc(), RustBigUint::from(1_000u32))
+    );
+}
+
+#[test]
+fn fail_cycle_grace_period_warning_and_partial_credit() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_failure_policy(3_600u64, 1u64, 10u64, 100u64, true)
+        .with_result(ExpectError(4, "Owner ou DAO seulement"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_failure_policy(2 * DAY, 1u64, 10u64, 100u64, true)
+        .with_result(ExpectError(4, "Delai de grace max 24h"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_failure_policy(3_600u64, 1u64, 10u64, 100u64, true)
+        .run();
+
+    // Les deux premiers SC signent, le troisieme bloque le cycle
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+    let order = rotation_order(&mut world);
+    assert_eq!(order, peripherals);
+    complete_cycle(&mut world, &[MEMBER_1, MEMBER_2]);
+
+    // Pas de timeout pendant le delai de grace
+    world.current_block().block_timestamp(2 * DAY + 3_599);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .fail_cycle(MAIN_RING)
+        .with_result(ExpectError(4, "Le cycle n'est pas encore en timeout"))
+        .run();
+
+    // 1ere infraction: simple avertissement, credit partiel pour les signataires
+    world.current_block().block_timestamp(2 * DAY + 3_600);
+    let logs = world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .fail_cycle(MAIN_RING)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "sc_warned"));
+    assert!(has_event(&logs, "partial_credit"));
+    assert!(!has_event(&logs, "sc_banned_progressive"));
+
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[0]), 1);
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[1]), 1);
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[2]), 0);
+
+    let info: (u64, u64, u64, u64, u64, u64, u64, u64, u64, bool) = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_infraction_info(&peripherals[2])
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple();
+    assert_eq!(info, (1, 2 * DAY + 3_600, 0, 0, 100, 3_600, 1, 10, 100, true));
+    assert_eq!(active_contracts(&mut world).len(), 3);
+
+    // 2eme infraction: ban de (2 - 1) x 10 jours
+    set_day(&mut world, 3);
+    start_cycle(&mut world, OWNER);
+    complete_cycle(&mut world, &[MEMBER_1, MEMBER_2]);
+    world.current_block().block_timestamp(4 * DAY + 3_600);
+    let logs = world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .fail_cycle(MAIN_RING)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "sc_banned_progressive"));
+
+    let (count, _, ban_until, remaining, _) = infraction_info(&mut world, &peripherals[2]);
+    assert_eq!(count, 2);
+    assert_eq!(ban_until, 14 * DAY + 3_600);
+    assert_eq!(remaining, 10);
+    assert_eq!(active_contracts(&mut world).len(), 2);
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[0]), 2);
+}
+
+/// Deploie le DAO V2 (vote sans quorum, periode de vote d'un jour, sans timelock,
+/// proposition a partir d'un XCX)
+fn setup_dao_v2(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(DAO_V2_CODE_PATH)
+        .argument(&REWARD_TOKEN)
+        .new_address(DAO_V2_ADDRESS)
+        .run();
+
+    for (endpoint, value) in [
+        ("setVotingPeriod", DAY),
+        ("setTimelockPeriod", 0),
+        ("setQuorumPercentage", 0),
+    ] {
+        world
+            .tx()
+            .from(OWNER)
+            .to(DAO_V2_ADDRESS)
+            .raw_call(endpoint)
+            .argument(&value)
+            .run();
+    }
+    world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("setMinProposalThreshold")
+        .argument(&xcx(1))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("setCircleOfLifeAddress")
+        .argument(&SC0_ADDRESS)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_dao_contract(DAO_V2_ADDRESS)
+        .run();
+}
+
+fn dao_vote(world: &mut ScenarioWorld, proposal_id: u64, support: bool) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("vote")
+        .argument(&proposal_id)
+        .argument(&support)
+        .payment(EsdtTokenPayment::new(REWARD_TOKEN.to_token_identifier(), 0, xcx(1)))
+        .run();
+}
+
+/// Proposition du DAO V2 appelant SC0 (`call_args` deja encodes), proposee et votee
+/// par OWNER le jour `day`, finalisee et executee le lendemain
+fn dao_v2_call_sc0<T: TopEncode>(
+    world: &mut ScenarioWorld,
+    day: u64,
+    proposal_type: u8,
+    amount: u64,
+    call_args: &[T],
+) -> Vec<Log> {
+    set_day(world, day);
+    let mut tx = world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("createProposal")
+        .argument(&ManagedBuffer::<StaticApi>::from("Gouvernance SC0"))
+        .argument(&ManagedBuffer::<StaticApi>::new())
+        .argument(&proposal_type)
+        .argument(&SC0_ADDRESS)
+        .argument(&BigUint::<StaticApi>::from(amount));
+    for arg in call_args {
+        tx = tx.argument(arg);
+    }
+    let proposal_id = tx
+        .payment(EsdtTokenPayment::new(REWARD_TOKEN.to_token_identifier(), 0, xcx(1)))
+        .original_result::<u64>()
+        .returns(ReturnsResult)
+        .run();
+    dao_vote(world, proposal_id, true);
+    set_day(world, day + 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("finalizeProposal")
+        .argument(&proposal_id)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("executeProposal")
+        .argument(&proposal_id)
+        .returns(ReturnsLogs)
+        .run()
+}
+
+fn appeal_ban(world: &mut ScenarioWorld, member: TestAddress, bond: u64) -> u64 {
+    world
+        .tx()
+        .from(member)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .appeal_ban(ManagedBuffer::from("panne reseau"))
+        .egld(bond)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+#[test]
+fn ban_appeal_through_dao_v2() {
+    let mut world = world();
+    let peripherals = setup(&mut world, &[MEMBER_1, MEMBER_2]);
+    setup_dao_v2(&mut world);
+    let bond = ONE_EGLD / 10;
+
+    // MEMBER_1 bloque seul deux cycles: 2 infractions, banni 60 jours
+    world
+        .tx()
+        .from(MEMBER_2)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_inactive(NOT_DELEGATED)
+        .run();
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+    set_day(&mut world, 2);
+    fail_cycle(&mut world);
+    set_day(&mut world, 32);
+    set_active(&mut world, MEMBER_1);
+    start_cycle(&mut world, OWNER);
+    set_day(&mut world, 33);
+    fail_cycle(&mut world);
+    assert_eq!(infraction_info(&mut world, &peripherals[0]).0, 2);
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .appeal_ban(ManagedBuffer::from("panne reseau"))
+        .egld(bond)
+        .with_result(ExpectError(4, "Appels desactives"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_appeal_bond(bond)
+        .run();
+    world
+        .tx()
+        .from(MEMBER_2)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .appeal_ban(ManagedBuffer::from("panne reseau"))
+        .egld(bond)
+        .with_result(ExpectError(4, "Votre SC n'est pas banni"))
+        .run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .appeal_ban(ManagedBuffer::from("panne reseau"))
+        .egld(bond / 2)
+        .with_result(ExpectError(4, "Caution incorrecte"))
+        .run();
+
+    // 1er appel rejete: la caution va a la tresorerie du DAO, le ban reste
+    let proposal_id = appeal_ban(&mut world, MEMBER_1, bond);
+    assert_eq!(proposal_id, 1);
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .appeal_ban(ManagedBuffer::from("panne reseau"))
+        .egld(bond)
+        .with_result(ExpectError(4, "Appel deja en cours"))
+        .run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .resolve_ban_appeal(&peripherals[0], true)
+        .with_result(ExpectError(4, "DAO seulement"))
+        .run();
+
+    dao_vote(&mut world, proposal_id, false);
+    set_day(&mut world, 35);
+    world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("finalizeProposal")
+        .argument(&proposal_id)
+        .run();
+
+    world.check_account(MEMBER_1).balance(4 * ONE_EGLD - bond);
+    world.check_account(DAO_V2_ADDRESS).balance(bond);
+    let (appeal, _) = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_appeal_info(&peripherals[0])
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple();
+    assert_eq!(appeal, 0);
+    let (count, _, ban_until, _, _) = infraction_info(&mut world, &peripherals[0]);
+    assert_eq!((count, ban_until), (2, (33 + 60) * DAY));
+
+    // 2eme appel accepte: ban leve, compteur remis a zero, caution rendue
+    let proposal_id = appeal_ban(&mut world, MEMBER_1, bond);
+    assert_eq!(proposal_id, 2);
+    dao_vote(&mut world, proposal_id, true);
+    set_day(&mut world, 37);
+    world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("finalizeProposal")
+        .argument(&proposal_id)
+        .run();
+    let logs = world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("executeProposal")
+        .argument(&proposal_id)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "ban_appeal_resolved"));
+
+    world.check_account(MEMBER_1).balance(4 * ONE_EGLD - bond);
+    world.check_account(DAO_V2_ADDRESS).balance(bond);
+    let (count, _, ban_until, remaining, _) = infraction_info(&mut world, &peripherals[0]);
+    assert_eq!((count, ban_until, remaining), (0, 0, 0));
+    set_active(&mut world, MEMBER_1);
+}
+
+#[test]
+fn ban_appeal_closes_when_sc0_rejects_resolution() {
+    let mut world = world();
+    let peripherals = setup(&mut world, &[MEMBER_1, MEMBER_2]);
+    setup_dao_v2(&mut world);
+    let bond = ONE_EGLD / 10;
+
+    world
+        .tx()
+        .from(MEMBER_2)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_inactive(NOT_DELEGATED)
+        .run();
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+    set_day(&mut world, 2);
+    fail_cycle(&mut world);
+    set_day(&mut world, 32);
+    set_active(&mut world, MEMBER_1);
+    start_cycle(&mut world, OWNER);
+    set_day(&mut world, 33);
+    fail_cycle(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_appeal_bond(bond)
+        .run();
+    let proposal_id = appeal_ban(&mut world, MEMBER_1, bond);
+    dao_vote(&mut world, proposal_id, false);
+
+    // SC0 ne reconnait plus le DAO: la proposition est tout de meme close
+    world.account(DAO_ADDRESS).nonce(1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_dao_contract(DAO_ADDRESS)
+        .run();
+    set_day(&mut world, 35);
+    let logs = world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("finalizeProposal")
+        .argument(&proposal_id)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "ban_appeal_closed"));
+    assert!(has_event(&logs, "ban_appeal_resolution_failed"));
+    world.check_account(DAO_V2_ADDRESS).balance(bond);
+
+    // L'appel reste ouvert sur SC0 jusqu'a sa cloture par l'owner ou le DAO
+    let (appeal, _) = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_appeal_info(&peripherals[0])
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple();
+    assert_eq!(appeal, proposal_id);
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .clear_ban_appeal(&peripherals[0])
+        .with_result(ExpectError(4, "Owner ou DAO seulement"))
+        .run();
+    let logs = world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .clear_ban_appeal(&peripherals[0])
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "ban_appeal_cleared"));
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .clear_ban_appeal(&peripherals[0])
+        .with_result(ExpectError(4, "Aucun appel en cours"))
+        .run();
+
+    // Cloture idempotente par le DAO
+    for _ in 0..2 {
+        world
+            .tx()
+            .from(DAO_ADDRESS)
+            .to(SC0_ADDRESS)
+            .typed(CircleOfLifeCenterProxy)
+            .resolve_ban_appeal(&peripherals[0], false)
+            .run();
+    }
+
+    // Le ban court toujours et le membre peut faire appel a nouveau
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_dao_contract(DAO_V2_ADDRESS)
+        .run();
+    let new_proposal_id = appeal_ban(&mut world, MEMBER_1, bond);
+    assert!(new_proposal_id > proposal_id);
+}
+
+fn join_ring(world: &mut ScenarioWorld, member: TestAddress, ring_id: u64) -> Address {
+    world
+        .tx()
+        .from(member)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .join_circle(OptionalValue::Some(ring_id))
+        .egld(ONE_EGLD)
+        .gas(JOIN_GAS_LIMIT)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+#[test]
+fn independent_rings_share_one_center() {
+    let mut world = world();
+    let main_peripherals = setup(&mut world, &[MEMBER_1]);
+    for member in [MEMBER_2, MEMBER_3, MEMBER_4, KEEPER_BOT] {
+        world.account(member).nonce(1).balance(5 * ONE_EGLD);
+    }
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .create_ring(b"Cercle du dimanche", ONE_EGLD, 2 * CIRCULATION_AMOUNT, 2u64)
+        .with_result(ExpectError(4, "Owner only"))
+        .run();
+    let ring_id = world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .create_ring(b"Cercle du dimanche", ONE_EGLD, 2 * CIRCULATION_AMOUNT, 2u64)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert_eq!(ring_id, 1);
+
+    world
+        .tx()
+        .from(MEMBER_2)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .join_circle(OptionalValue::Some(2u64))
+        .egld(ONE_EGLD)
+        .gas(JOIN_GAS_LIMIT)
+        .with_result(ExpectError(4, "Cercle inconnu"))
+        .run();
+
+    let ring_peripherals = [join_ring(&mut world, MEMBER_2, ring_id), join_ring(&mut world, MEMBER_3, ring_id)];
+    world
+        .tx()
+        .from(MEMBER_4)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .join_circle(OptionalValue::Some(ring_id))
+        .egld(ONE_EGLD)
+        .gas(JOIN_GAS_LIMIT)
+        .with_result(ExpectError(4, "Cercle complet"))
+        .run();
+
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_member_ring(MEMBER_3)
+        .returns(ExpectValue(OptionalValue::Some(ring_id)))
+        .run();
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_ring_count()
+        .returns(ExpectValue(2u64))
+        .run();
+
+    // Chaque cercle a sa propre rotation
+    let ring_order: Vec<Address> = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_rotation_order_view(OptionalValue::Some(ring_id))
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec();
+    assert_eq!(ring_order, ring_peripherals.to_vec());
+
+    // Les deux cycles demarrent le meme jour, chacun avec son montant circulant
+    set_day(&mut world, 1);
+    start_cycle(&mut world, OWNER);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .start_daily_cycle(OptionalValue::Some(ring_id))
+        .run();
+    world.check_account(&main_peripherals[0]).balance(CIRCULATION_AMOUNT);
+    world.check_account(&ring_peripherals[0]).balance(2 * CIRCULATION_AMOUNT);
+
+    pre_sign(&mut world, MEMBER_2);
+    pre_sign(&mut world, MEMBER_3);
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(OptionalValue::Some(ring_id))
+        .returns(ExpectValue(2u32))
+        .run();
+
+    // Le cercle secondaire est complete, le cercle principal attend toujours MEMBER_1
+    let (name, fee, circulation, max_members, active, completed) = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_ring_info(ring_id)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple();
+    assert_eq!(name, b"Cercle du dimanche".to_vec());
+    assert_eq!((fee, circulation), (ONE_EGLD.into(), (2 * CIRCULATION_AMOUNT).into()));
+    assert_eq!((max_members, active, completed), (2, 2, 1));
+    assert_eq!(cycles_completed(&mut world), 0);
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_cycle_holder(MAIN_RING)
+        .returns(ExpectValue(OptionalValue::Some(ManagedAddress::<StaticApi>::from(&main_peripherals[0]))))
+        .run();
+
+    // Le timeout d'un cercle n'affecte pas l'autre
+    set_day(&mut world, 2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .fail_cycle(OptionalValue::Some(ring_id))
+        .with_result(ExpectError(4, "Pas de cycle en cours"))
+        .run();
+    fail_cycle(&mut world);
+    for sc in ring_peripherals.iter() {
+        assert_eq!(sc_cycles_completed(&mut world, sc), 1);
+    }
+}
+
+#[test]
+fn stablecoin_ring_circulates_esdt() {
+    let mut world = world();
+    setup(&mut world, &[]);
+    let usdc = |amount: u64| EsdtTokenPayment::new(USDC_TOKEN.to_token_identifier(), 0, amount.into());
+    for member in [MEMBER_1, MEMBER_2] {
+        world.account(member).nonce(1).balance(ONE_EGLD).esdt_balance(USDC_TOKEN, 100u64);
+    }
+
+    let ring_id: u64 = world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .create_ring(b"Cercle USDC", 10u64, 5u64, 0u64)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_circulation_token(EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN), OptionalValue::Some(ring_id))
+        .run();
+
+    // Les frais d'entree se paient dans le token du cercle
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .join_circle(OptionalValue::Some(ring_id))
+        .egld(10u64)
+        .gas(JOIN_GAS_LIMIT)
+        .with_result(ExpectError(4, "Token de paiement invalide"))
+        .run();
+    let peripherals: Vec<Address> = [MEMBER_1, MEMBER_2]
+        .iter()
+        .map(|member| {
+            world
+                .tx()
+                .from(*member)
+                .to(SC0_ADDRESS)
+                .typed(CircleOfLifeCenterProxy)
+                .join_circle(OptionalValue::Some(ring_id))
+                .payment(usdc(10))
+                .gas(JOIN_GAS_LIMIT)
+                .returns(ReturnsResultUnmanaged)
+                .run()
+        })
+        .collect();
+    world.check_account(SC0_ADDRESS).esdt_balance(USDC_TOKEN, 20u64);
+
+    set_day(&mut world, 1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .start_daily_cycle(OptionalValue::Some(ring_id))
+        .run();
+    world.check_account(&peripherals[0]).esdt_balance(USDC_TOKEN, 5u64);
+    world
+        .query()
+        .to(&peripherals[0])
+        .typed(CirclePeripheralProxy)
+        .get_balance()
+        .returns(ExpectValue(5u64))
+        .run();
+
+    // Les frais ESDT sont retirables, jamais le montant en circulation
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .withdraw_token(EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN), 11u64, OWNER)
+        .with_result(ExpectError(4, "Solde insuffisant"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .withdraw_token(EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN), 10u64, OWNER)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_circulation_token(EgldOrEsdtTokenIdentifier::egld(), OptionalValue::Some(ring_id))
+        .with_result(ExpectError(4, "Impossible de changer le token pendant un cycle"))
+        .run();
+
+    pre_sign(&mut world, MEMBER_1);
+    pre_sign(&mut world, MEMBER_2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(OptionalValue::Some(ring_id))
+        .returns(ExpectValue(2u32))
+        .run();
+
+    for sc in peripherals.iter() {
+        world.check_account(sc).esdt_balance(USDC_TOKEN, 0u64);
+    }
+    world.check_account(SC0_ADDRESS).esdt_balance(USDC_TOKEN, 10u64);
+    world.check_account(OWNER).esdt_balance(USDC_TOKEN, 10u64);
+
+    // Depots dans le token du cercle, repartis selon la table de distribution:
+    // la part DAO est envoyee en USDC, Treasury et Liquidity restent dans SC0
+    world.account(DAO_ADDRESS).nonce(1);
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(CircleOfLifeCenterProxy).set_distribution_enabled(true).run();
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(CircleOfLifeCenterProxy).set_dao_contract(DAO_ADDRESS).run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .deposit()
+        .egld(ONE_EGLD / 2)
+        .with_result(ExpectError(4, "Token de paiement invalide"))
+        .run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .deposit()
+        .payment(usdc(50))
+        .run();
+    world.check_account(DAO_ADDRESS).esdt_balance(USDC_TOKEN, 16u64);
+    world.check_account(SC0_ADDRESS).esdt_balance(USDC_TOKEN, 44u64);
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_member_token_deposits(MEMBER_1, EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN))
+        .returns(ExpectValue(50u64))
+        .run();
+
+    // Les depots ESDT suivent le nouveau wallet lors d'un transfert d'adhesion
+    world.account(MEMBER_3).nonce(1);
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .propose_membership_transfer(MEMBER_3)
+        .run();
+    world
+        .tx()
+        .from(MEMBER_3)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .accept_membership_transfer(MEMBER_1)
+        .run();
+    for (member, deposits) in [(MEMBER_3, 50u64), (MEMBER_1, 0u64)] {
+        world
+            .query()
+            .to(SC0_ADDRESS)
+            .typed(CircleOfLifeCenterProxy)
+            .get_member_token_deposits(member, EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN))
+            .returns(ExpectValue(deposits))
+            .run();
+    }
+}
+
+#[test]
+fn ring_start_keeps_other_rings_circulation() {
+    let mut world = world();
+    setup(&mut world, &[MEMBER_1]);
+    world.account(MEMBER_2).nonce(1).balance(5 * ONE_EGLD);
+    let ring_id = world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .create_ring(b"Cercle du dimanche", ONE_EGLD, 2 * ONE_EGLD, 2u64)
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    join_ring(&mut world, MEMBER_2, ring_id);
+    world.check_account(SC0_ADDRESS).balance(2 * ONE_EGLD);
+
+    // Le solde couvre le cercle secondaire seulement en puisant dans la reserve du cercle principal
+    set_day(&mut world, 1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .start_daily_cycle(OptionalValue::Some(ring_id))
+        .with_result(ExpectError(4, "Solde SC0 insuffisant pour le montant circulant"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .fund_circulation()
+        .egld(CIRCULATION_AMOUNT)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .start_daily_cycle(OptionalValue::Some(ring_id))
+        .run();
+    world.check_account(SC0_ADDRESS).balance(CIRCULATION_AMOUNT);
+}
+
+fn peripheral_surplus(world: &mut ScenarioWorld, sc: &Address, token: EgldOrEsdtTokenIdentifier<StaticApi>) -> (RustBigUint, RustBigUint) {
+    world
+        .query()
+        .to(sc)
+        .typed(CirclePeripheralProxy)
+        .get_surplus(OptionalValue::Some(token))
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple()
+}
+
+#[test]
+fn peripheral_withdraws_only_its_surplus() {
+    let mut world = world();
+    let peripherals = setup(&mut world, &[MEMBER_1, MEMBER_2]);
+    world.set_esdt_balance(MEMBER_1, b"USDC-123456", 100u64);
+    let holder_sc = &peripherals[0];
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, MEMBER_1);
+
+    // Un envoi par erreur s'ajoute au montant en circulation du holder
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(holder_sc)
+        .typed(CirclePeripheralProxy)
+        .deposit()
+        .egld(ONE_EGLD)
+        .run();
+    assert_eq!(
+        peripheral_surplus(&mut world, holder_sc, EgldOrEsdtTokenIdentifier::egld()),
+        (RustBigUint::from(CIRCULATION_AMOUNT), RustBigUint::from(ONE_EGLD))
+    );
+
+    world
+        .tx()
+        .from(MEMBER_2)
+        .to(holder_sc)
+        .typed(CirclePeripheralProxy)
+        .withdraw_surplus(OptionalValue::<EgldOrEsdtTokenIdentifier<StaticApi>>::None)
+        .with_result(ExpectError(4, "Owner seulement"))
+        .run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(holder_sc)
+        .typed(CirclePeripheralProxy)
+        .withdraw_surplus(OptionalValue::<EgldOrEsdtTokenIdentifier<StaticApi>>::None)
+        .returns(ExpectValue(ONE_EGLD))
+        .run();
+    world.check_account(holder_sc).balance(CIRCULATION_AMOUNT);
+    world.check_account(MEMBER_1).balance(4 * ONE_EGLD);
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(holder_sc)
+        .typed(CirclePeripheralProxy)
+        .withdraw_surplus(OptionalValue::<EgldOrEsdtTokenIdentifier<StaticApi>>::None)
+        .with_result(ExpectError(4, "Aucun surplus a retirer"))
+        .run();
+
+    // Un ESDT qui n'est pas le token de circulation est entierement retirable
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(holder_sc)
+        .typed(CirclePeripheralProxy)
+        .deposit()
+        .payment(EsdtTokenPayment::new(USDC_TOKEN.to_token_identifier(), 0, 40u64.into()))
+        .run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(holder_sc)
+        .typed(CirclePeripheralProxy)
+        .withdraw_surplus(OptionalValue::Some(EgldOrEsdtTokenIdentifier::esdt(USDC_TOKEN)))
+        .returns(ExpectValue(40u64))
+        .run();
+    world.check_account(MEMBER_1).esdt_balance(USDC_TOKEN, 100u64);
+
+    // Une fois le cycle termine plus rien n'est reserve
+    pre_sign(&mut world, MEMBER_1);
+    pre_sign(&mut world, MEMBER_2);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(MAIN_RING)
+        .returns(ExpectValue(2u32))
+        .run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(holder_sc)
+        .typed(CirclePeripheralProxy)
+        .deposit()
+        .egld(ONE_EGLD)
+        .run();
+    assert_eq!(
+        peripheral_surplus(&mut world, holder_sc, EgldOrEsdtTokenIdentifier::egld()),
+        (RustBigUint::from(0u64), RustBigUint::from(ONE_EGLD))
+    );
+}
+
+#[test]
+fn holder_cannot_move_circulating_amount() {
+    let mut world = world();
+    let peripherals = setup(&mut world, &[MEMBER_1, MEMBER_2]);
+    let holder_sc = &peripherals[0];
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, MEMBER_1);
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(holder_sc)
+        .typed(CirclePeripheralProxy)
+        .deposit()
+        .egld(ONE_EGLD)
+        .run();
+
+    // L'owner du holder ne peut sortir que le surplus, ni vers lui ni vers un autre SC
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(holder_sc)
+        .typed(CirclePeripheralProxy)
+        .transfer(MEMBER_1, ONE_EGLD + 1, OptionalValue::<EgldOrEsdtTokenIdentifier<StaticApi>>::None)
+        .with_result(ExpectError(4, "Montant circulant verrouille pendant le cycle"))
+        .run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(holder_sc)
+        .typed(CirclePeripheralProxy)
+        .sign_and_forward(&peripherals[1], CIRCULATION_AMOUNT)
+        .with_result(ExpectError(4, "SC0 seulement"))
+        .run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(holder_sc)
+        .typed(CirclePeripheralProxy)
+        .transfer(MEMBER_1, ONE_EGLD, OptionalValue::<EgldOrEsdtTokenIdentifier<StaticApi>>::None)
+        .run();
+    world.check_account(holder_sc).balance(CIRCULATION_AMOUNT);
+
+    // Le montant circulant suit l'ordre fixe par SC0
+    pre_sign(&mut world, MEMBER_1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_next_transfer(MAIN_RING)
+        .run();
+    world.check_account(holder_sc).balance(0u64);
+    world.check_account(&peripherals[1]).balance(CIRCULATION_AMOUNT);
+    assert_eq!(
+        peripheral_surplus(&mut world, holder_sc, EgldOrEsdtTokenIdentifier::egld()),
+        (RustBigUint::from(0u64), RustBigUint::from(0u64))
+    );
+}
+
+fn outdated_peripherals(world: &mut ScenarioWorld) -> Vec<Address> {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_outdated_peripherals(1usize, 10usize)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_vec()
+}
+
+fn upgrade_peripherals(world: &mut ScenarioWorld, start: usize, count: usize) -> Vec<Log> {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .upgrade_peripherals(start, count)
+        .returns(ReturnsLogs)
+        .run()
+}
+
+#[test]
+fn upgrade_peripherals_from_new_template() {
+    let mut world = world();
+    let peripherals = setup(&mut world, &[MEMBER_1, MEMBER_2, MEMBER_3]);
+
+    // Les SC deployes depuis le template courant sont a jour
+    assert!(outdated_peripherals(&mut world).is_empty());
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_peripheral_code_version(&peripherals[0])
+        .returns(ExpectValue(MultiValue2::from((1u64, 1u64))))
+        .run();
+
+    // Nouveau template: tous les SC deviennent obsoletes
+    world
+        .tx()
+        .from(OWNER)
+        .typed(CirclePeripheralProxy)
+        .init(OWNER, SC0_ADDRESS, OptionalValue::<u64>::None)
+        .code(PERIPHERAL_CODE_PATH)
+        .new_address(TEMPLATE_V2_ADDRESS)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_peripheral_template(TEMPLATE_V2_ADDRESS)
+        .run();
+    assert_eq!(outdated_peripherals(&mut world), peripherals);
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .upgrade_peripherals(1usize, 2usize)
+        .with_result(ExpectError(4, "Owner only"))
+        .run();
+
+    // Un SC par transaction (upgrade asynchrone): le suivant obsolete de la plage
+    let logs = upgrade_peripherals(&mut world, 1, 2);
+    assert!(has_event(&logs, "peripheral_upgrade_started"));
+    assert_eq!(outdated_peripherals(&mut world), peripherals[1..].to_vec());
+    upgrade_peripherals(&mut world, 1, 2);
+    assert_eq!(outdated_peripherals(&mut world), vec![peripherals[2].clone()]);
+    // Plus de SC obsolete dans la plage: signale par un evenement
+    let logs = upgrade_peripherals(&mut world, 1, 2);
+    assert!(has_event(&logs, "peripherals_up_to_date"));
+    assert!(!has_event(&logs, "peripheral_upgrade_started"));
+    assert_eq!(outdated_peripherals(&mut world), vec![peripherals[2].clone()]);
+    upgrade_peripherals(&mut world, 3, 10);
+    assert!(outdated_peripherals(&mut world).is_empty());
+    assert!(has_event(&upgrade_peripherals(&mut world, 1, 10), "peripherals_up_to_date"));
+
+    // L'etat des SC est conserve apres l'upgrade
+    world
+        .query()
+        .to(&peripherals[1])
+        .typed(CirclePeripheralProxy)
+        .get_owner()
+        .returns(ExpectValue(MEMBER_2))
+        .run();
+    world
+        .query()
+        .to(&peripherals[1])
+        .typed(CirclePeripheralProxy)
+        .get_code_version()
+        .returns(ExpectValue(2u64))
+        .run();
+}
+
+fn exit_quote(world: &mut ScenarioWorld, member: TestAddress) -> (RustBigUint, RustBigUint, RustBigUint) {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_exit_quote(member)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple()
+}
+
+fn leave_circle(world: &mut ScenarioWorld, member: TestAddress) -> Vec<Log> {
+    world
+        .tx()
+        .from(member)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .leave_circle()
+        .returns(ReturnsLogs)
+        .run()
+}
+
+#[test]
+fn leave_circle_settles_and_rejoin_reuses_the_sc() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+    setup_rewards(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_exit_policy(true, 5_000u64, 10u64)
+        .run();
+
+    set_day(&mut world, 1);
+    start_cycle(&mut world, MEMBER_1);
+    world
+        .tx()
+        .from(MEMBER_3)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .leave_circle()
+        .with_result(ExpectError(4, "Impossible de quitter pendant un cycle"))
+        .run();
+    complete_cycle(&mut world, &members);
+
+    world
+        .tx()
+        .from(MEMBER_3)
+        .to(&peripherals[2])
+        .typed(CirclePeripheralProxy)
+        .deposit()
+        .egld(ONE_EGLD)
+        .run();
+
+    // 3 jours sur 10: 50% des frais d'entree x 7/10 = 0.35 EGLD
+    set_day(&mut world, 3);
+    let rewards = xcx(12_000) + xcx(12_000) * 314u64 / 10_000u64;
+    let refund = 350_000_000_000_000_000u64;
+    assert_eq!(
+        exit_quote(&mut world, MEMBER_3),
+        (rewards.to_alloc(), RustBigUint::from(refund), RustBigUint::from(ONE_EGLD))
+    );
+
+    let logs = leave_circle(&mut world, MEMBER_3);
+    assert!(has_event(&logs, "member_exit_settled"));
+    world.check_account(&peripherals[2]).balance(0u64);
+    world
+        .check_account(MEMBER_3)
+        .balance(4 * ONE_EGLD + refund)
+        .esdt_balance(REWARD_TOKEN, rewards.clone());
+    assert_eq!(active_contracts(&mut world), vec![peripherals[0].clone(), peripherals[1].clone()]);
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_retired_contract(MEMBER_3)
+        .returns(ExpectValue(OptionalValue::Some(ManagedAddress::<StaticApi>::from(&peripherals[2]))))
+        .run();
+
+    // Politique de confiscation: les recompenses retournent au pool
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_exit_policy(false, 0u64, 0u64)
+        .run();
+    let pool_before: RustBigUint = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_rewards_pool()
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    leave_circle(&mut world, MEMBER_2);
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_rewards_pool()
+        .returns(ExpectValue(BigUint::<StaticApi>::from(pool_before) + &rewards))
+        .run();
+    world.check_account(MEMBER_2).esdt_balance(REWARD_TOKEN, 0u64);
+
+    // Le retour reutilise le SC retire au lieu d'en deployer un nouveau
+    let (sc, logs) = world
+        .tx()
+        .from(MEMBER_3)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .join_circle(MAIN_RING)
+        .egld(ONE_EGLD)
+        .gas(JOIN_GAS_LIMIT)
+        .returns(ReturnsResultUnmanaged)
+        .returns(ReturnsLogs)
+        .run();
+    assert_eq!(sc, peripherals[2]);
+    assert!(has_event(&logs, "member_rejoined"));
+    assert!(!has_event(&logs, "contract_created"));
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .is_contract_retired(&peripherals[2])
+        .returns(ExpectValue(false))
+        .run();
+    assert_eq!(active_contracts(&mut world), vec![peripherals[0].clone(), peripherals[2].clone()]);
+}
+
+#[test]
+fn membership_transfer_to_new_wallet() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2];
+    let peripherals = setup(&mut world, &members);
+    setup_nft(&mut world);
+    world.account(MEMBER_4).nonce(1).balance(ONE_EGLD);
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .deposit()
+        .egld(2 * ONE_EGLD)
+        .run();
+    set_day(&mut world, 1);
+    start_cycle(&mut world, MEMBER_1);
+    complete_cycle(&mut world, &members);
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .propose_membership_transfer(MEMBER_2)
+        .with_result(ExpectError(4, "Le nouveau wallet est deja membre"))
+        .run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .propose_membership_transfer(MEMBER_4)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .accept_membership_transfer(MEMBER_1)
+        .with_result(ExpectError(4, "Aucun transfert propose a ce wallet"))
+        .run();
+
+    let logs = world
+        .tx()
+        .from(MEMBER_4)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .accept_membership_transfer(MEMBER_1)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "membership_transferred"));
+
+    // Le SC, les depots et l'historique suivent le nouveau wallet
+    world
+        .query()
+        .to(&peripherals[0])
+        .typed(CirclePeripheralProxy)
+        .get_owner()
+        .returns(ExpectValue(MEMBER_4))
+        .run();
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_member_egld_deposits(MEMBER_4)
+        .returns(ExpectValue(2 * ONE_EGLD))
+        .run();
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_member_egld_deposits(MEMBER_1)
+        .returns(ExpectValue(0u64))
+        .run();
+    world
+        .check_account(NFT_ADDRESS)
+        .check_storage(&nft_member_cycles_key(MEMBER_4), "1");
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[0]), 1);
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(&peripherals[0])
+        .typed(CirclePeripheralProxy)
+        .deactivate()
+        .with_result(ExpectError(4, "Owner ou delegue seulement"))
+        .run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .leave_circle()
+        .with_result(ExpectError(4, "Pas membre"))
+        .run();
+    set_active(&mut world, MEMBER_4);
+}
+
+fn skip_holder(world: &mut ScenarioWorld) -> Vec<Log> {
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .skip_holder(MAIN_RING)
+        .returns(ReturnsLogs)
+        .run()
+}
+
+#[test]
+fn skip_holder_after_hop_timeout() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+    setup_rewards(&mut world);
+    world.account(KEEPER_BOT).nonce(1).balance(ONE_EGLD);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_hop_timeout(3_600u64, 2u64)
+        .run();
+
+    // MEMBER_2 n'a pas pre-signe: la rotation s'arrete sur son SC
+    set_day(&mut world, 1);
+    start_cycle(&mut world, MEMBER_1);
+    pre_sign(&mut world, MEMBER_1);
+    pre_sign(&mut world, MEMBER_3);
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(MAIN_RING)
+        .returns(ExpectValue(1u32))
+        .run();
+    world.check_account(&peripherals[1]).balance(CIRCULATION_AMOUNT);
+
+    world.current_block().block_timestamp(DAY + 3_599);
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .skip_holder(MAIN_RING)
+        .with_result(ExpectError(4, "Le tour n'est pas encore en timeout"))
+        .run();
+
+    // Premier saut: infraction partielle seulement, le cycle continue et MEMBER_3
+    // (pre-signe) signe dans la foulee, ce qui complete le cycle
+    world.current_block().block_timestamp(DAY + 3_600);
+    let logs = skip_holder(&mut world);
+    assert!(has_event(&logs, "holder_skipped"));
+    world.check_account(&peripherals[1]).balance(0u64);
+    world.check_account(&peripherals[2]).balance(0u64);
+    assert_eq!(cycles_completed(&mut world), 1);
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_skipped_hops(&peripherals[1])
+        .returns(ExpectValue(1u64))
+        .run();
+    assert_eq!(infraction_info(&mut world, &peripherals[1]).0, 0);
+
+    // Le SC saute n'a ni recompense ni credit: 36 000 XCX partages entre les deux autres
+    let with_pioneer = |base: BigUint<StaticApi>| (base.clone() + base * 314u64 / 10_000u64).to_alloc();
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[1]), 0);
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[2]), 1);
+    assert_eq!(pending_rewards(&mut world, MEMBER_2), RustBigUint::from(0u64));
+    assert_eq!(pending_rewards(&mut world, MEMBER_3), with_pioneer(xcx(18_000)));
+
+    // Second saut: converti en infraction (ban, aucun avertissement par defaut)
+    set_day(&mut world, 2);
+    pre_sign(&mut world, MEMBER_1);
+    pre_sign(&mut world, MEMBER_3);
+    start_cycle(&mut world, MEMBER_1);
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_all_pending_transfers(MAIN_RING)
+        .returns(ExpectValue(1u32))
+        .run();
+    world.current_block().block_timestamp(2 * DAY + 3_600);
+    let logs = skip_holder(&mut world);
+    assert!(has_event(&logs, "sc_banned_progressive"));
+    let (infractions, _, ban_until, _, _) = infraction_info(&mut world, &peripherals[1]);
+    assert_eq!((infractions, ban_until), (1, 2 * DAY + 3_600 + 30 * DAY));
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_skipped_hops(&peripherals[1])
+        .returns(ExpectValue(0u64))
+        .run();
+
+    assert_eq!(cycles_completed(&mut world), 2);
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[1]), 0);
+    assert_eq!(sc_cycles_completed(&mut world, &peripherals[2]), 2);
+    assert_eq!(pending_rewards(&mut world, MEMBER_2), RustBigUint::from(0u64));
+    assert_eq!(pending_rewards(&mut world, MEMBER_3), with_pioneer(xcx(36_000)));
+
+    // Un holder qui a pre-signe n'est pas saute, meme si aucun keeper n'a traite sa signature
+    set_day(&mut world, 3);
+    start_cycle(&mut world, MEMBER_1);
+    pre_sign(&mut world, MEMBER_1);
+    world.current_block().block_timestamp(3 * DAY + 3_600);
+    let logs = skip_holder(&mut world);
+    assert!(!has_event(&logs, "holder_skipped"));
+    world.check_account(&peripherals[0]).balance(0u64);
+    world.check_account(&peripherals[2]).balance(CIRCULATION_AMOUNT);
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_skipped_hops(&peripherals[0])
+        .returns(ExpectValue(0u64))
+        .run();
+    assert_eq!(infraction_info(&mut world, &peripherals[0]).0, 0);
+}
+
+fn cycle_record(world: &mut ScenarioWorld, epoch: u64) -> Option<(CycleRecord<StaticApi>, Vec<CycleHop<StaticApi>>)> {
+    let record = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_cycle_record(epoch, MAIN_RING)
+        .returns(ReturnsResult)
+        .run();
+    record.into_option().map(|record| {
+        let (record, hops) = record.into_tuple();
+        (record, hops.iter().map(|hop| hop.clone()).collect())
+    })
+}
+
+#[test]
+fn cycle_records_with_hops_and_pruning() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2];
+    let peripherals = setup(&mut world, &members);
+    setup_rewards(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_cycle_history_retention(2u64)
+        .run();
+
+    // Epoch 1: cycle complete
+    set_day(&mut world, 1);
+    start_cycle(&mut world, MEMBER_2);
+    complete_cycle(&mut world, &members);
+
+    let (record, hops) = cycle_record(&mut world, 1).unwrap();
+    assert_eq!(record.status, CycleStatus::Completed);
+    assert_eq!(record.starter, MEMBER_2.to_managed_address());
+    assert_eq!((record.day, record.started_at, record.ended_at), (1, DAY, DAY));
+    assert_eq!(record.failed_sc, None);
+    assert_eq!(record.rewards_paid, xcx(36_000));
+    let hop_path: Vec<(Address, Address, bool)> = hops
+        .iter()
+        .map(|hop| (hop.from.to_address(), hop.to.to_address(), hop.skipped))
+        .collect();
+    assert_eq!(
+        hop_path,
+        vec![
+            (peripherals[0].clone(), peripherals[1].clone(), false),
+            (peripherals[1].clone(), SC0_ADDRESS.to_address(), false),
+        ]
+    );
+
+    // Epoch 2: cycle bloque par le premier SC
+    set_day(&mut world, 2);
+    start_cycle(&mut world, MEMBER_1);
+    set_day(&mut world, 3);
+    fail_cycle(&mut world);
+    let (record, hops) = cycle_record(&mut world, 2).unwrap();
+    assert_eq!(record.status, CycleStatus::Failed);
+    assert_eq!(record.failed_sc, Some(ManagedAddress::from(&peripherals[0])));
+    assert_eq!(record.ended_at, 3 * DAY);
+    assert!(hops.is_empty());
+
+    // Epoch 3: seuls les 2 derniers epochs sont conserves
+    start_cycle(&mut world, MEMBER_2);
+    assert!(cycle_record(&mut world, 1).is_none());
+    let history: Vec<u64> = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_cycle_history(0u64, 10u64, MAIN_RING)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|entry| entry.into_tuple().0)
+        .collect();
+    assert_eq!(history, vec![2, 3]);
+    assert_eq!(cycle_record(&mut world, 3).unwrap().0.status, CycleStatus::Running);
+}
+
+fn set_circulation_policy(world: &mut ScenarioWorld, policy: CirculationPolicy<StaticApi>) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_circulation_policy(policy, MAIN_RING)
+        .run();
+}
+
+fn next_circulation_amount(world: &mut ScenarioWorld) -> RustBigUint {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_next_circulation_amount(MAIN_RING)
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+fn tier(min_members: u64, amount: u64) -> CirculationTier<StaticApi> {
+    CirculationTier {
+        min_members,
+        amount: amount.into(),
+    }
+}
+
+#[test]
+fn dynamic_circulation_policy() {
+    let mut world = world();
+    let members = [MEMBER_1, MEMBER_2, MEMBER_3];
+    let peripherals = setup(&mut world, &members);
+    assert_eq!(next_circulation_amount(&mut world), RustBigUint::from(CIRCULATION_AMOUNT));
+
+    // 3 SC actifs x 0.001 EGLD, plafonne a 0.0025 EGLD
+    set_circulation_policy(
+        &mut world,
+        CirculationPolicy::PerMember {
+            amount_per_member: CIRCULATION_AMOUNT.into(),
+            cap: (CIRCULATION_AMOUNT * 5 / 2).into(),
+        },
+    );
+    assert_eq!(next_circulation_amount(&mut world), RustBigUint::from(CIRCULATION_AMOUNT * 5 / 2));
+
+    // 1% de la tresorerie (3 EGLD de frais d'entree), applique au demarrage du cycle
+    set_circulation_policy(
+        &mut world,
+        CirculationPolicy::TreasuryShare {
+            bps: 100,
+            cap: BigUint::zero(),
+        },
+    );
+    let treasury_share = 3 * ONE_EGLD / 100;
+    assert_eq!(next_circulation_amount(&mut world), RustBigUint::from(treasury_share));
+    set_day(&mut world, 1);
+    start_cycle(&mut world, MEMBER_1);
+    world.check_account(&peripherals[0]).balance(treasury_share);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_circulation_policy(CirculationPolicy::Fixed, MAIN_RING)
+        .with_result(ExpectError(4, "Impossible de changer la politique pendant un cycle"))
+        .run();
+    complete_cycle(&mut world, &members);
+    world.check_account(SC0_ADDRESS).balance(3 * ONE_EGLD);
+
+    // Paliers par nombre de SC actifs
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_circulation_policy(
+            CirculationPolicy::Tiers {
+                tiers: ManagedVec::from_iter([tier(3, 2 * CIRCULATION_AMOUNT), tier(1, CIRCULATION_AMOUNT)]),
+                cap: BigUint::zero(),
+            },
+            MAIN_RING,
+        )
+        .with_result(ExpectError(4, "Paliers non tries"))
+        .run();
+    set_circulation_policy(
+        &mut world,
+        CirculationPolicy::Tiers {
+            tiers: ManagedVec::from_iter([tier(1, CIRCULATION_AMOUNT), tier(3, 2 * CIRCULATION_AMOUNT)]),
+            cap: BigUint::zero(),
+        },
+    );
+    assert_eq!(next_circulation_amount(&mut world), RustBigUint::from(2 * CIRCULATION_AMOUNT));
+    world
+        .tx()
+        .from(MEMBER_3)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_inactive(NOT_DELEGATED)
+        .run();
+    assert_eq!(next_circulation_amount(&mut world), RustBigUint::from(CIRCULATION_AMOUNT));
+
+    // La politique peut aussi etre votee par le DAO V2 (montant = cercle vise)
+    setup_dao_v2(&mut world);
+    let policy = CirculationPolicy::<StaticApi>::PerMember {
+        amount_per_member: (3 * CIRCULATION_AMOUNT).into(),
+        cap: BigUint::zero(),
+    };
+    let logs = dao_v2_call_sc0(&mut world, 2, SET_CIRCULATION_POLICY, 0, &[policy]);
+    assert!(!has_event(&logs, "circle_of_life_call_failed"));
+    assert_eq!(next_circulation_amount(&mut world), RustBigUint::from(6 * CIRCULATION_AMOUNT));
+
+    // Une politique invalide est rejetee par SC0: la proposition est rouverte
+    let policy = CirculationPolicy::<StaticApi>::TreasuryShare {
+        bps: 0,
+        cap: BigUint::zero(),
+    };
+    let logs = dao_v2_call_sc0(&mut world, 4, SET_CIRCULATION_POLICY, 0, &[policy]);
+    assert!(has_event(&logs, "circle_of_life_call_failed"));
+    assert_eq!(next_circulation_amount(&mut world), RustBigUint::from(6 * CIRCULATION_AMOUNT));
+}
+
+fn share(target: DistributionTarget<StaticApi>, bps: u64) -> DistributionShare<StaticApi> {
+    DistributionShare { target, bps }
+}
+
+#[test]
+fn dao_governed_distribution_table() {
+    let mut world = world();
+    setup(&mut world, &[MEMBER_1]);
+
+    // Sans table, la vue expose la repartition historique
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_distribution_table()
+        .returns(ExpectValue(MultiValueVec::from(vec![
+            share(DistributionTarget::Treasury, 314),
+            share(DistributionTarget::Liquidity, 6780),
+            share(DistributionTarget::Dao, 2906),
+        ])))
+        .run();
+
+    world.account(MEMBER_2).nonce(1).balance(5 * ONE_EGLD);
+    world.account(MEMBER_4).nonce(1);
+    setup_dao_v2(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_distribution_enabled(true)
+        .run();
+
+    let table = vec![
+        share(DistributionTarget::Treasury, 3_000),
+        share(DistributionTarget::Dao, 2_000),
+        share(DistributionTarget::BurnSwap, 2_000),
+        share(DistributionTarget::Address(MEMBER_4.to_managed_address()), 3_000),
+    ];
+    // Seul le DAO peut changer la table, meme pas l'owner
+    for caller in [MEMBER_1, OWNER] {
+        world
+            .tx()
+            .from(caller)
+            .to(SC0_ADDRESS)
+            .typed(CircleOfLifeCenterProxy)
+            .set_distribution_table(MultiValueVec::from(table.clone()))
+            .with_result(ExpectError(4, "DAO seulement"))
+            .run();
+    }
+
+    // Une table invalide votee par le DAO est rejetee par SC0: la proposition est rouverte
+    let logs = dao_v2_call_sc0(
+        &mut world,
+        1,
+        SET_DISTRIBUTION_TABLE,
+        0,
+        &[
+            share(DistributionTarget::Treasury, u64::MAX - 100),
+            share(DistributionTarget::Dao, 10_101),
+        ],
+    );
+    assert!(has_event(&logs, "circle_of_life_call_failed"));
+    let logs = dao_v2_call_sc0(
+        &mut world,
+        3,
+        SET_DISTRIBUTION_TABLE,
+        0,
+        &[share(DistributionTarget::Treasury, 5_000), share(DistributionTarget::Dao, 4_000)],
+    );
+    assert!(has_event(&logs, "circle_of_life_call_failed"));
+    let logs = dao_v2_call_sc0(
+        &mut world,
+        5,
+        SET_DISTRIBUTION_TABLE,
+        0,
+        &[share(DistributionTarget::Dao, 5_000), share(DistributionTarget::Dao, 5_000)],
+    );
+    assert!(has_event(&logs, "circle_of_life_call_failed"));
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_distribution_table_history_count()
+        .returns(ExpectValue(0usize))
+        .run();
+
+    let logs = dao_v2_call_sc0(&mut world, 7, SET_DISTRIBUTION_TABLE, 0, &table);
+    assert!(!has_event(&logs, "circle_of_life_call_failed"));
+
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_distribution_table()
+        .returns(ExpectValue(MultiValueVec::from(table.clone())))
+        .run();
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_distribution_table_history_count()
+        .returns(ExpectValue(1usize))
+        .run();
+
+    // Les frais d'entree du nouveau membre suivent la table
+    world
+        .tx()
+        .from(MEMBER_2)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .join_circle(MAIN_RING)
+        .egld(ONE_EGLD)
+        .gas(JOIN_GAS_LIMIT)
+        .run();
+
+    world.check_account(DAO_V2_ADDRESS).balance(ONE_EGLD / 5);
+    world.check_account(MEMBER_4).balance(3 * ONE_EGLD / 10);
+    world.check_account(SC0_ADDRESS).balance(ONE_EGLD + ONE_EGLD / 2);
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_pending_burn_swap_egld()
+        .returns(ExpectValue(ONE_EGLD / 5))
+        .run();
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_total_distributed_dao()
+        .returns(ExpectValue(ONE_EGLD / 5))
+        .run();
+}
+
+fn liquidity_stage(world: &mut ScenarioWorld) -> (LiquidityStage, u64) {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_liquidity_stage()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple()
+}
+
+#[test]
+fn liquidity_pipeline_stages() {
+    let mut world = world();
+    setup(&mut world, &[MEMBER_1]);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Idle, 0));
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .advance_liquidity()
+        .with_result(ExpectError(4, "Pas de liquidite en attente"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .liquidity_step2_swap()
+        .with_result(ExpectError(4, "Etape de liquidite invalide"))
+        .run();
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .advance_liquidity()
+        .with_result(ExpectError(4, "Owner only"))
+        .run();
+
+    // Reprise manuelle: seules les etapes hors attente de callback sont acceptees
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_liquidity_stage(LiquidityStage::Swapping)
+        .with_result(ExpectError(4, "Etape d'attente de callback"))
+        .run();
+    let logs = world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_liquidity_stage(LiquidityStage::Wrapped)
+        .returns(ReturnsLogs)
+        .run();
+    assert!(has_event(&logs, "liquidity_stage_changed"));
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Wrapped, 0));
+
+    // advanceLiquidity execute l'etape suivante: le swap
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .advance_liquidity()
+        .with_result(ExpectError(4, "WEGLD token ID non configure"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .liquidity_step1_wrap_egld()
+        .with_result(ExpectError(4, "Etape de liquidite invalide"))
+        .run();
+}
+
+#[test]
+fn price_guard_policy_configuration() {
+    let mut world = world();
+    setup(&mut world, &[MEMBER_1]);
+
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_price_guard_policy_view()
+        .returns(ExpectValue(PriceGuardPolicy::<StaticApi> {
+            max_deviation_bps: 500,
+            twap_window_seconds: 3_600,
+            oracle: None,
+        }))
+        .run();
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_price_guard_policy(300u64, 1_800u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Owner ou DAO seulement"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_price_guard_policy(6_000u64, 1_800u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Ecart de prix max 50%"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_price_guard_policy(300u64, 0u64, OptionalValue::<ManagedAddress<StaticApi>>::None)
+        .with_result(ExpectError(4, "Fenetre TWAP invalide"))
+        .run();
+
+    world.account(DAO_ADDRESS).nonce(1);
+    world.account(KEEPER_BOT).nonce(1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_dao_contract(DAO_ADDRESS)
+        .run();
+    world
+        .tx()
+        .from(DAO_ADDRESS)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .set_price_guard_policy(300u64, 1_800u64, OptionalValue::Some(KEEPER_BOT.to_managed_address()))
+        .run();
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_price_guard_policy_view()
+        .returns(ExpectValue(PriceGuardPolicy::<StaticApi> {
+            max_deviation_bps: 300,
+            twap_window_seconds: 1_800,
+            oracle: Some(KEEPER_BOT.to_managed_address()),
+        }))
+        .run();
+
+    world
+        .tx()
+        .from(KEEPER_BOT)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .update_price_reference()
+        .with_result(ExpectError(4, "xExchange non configure"))
+        .run();
+}
@@ -0,0 +1,850 @@
+use multiversx_sc_scenario::imports::*;
+use multiversx_sc_scenario::scenario_model::Log;
+
+use circle_of_life_center::circle_of_life_center_proxy::{
+    CircleOfLifeCenterProxy, DistributionShare, DistributionTarget, LiquidityStage, ProtocolLock,
+    ProtocolLockStatus,
+};
+use circle_peripheral::circle_peripheral_proxy::CirclePeripheralProxy;
+use mock_lp_locker::mock_lp_locker_proxy::{LockInfo, MockLpLockerProxy};
+use mock_wegld_swap::mock_wegld_swap_proxy::MockWegldSwapProxy;
+use mock_xexchange_pair::mock_xexchange_pair_proxy::MockXExchangePairProxy;
+
+const OWNER: TestAddress = TestAddress::new("owner");
+const MEMBER_1: TestAddress = TestAddress::new("member-1");
+const WHALE: TestAddress = TestAddress::new("whale");
+const VOTER: TestAddress = TestAddress::new("voter");
+const DAO_ADDRESS: TestAddress = TestAddress::new("dao");
+const SC0_ADDRESS: TestSCAddress = TestSCAddress::new("circle-of-life-center");
+const TEMPLATE_ADDRESS: TestSCAddress = TestSCAddress::new("peripheral-template");
+const WEGLD_SWAP_ADDRESS: TestSCAddress = TestSCAddress::new("wegld-swap");
+const PAIR_ADDRESS: TestSCAddress = TestSCAddress::new("xexchange-pair");
+const LOCKER_ADDRESS: TestSCAddress = TestSCAddress::new("lp-locker");
+const DAO_V2_ADDRESS: TestSCAddress = TestSCAddress::new("xcirclex-dao-v2");
+const SC0_CODE_PATH: MxscPath = MxscPath::new("output/circle-of-life-center.mxsc.json");
+const PERIPHERAL_CODE_PATH: MxscPath =
+    MxscPath::new("../circle-peripheral/output/circle-peripheral.mxsc.json");
+const WEGLD_SWAP_CODE_PATH: MxscPath = MxscPath::new("../mock-wegld-swap/output/mock-wegld-swap.mxsc.json");
+const PAIR_CODE_PATH: MxscPath = MxscPath::new("../mock-xexchange-pair/output/mock-xexchange-pair.mxsc.json");
+const LOCKER_CODE_PATH: MxscPath = MxscPath::new("../mock-lp-locker/output/mock-lp-locker.mxsc.json");
+const DAO_V2_CODE_PATH: MxscPath = MxscPath::new("../xcirclex-dao-v2/output/xcirclex-dao-v2.mxsc.json");
+const XCX_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("XCX-123456");
+const WEGLD_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("WEGLD-123456");
+const LP_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("XCXWEGLD-123456");
+
+const ONE_EGLD: u64 = 1_000_000_000_000_000_000;
+/// Liquidite accumulee par les frais d'entree d'un membre: 70% de 96.86% de 1 EGLD
+const PENDING_LIQUIDITY: u64 = 678_020_000_000_000_000;
+/// Reserves initiales de la paire: 1000 WEGLD / 1 000 000 XCX
+const POOL_WEGLD: u64 = 1_000;
+const POOL_XCX: u64 = 1_000_000;
+const HOUR: u64 = 3_600;
+const DAY: u64 = 24 * HOUR;
+const LOCK_DURATION_SECONDS: u64 = 365 * 24 * HOUR;
+
+const ADVANCE_GAS_LIMIT: u64 = 300_000_000;
+const JOIN_GAS_LIMIT: u64 = 100_000_000;
+const MAIN_RING: OptionalValue<u64> = OptionalValue::None;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.register_contract(SC0_CODE_PATH, circle_of_life_center::ContractBuilder);
+    blockchain.register_contract(PERIPHERAL_CODE_PATH, circle_peripheral::ContractBuilder);
+    blockchain.register_contract(WEGLD_SWAP_CODE_PATH, mock_wegld_swap::ContractBuilder);
+    blockchain.register_contract(PAIR_CODE_PATH, mock_xexchange_pair::ContractBuilder);
+    blockchain.register_contract(LOCKER_CODE_PATH, mock_lp_locker::ContractBuilder);
+    blockchain.register_contract(DAO_V2_CODE_PATH, xcirclex_dao_v2::ContractBuilder);
+    blockchain
+}
+
+fn tokens(amount: u64) -> BigUint<StaticApi> {
+    BigUint::from(amount) * BigUint::from(ONE_EGLD)
+}
+
+fn has_event(logs: &[Log], identifier: &str) -> bool {
+    logs.iter()
+        .any(|log| log.topics.first().map(|t| t.as_slice()) == Some(identifier.as_bytes()))
+}
+
+/// Deploie SC0 avec la distribution activee, les mocks WEGLD / paire / LP locker
+/// (paire amorcee a 1000 XCX par WEGLD), puis fait rejoindre un membre:
+/// ses frais d'entree alimentent la liquidite en attente
+fn setup(world: &mut ScenarioWorld) {
+    world.account(OWNER).nonce(1).balance(tokens(POOL_WEGLD + 10)).esdt_balance(XCX_TOKEN, tokens(POOL_XCX));
+    world.account(MEMBER_1).nonce(1).balance(5 * ONE_EGLD);
+    world.account(WHALE).nonce(1).esdt_balance(WEGLD_TOKEN, tokens(200));
+
+    world
+        .tx()
+        .from(OWNER)
+        .typed(CircleOfLifeCenterProxy)
+        .init()
+        .code(SC0_CODE_PATH)
+        .code_metadata(CodeMetadata::UPGRADEABLE | CodeMetadata::PAYABLE_BY_SC)
+        .new_address(SC0_ADDRESS)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .typed(CirclePeripheralProxy)
+        .init(OWNER, SC0_ADDRESS, OptionalValue::<u64>::None)
+        .code(PERIPHERAL_CODE_PATH)
+        .new_address(TEMPLATE_ADDRESS)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .typed(MockWegldSwapProxy)
+        .init(WEGLD_TOKEN)
+        .code(WEGLD_SWAP_CODE_PATH)
+        .new_address(WEGLD_SWAP_ADDRESS)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .typed(MockXExchangePairProxy)
+        .init(XCX_TOKEN, WEGLD_TOKEN, LP_TOKEN)
+        .code(PAIR_CODE_PATH)
+        .new_address(PAIR_ADDRESS)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .typed(MockLpLockerProxy)
+        .init()
+        .code(LOCKER_CODE_PATH)
+        .new_address(LOCKER_ADDRESS)
+        .run();
+    world.set_esdt_local_roles(WEGLD_SWAP_ADDRESS, WEGLD_TOKEN.as_bytes(), &[EsdtLocalRole::Mint, EsdtLocalRole::Burn]);
+    world.set_esdt_local_roles(PAIR_ADDRESS, LP_TOKEN.as_bytes(), &[EsdtLocalRole::Mint, EsdtLocalRole::Burn]);
+
+    // Amorcage de la paire par l'owner
+    world
+        .tx()
+        .from(OWNER)
+        .to(WEGLD_SWAP_ADDRESS)
+        .typed(MockWegldSwapProxy)
+        .wrap_egld()
+        .egld(tokens(POOL_WEGLD))
+        .run();
+    let mut payments = ManagedVec::new();
+    payments.push(EsdtTokenPayment::new(XCX_TOKEN.to_token_identifier(), 0, tokens(POOL_XCX)));
+    payments.push(EsdtTokenPayment::new(WEGLD_TOKEN.to_token_identifier(), 0, tokens(POOL_WEGLD)));
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR_ADDRESS)
+        .typed(MockXExchangePairProxy)
+        .add_liquidity(BigUint::from(1u64), BigUint::from(1u64))
+        .payment(payments)
+        .run();
+
+    let sc0 = || CircleOfLifeCenterProxy;
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(sc0()).set_peripheral_template(TEMPLATE_ADDRESS).run();
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(sc0()).set_distribution_enabled(true).run();
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(sc0()).set_wegld_contract(WEGLD_SWAP_ADDRESS, WEGLD_TOKEN).run();
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(sc0()).set_xexchange_pair(PAIR_ADDRESS).run();
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(sc0()).set_lp_locker(LOCKER_ADDRESS).run();
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(sc0()).set_lp_token_id(LP_TOKEN).run();
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(sc0()).set_xcirclex_token_id(XCX_TOKEN).run();
+
+    world
+        .tx()
+        .from(MEMBER_1)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .join_circle(MAIN_RING)
+        .egld(ONE_EGLD)
+        .gas(JOIN_GAS_LIMIT)
+        .run();
+}
+
+fn advance_liquidity(world: &mut ScenarioWorld) -> Vec<Log> {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .advance_liquidity()
+        .gas(ADVANCE_GAS_LIMIT)
+        .returns(ReturnsLogs)
+        .run()
+}
+
+fn liquidity_stage(world: &mut ScenarioWorld) -> (LiquidityStage, u64) {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_liquidity_stage()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple()
+}
+
+fn pending_liquidity(world: &mut ScenarioWorld) -> RustBigUint {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_pending_liquidity_egld()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+}
+
+fn protocol_lock(world: &mut ScenarioWorld, lock_id: u64) -> ProtocolLock<StaticApi> {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_protocol_lock_view(lock_id)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn pending_protocol_withdrawal(world: &mut ScenarioWorld) -> (RustBigUint, RustBigUint, bool) {
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_pending_protocol_withdrawal()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple()
+}
+
+fn set_paused(world: &mut ScenarioWorld, contract: TestSCAddress, paused: bool) {
+    let tx = world.tx().from(OWNER).to(contract);
+    if contract == WEGLD_SWAP_ADDRESS {
+        let proxy = tx.typed(MockWegldSwapProxy);
+        if paused { proxy.pause().run() } else { proxy.resume().run() }
+    } else if contract == PAIR_ADDRESS {
+        let proxy = tx.typed(MockXExchangePairProxy);
+        if paused { proxy.pause().run() } else { proxy.resume().run() }
+    } else {
+        let proxy = tx.typed(MockLpLockerProxy);
+        if paused { proxy.pause().run() } else { proxy.resume().run() }
+    }
+}
+
+#[test]
+fn liquidity_pipeline_accumulate_to_lock() {
+    let mut world = world();
+    setup(&mut world);
+    assert_eq!(pending_liquidity(&mut world), RustBigUint::from(PENDING_LIQUIDITY));
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Idle, 0));
+
+    // withdrawToken ne touche pas aux EGLD en attente de liquidite
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .withdraw_token(EgldOrEsdtTokenIdentifier::egld(), ONE_EGLD - PENDING_LIQUIDITY + 1, OWNER)
+        .with_result(ExpectError(4, "Solde insuffisant"))
+        .run();
+
+    // 1. Wrap: les EGLD en attente deviennent des WEGLD
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Wrapped, 0));
+    assert_eq!(pending_liquidity(&mut world), RustBigUint::from(0u32));
+    world.check_account(SC0_ADDRESS).esdt_balance(WEGLD_TOKEN, PENDING_LIQUIDITY);
+
+    // 2. Swap de la moitie en XCX, sous le controle de prix
+    let logs = advance_liquidity(&mut world);
+    assert!(has_event(&logs, "price_reference_updated"));
+    assert!(!has_event(&logs, "swap_blocked"));
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Swapped, 0));
+    world.check_account(SC0_ADDRESS).esdt_balance(WEGLD_TOKEN, PENDING_LIQUIDITY / 2);
+
+    // 3. addLiquidity: SC0 recoit les LP tokens
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::LiquidityAdded, 0));
+    let (_, _, lp_supply): (RustBigUint, RustBigUint, RustBigUint) = world
+        .query()
+        .to(PAIR_ADDRESS)
+        .typed(MockXExchangePairProxy)
+        .get_reserves_and_total_supply()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple();
+    assert!(lp_supply > RustBigUint::from(POOL_WEGLD) * RustBigUint::from(ONE_EGLD));
+
+    // 4. Lock des LP tokens: le pipeline revient a Idle
+    let logs = advance_liquidity(&mut world);
+    assert!(has_event(&logs, "liquidity_processing_completed"));
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Idle, 0));
+    world.check_account(SC0_ADDRESS).esdt_balance(LP_TOKEN, 0u64);
+    let lock: LockInfo<StaticApi> = world
+        .query()
+        .to(LOCKER_ADDRESS)
+        .typed(MockLpLockerProxy)
+        .locks(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(lock.owner, SC0_ADDRESS.to_managed_address());
+    assert_eq!(lock.token_id, LP_TOKEN.to_token_identifier());
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .advance_liquidity()
+        .with_result(ExpectError(4, "Pas de liquidite en attente"))
+        .run();
+}
+
+#[test]
+fn cycle_start_keeps_pending_liquidity() {
+    let mut world = world();
+    setup(&mut world);
+    world.current_block().block_timestamp(24 * HOUR);
+
+    // Le montant circulant ne peut pas puiser dans les EGLD en attente de liquidite
+    let sc0 = || CircleOfLifeCenterProxy;
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(sc0())
+        .set_circulation_amount(ONE_EGLD - PENDING_LIQUIDITY + 1, MAIN_RING)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(sc0())
+        .start_daily_cycle(MAIN_RING)
+        .with_result(ExpectError(4, "Solde SC0 insuffisant pour le montant circulant"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(sc0())
+        .set_circulation_amount(ONE_EGLD - PENDING_LIQUIDITY, MAIN_RING)
+        .run();
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(sc0()).start_daily_cycle(MAIN_RING).run();
+    assert_eq!(pending_liquidity(&mut world), RustBigUint::from(PENDING_LIQUIDITY));
+}
+
+#[test]
+fn liquidity_pipeline_retries_failed_callbacks() {
+    let mut world = world();
+    setup(&mut world);
+
+    // Wrap en echec: les EGLD reviennent en attente
+    set_paused(&mut world, WEGLD_SWAP_ADDRESS, true);
+    let logs = advance_liquidity(&mut world);
+    assert!(has_event(&logs, "liquidity_processing_error"));
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Idle, 1));
+    assert_eq!(pending_liquidity(&mut world), RustBigUint::from(PENDING_LIQUIDITY));
+    set_paused(&mut world, WEGLD_SWAP_ADDRESS, false);
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Wrapped, 0));
+
+    // Swap en echec: les WEGLD restent dans SC0, le swap est relance
+    set_paused(&mut world, PAIR_ADDRESS, true);
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Wrapped, 1));
+    world.check_account(SC0_ADDRESS).esdt_balance(WEGLD_TOKEN, PENDING_LIQUIDITY);
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Wrapped, 2));
+    set_paused(&mut world, PAIR_ADDRESS, false);
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Swapped, 0));
+
+    // addLiquidity en echec
+    set_paused(&mut world, PAIR_ADDRESS, true);
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Swapped, 1));
+    set_paused(&mut world, PAIR_ADDRESS, false);
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::LiquidityAdded, 0));
+
+    // Lock en echec: les LP tokens restent dans SC0
+    set_paused(&mut world, LOCKER_ADDRESS, true);
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::LiquidityAdded, 1));
+    set_paused(&mut world, LOCKER_ADDRESS, false);
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Idle, 0));
+    world.check_account(SC0_ADDRESS).esdt_balance(LP_TOKEN, 0u64);
+}
+
+#[test]
+fn lock_without_id_is_not_recorded() {
+    let mut world = world();
+    setup(&mut world);
+    advance_liquidity(&mut world);
+    advance_liquidity(&mut world);
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::LiquidityAdded, 0));
+
+    // Le locker garde les LP tokens sans retourner d'ID: rien n'est enregistre sous l'ID 0
+    world.tx().from(OWNER).to(LOCKER_ADDRESS).typed(MockLpLockerProxy).set_omit_lock_id(true).run();
+    let logs = advance_liquidity(&mut world);
+    assert!(has_event(&logs, "liquidity_processing_error"));
+    assert!(!has_event(&logs, "protocol_liquidity_locked"));
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::LiquidityAdded, 1));
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_protocol_lock_view(0u64)
+        .with_result(ExpectError(4, "Lock protocolaire inconnu"))
+        .run();
+}
+
+#[test]
+fn liquidity_swap_blocked_on_price_manipulation() {
+    let mut world = world();
+    setup(&mut world);
+    advance_liquidity(&mut world);
+
+    // Prix de reference observe avant la manipulation
+    world
+        .tx()
+        .from(WHALE)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .update_price_reference()
+        .run();
+
+    // Un gros swap WEGLD -> XCX dans le meme bloc fait chuter le prix de ~30%
+    world
+        .tx()
+        .from(WHALE)
+        .to(PAIR_ADDRESS)
+        .typed(MockXExchangePairProxy)
+        .swap_tokens_fixed_input(XCX_TOKEN, BigUint::from(1u64))
+        .payment(EsdtTokenPayment::new(WEGLD_TOKEN.to_token_identifier(), 0, tokens(200)))
+        .run();
+
+    let logs = advance_liquidity(&mut world);
+    assert!(has_event(&logs, "swap_blocked"));
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Wrapped, 0));
+    world.check_account(SC0_ADDRESS).esdt_balance(WEGLD_TOKEN, PENDING_LIQUIDITY);
+
+    // Une observation ne remplace jamais la moyenne: toujours bloque une fenetre plus tard
+    world.current_block().block_timestamp(HOUR);
+    let logs = advance_liquidity(&mut world);
+    assert!(has_event(&logs, "swap_blocked"));
+
+    // Le nouveau prix tenu dans la duree est suivi progressivement
+    let mut hours = 1;
+    loop {
+        hours += 1;
+        assert!(hours <= 24, "la reference doit suivre un prix durable");
+        world.current_block().block_timestamp(hours * HOUR);
+        let logs = advance_liquidity(&mut world);
+        if !has_event(&logs, "swap_blocked") {
+            break;
+        }
+    }
+    assert!(hours > 10);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Swapped, 0));
+}
+
+fn twap_price(world: &mut ScenarioWorld) -> RustBigUint {
+    let (twap, _): (RustBigUint, u64) = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_twap_price()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple();
+    twap
+}
+
+#[test]
+fn planted_price_observation_moves_twap_by_bounded_step() {
+    let mut world = world();
+    setup(&mut world);
+    let update_reference = |world: &mut ScenarioWorld| {
+        world
+            .tx()
+            .from(WHALE)
+            .to(SC0_ADDRESS)
+            .typed(CircleOfLifeCenterProxy)
+            .update_price_reference()
+            .run();
+    };
+    update_reference(&mut world);
+    let reference = twap_price(&mut world);
+
+    // Prix manipule puis observe dans le meme bloc
+    world
+        .tx()
+        .from(WHALE)
+        .to(PAIR_ADDRESS)
+        .typed(MockXExchangePairProxy)
+        .swap_tokens_fixed_input(XCX_TOKEN, BigUint::from(1u64))
+        .payment(EsdtTokenPayment::new(WEGLD_TOKEN.to_token_identifier(), 0, tokens(200)))
+        .run();
+    update_reference(&mut world);
+    assert_eq!(twap_price(&mut world), reference);
+
+    // Une fenetre plus tard, l'observation plantee (-30%) ne deplace la moyenne que de
+    // la moitie de l'ecart maximal (5%)
+    world.current_block().block_timestamp(10 * HOUR);
+    update_reference(&mut world);
+    let twap = twap_price(&mut world);
+    let floor = &reference * 9_750u64 / 10_000u64;
+    assert!(twap >= floor && twap < reference);
+}
+
+fn process_burn_swap(world: &mut ScenarioWorld) -> Vec<Log> {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_burn_swap(BigUint::from(1u64))
+        .gas(ADVANCE_GAS_LIMIT)
+        .returns(ReturnsLogs)
+        .run()
+}
+
+fn pending_burn_swap(world: &mut ScenarioWorld) -> (RustBigUint, RustBigUint) {
+    let egld = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_pending_burn_swap_egld()
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    let wegld = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_pending_burn_swap_wegld()
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    (egld, wegld)
+}
+
+#[test]
+fn burn_swap_wraps_then_swaps_and_burns() {
+    let mut world = world();
+    setup(&mut world);
+    world.set_esdt_local_roles(SC0_ADDRESS, XCX_TOKEN.as_bytes(), &[EsdtLocalRole::Burn]);
+
+    // 90% des depots vont au rachat + burn
+    let mut table = MultiValueVec::new();
+    table.push(DistributionShare { target: DistributionTarget::Treasury, bps: 1_000 });
+    table.push(DistributionShare { target: DistributionTarget::BurnSwap, bps: 9_000 });
+    world.account(DAO_ADDRESS).nonce(1);
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(CircleOfLifeCenterProxy).set_dao_contract(DAO_ADDRESS).run();
+    world.tx().from(DAO_ADDRESS).to(SC0_ADDRESS).typed(CircleOfLifeCenterProxy).set_distribution_table(table).run();
+    world.tx().from(MEMBER_1).to(SC0_ADDRESS).typed(CircleOfLifeCenterProxy).deposit().egld(ONE_EGLD).run();
+    let burn_egld = 9 * ONE_EGLD / 10;
+    assert_eq!(pending_burn_swap(&mut world), (RustBigUint::from(burn_egld), RustBigUint::from(0u32)));
+
+    // 1. Wrap: les WEGLD attendent le swap et bloquent le pipeline de liquidite
+    process_burn_swap(&mut world);
+    assert_eq!(pending_burn_swap(&mut world), (RustBigUint::from(0u32), RustBigUint::from(burn_egld)));
+    world.check_account(SC0_ADDRESS).esdt_balance(WEGLD_TOKEN, burn_egld);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .advance_liquidity()
+        .with_result(ExpectError(4, "Rachat BurnSwap en cours"))
+        .run();
+
+    // 2. Swap en echec: les WEGLD reviennent en attente
+    set_paused(&mut world, PAIR_ADDRESS, true);
+    let logs = process_burn_swap(&mut world);
+    assert!(has_event(&logs, "liquidity_processing_error"));
+    assert_eq!(pending_burn_swap(&mut world), (RustBigUint::from(0u32), RustBigUint::from(burn_egld)));
+    set_paused(&mut world, PAIR_ADDRESS, false);
+
+    // Swap puis burn des XCX rachetes dans le callback
+    let logs = process_burn_swap(&mut world);
+    assert!(has_event(&logs, "burn_swap_processed"));
+    assert_eq!(pending_burn_swap(&mut world), (RustBigUint::from(0u32), RustBigUint::from(0u32)));
+    world.check_account(SC0_ADDRESS).esdt_balance(WEGLD_TOKEN, 0u64);
+    let burned: RustBigUint = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_total_burned()
+        .returns(ReturnsResultUnmanaged)
+        .run();
+    assert!(burned > RustBigUint::from(800u64) * RustBigUint::from(ONE_EGLD));
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .process_burn_swap(BigUint::from(1u64))
+        .with_result(ExpectError(4, "Pas d'EGLD en attente pour le rachat"))
+        .run();
+}
+
+/// Deploie le DAO V2 (vote d'un jour, sans timelock ni quorum) et le branche sur SC0;
+/// VOTER detient les XCX necessaires pour proposer et voter
+fn setup_dao_v2(world: &mut ScenarioWorld) {
+    world.account(VOTER).nonce(1).esdt_balance(XCX_TOKEN, tokens(1));
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(DAO_V2_CODE_PATH)
+        .argument(&XCX_TOKEN)
+        .new_address(DAO_V2_ADDRESS)
+        .run();
+    for (endpoint, value) in [
+        ("setVotingPeriod", DAY),
+        ("setTimelockPeriod", 0),
+        ("setQuorumPercentage", 0),
+    ] {
+        world
+            .tx()
+            .from(OWNER)
+            .to(DAO_V2_ADDRESS)
+            .raw_call(endpoint)
+            .argument(&value)
+            .run();
+    }
+    world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("setCircleOfLifeAddress")
+        .argument(&SC0_ADDRESS)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("setMinProposalThreshold")
+        .argument(&tokens(1))
+        .run();
+    world.tx().from(OWNER).to(SC0_ADDRESS).typed(CircleOfLifeCenterProxy).set_dao_contract(DAO_V2_ADDRESS).run();
+}
+
+/// Proposition du DAO V2 pour une etape de liquidite protocolaire (type 8: unlock,
+/// 9: remove, 10: distribution), proposee et votee par VOTER et finalisee a `now + DAY`
+fn dao_v2_pass_proposal(world: &mut ScenarioWorld, now: &mut u64, proposal_type: u8, lock_id: u64) -> u64 {
+    world.current_block().block_timestamp(*now);
+    let proposal_id = world
+        .tx()
+        .from(VOTER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("createProposal")
+        .argument(&ManagedBuffer::<StaticApi>::from("Liquidite protocolaire"))
+        .argument(&ManagedBuffer::<StaticApi>::new())
+        .argument(&proposal_type)
+        .argument(&SC0_ADDRESS)
+        .argument(&BigUint::<StaticApi>::from(lock_id))
+        .payment(EsdtTokenPayment::new(XCX_TOKEN.to_token_identifier(), 0, tokens(1)))
+        .original_result::<u64>()
+        .returns(ReturnsResult)
+        .run();
+    world
+        .tx()
+        .from(VOTER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("vote")
+        .argument(&proposal_id)
+        .argument(&true)
+        .payment(EsdtTokenPayment::new(XCX_TOKEN.to_token_identifier(), 0, tokens(1)))
+        .run();
+    *now += DAY + 1;
+    world.current_block().block_timestamp(*now);
+    world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("finalizeProposal")
+        .argument(&proposal_id)
+        .run();
+    proposal_id
+}
+
+fn dao_v2_execute(world: &mut ScenarioWorld, proposal_id: u64) -> Vec<Log> {
+    world
+        .tx()
+        .from(OWNER)
+        .to(DAO_V2_ADDRESS)
+        .raw_call("executeProposal")
+        .argument(&proposal_id)
+        .gas(ADVANCE_GAS_LIMIT)
+        .returns(ReturnsLogs)
+        .run()
+}
+
+#[test]
+fn protocol_owned_liquidity_lifecycle() {
+    let mut world = world();
+    setup(&mut world);
+    world.set_esdt_local_roles(SC0_ADDRESS, XCX_TOKEN.as_bytes(), &[EsdtLocalRole::Burn]);
+    setup_dao_v2(&mut world);
+
+    for _ in 0..4 {
+        advance_liquidity(&mut world);
+    }
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Idle, 0));
+
+    // Le lock cree par le pipeline est enregistre avec les apports de addLiquidity
+    let lock = protocol_lock(&mut world, 1);
+    let locker_lock: LockInfo<StaticApi> = world
+        .query()
+        .to(LOCKER_ADDRESS)
+        .typed(MockLpLockerProxy)
+        .locks(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(lock.status, ProtocolLockStatus::Locked);
+    assert_eq!(lock.lp_amount, locker_lock.amount);
+    assert_eq!(lock.unlock_at, locker_lock.unlock_timestamp);
+    assert!(lock.egld_contributed > 0u32 && lock.egld_contributed <= PENDING_LIQUIDITY / 2);
+    assert!(lock.xcirclex_contributed > 0u32);
+    let (total_lp, egld_contributed, _, egld_recovered, _, lock_count): (
+        RustBigUint, RustBigUint, RustBigUint, RustBigUint, RustBigUint, usize,
+    ) = world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_protocol_liquidity()
+        .returns(ReturnsResultUnmanaged)
+        .run()
+        .into_tuple();
+    assert_eq!(total_lp.to_bytes_be(), lock.lp_amount.to_bytes_be().as_slice());
+    assert_eq!(egld_contributed.to_bytes_be(), lock.egld_contributed.to_bytes_be().as_slice());
+    assert_eq!(egld_recovered, RustBigUint::from(0u32));
+    assert_eq!(lock_count, 1);
+
+    // Les locks protocolaires ne passent que par les propositions du DAO V2, apres expiration
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .unlock_lp_tokens(1u64, OWNER)
+        .with_result(ExpectError(4, "Lock protocolaire: utiliser unlockProtocolLiquidity"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .unlock_protocol_liquidity(1u64, 1u64)
+        .with_result(ExpectError(4, "DAO seulement"))
+        .run();
+    // Execution avant expiration: SC0 refuse, la proposition reste executable
+    let mut now = 0;
+    let unlock_proposal = dao_v2_pass_proposal(&mut world, &mut now, 8, 1);
+    let logs = dao_v2_execute(&mut world, unlock_proposal);
+    assert!(has_event(&logs, "circle_of_life_call_failed"));
+    assert_eq!(protocol_lock(&mut world, 1).status, ProtocolLockStatus::Locked);
+
+    // 1. Unlock: les LP tokens reviennent dans SC0, le pipeline attend
+    world.current_block().block_timestamp(LOCK_DURATION_SECONDS);
+    let logs = dao_v2_execute(&mut world, unlock_proposal);
+    assert!(has_event(&logs, "protocol_liquidity_unlocked"));
+    assert!(!has_event(&logs, "circle_of_life_call_failed"));
+    assert_eq!(protocol_lock(&mut world, 1).status, ProtocolLockStatus::Unlocked);
+    world.check_account(SC0_ADDRESS).esdt_balance(LP_TOKEN, lock.lp_amount.to_u64().unwrap());
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .advance_liquidity()
+        .with_result(ExpectError(4, "Retrait de liquidite protocolaire en cours"))
+        .run();
+
+    // 2. removeLiquidity: WEGLD en attente de distribution, XCX repartis selon la table
+    // (part DAO envoyee, Treasury et Liquidity brulees: XCX n'est pas le token de recompense)
+    now = LOCK_DURATION_SECONDS;
+    let remove_proposal = dao_v2_pass_proposal(&mut world, &mut now, 9, 1);
+    let logs = dao_v2_execute(&mut world, remove_proposal);
+    assert!(has_event(&logs, "protocol_liquidity_removed"));
+    let removed = protocol_lock(&mut world, 1);
+    assert_eq!(removed.status, ProtocolLockStatus::Removed);
+    assert!(removed.egld_recovered > 0u32 && removed.xcirclex_recovered > 0u32);
+    let xcx_recovered = removed.xcirclex_recovered.clone();
+    let xcx_to_dao = xcx_recovered.clone() - &xcx_recovered * 314u64 / 10_000u64 - &xcx_recovered * 6_780u64 / 10_000u64;
+    world.check_account(DAO_V2_ADDRESS).esdt_balance(XCX_TOKEN, xcx_to_dao.clone());
+    world
+        .query()
+        .to(SC0_ADDRESS)
+        .typed(CircleOfLifeCenterProxy)
+        .get_total_burned()
+        .returns(ExpectValue(xcx_recovered - xcx_to_dao))
+        .run();
+    world.check_account(SC0_ADDRESS).esdt_balance(LP_TOKEN, 0u64);
+    let (pending_lp, pending_wegld, in_flight) = pending_protocol_withdrawal(&mut world);
+    assert_eq!(pending_lp, RustBigUint::from(0u32));
+    assert_eq!(pending_wegld.to_bytes_be(), removed.egld_recovered.to_bytes_be().as_slice());
+    assert!(!in_flight);
+
+    // 3. Unwrap et distribution des EGLD selon la table (part Liquidity -> en attente)
+    let pending_before = pending_liquidity(&mut world);
+    let distribute_proposal = dao_v2_pass_proposal(&mut world, &mut now, 10, 0);
+    let logs = dao_v2_execute(&mut world, distribute_proposal);
+    assert!(has_event(&logs, "protocol_liquidity_proceeds_distributed"));
+    assert!(pending_liquidity(&mut world) > pending_before);
+    assert_eq!(
+        pending_protocol_withdrawal(&mut world),
+        (RustBigUint::from(0u32), RustBigUint::from(0u32), false)
+    );
+
+    // Le pipeline reprend avec les EGLD redistribues
+    advance_liquidity(&mut world);
+    assert_eq!(liquidity_stage(&mut world), (LiquidityStage::Wrapped, 0));
+}
+
+#[test]
+fn failed_protocol_liquidity_steps_reopen_dao_proposals() {
+    let mut world = world();
+    setup(&mut world);
+    world.set_esdt_local_roles(SC0_ADDRESS, XCX_TOKEN.as_bytes(), &[EsdtLocalRole::Burn]);
+    setup_dao_v2(&mut world);
+    for _ in 0..4 {
+        advance_liquidity(&mut world);
+    }
+    let lp_amount = protocol_lock(&mut world, 1).lp_amount.to_u64().unwrap();
+
+    // Unlock accepte par SC0 mais refuse par le LP Locker: SC0 rouvre la proposition
+    let mut now = LOCK_DURATION_SECONDS;
+    let unlock_proposal = dao_v2_pass_proposal(&mut world, &mut now, 8, 1);
+    set_paused(&mut world, LOCKER_ADDRESS, true);
+    let logs = dao_v2_execute(&mut world, unlock_proposal);
+    assert!(has_event(&logs, "liquidity_processing_error"));
+    assert!(has_event(&logs, "circle_of_life_call_failed"));
+    assert_eq!(protocol_lock(&mut world, 1).status, ProtocolLockStatus::Locked);
+    assert!(!pending_protocol_withdrawal(&mut world).2);
+
+    set_paused(&mut world, LOCKER_ADDRESS, false);
+    let logs = dao_v2_execute(&mut world, unlock_proposal);
+    assert!(has_event(&logs, "protocol_liquidity_unlocked"));
+    assert!(!has_event(&logs, "circle_of_life_call_failed"));
+
+    // Retrait bloque par le controle de prix: SC0 echoue, la proposition reste executable
+    let remove_proposal = dao_v2_pass_proposal(&mut world, &mut now, 9, 1);
+    world
+        .tx()
+        .from(WHALE)
+        .to(PAIR_ADDRESS)
+        .typed(MockXExchangePairProxy)
+        .swap_tokens_fixed_input(XCX_TOKEN, BigUint::from(1u64))
+        .payment(EsdtTokenPayment::new(WEGLD_TOKEN.to_token_identifier(), 0, tokens(200)))
+        .run();
+    for _ in 0..2 {
+        let logs = dao_v2_execute(&mut world, remove_proposal);
+        assert!(has_event(&logs, "circle_of_life_call_failed"));
+        assert!(!has_event(&logs, "protocol_liquidity_removed"));
+    }
+    assert_eq!(protocol_lock(&mut world, 1).status, ProtocolLockStatus::Unlocked);
+    world.check_account(SC0_ADDRESS).esdt_balance(LP_TOKEN, lp_amount);
+    assert!(!pending_protocol_withdrawal(&mut world).2);
+}
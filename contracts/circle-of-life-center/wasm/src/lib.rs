@@ -6,10 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                          126
+// Endpoints:                          203
 // Async Callback (empty):               1
-// Promise callbacks:                    5
-// Total number of exported functions: 134
+// Promise callbacks:                   10
+// Total number of exported functions: 216
 
 #![no_std]
 
@@ -23,30 +23,41 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         deposit => deposit
         setPeripheralTemplate => set_peripheral_template
+        upgradePeripherals => upgrade_peripherals
         changeOwner => change_owner
         pause => pause
         unpause => unpause
         setEntryFee => set_entry_fee
         setCirculationAmount => set_circulation_amount
+        setCirculationPolicy => set_circulation_policy
+        createRing => create_ring
+        setRingMaxMembers => set_ring_max_members
+        setCirculationToken => set_circulation_token
         setDistributionEnabled => set_distribution_enabled
         setDaoContract => set_dao_contract
+        setStakingContract => set_staking_contract
+        setDistributionTable => set_distribution_table
+        processBurnSwap => process_burn_swap
         setXExchangePair => set_xexchange_pair
         setWegldContract => set_wegld_contract
         setLpLocker => set_lp_locker
         setSlippageTolerance => set_slippage_tolerance
+        setPriceGuardPolicy => set_price_guard_policy
+        updatePriceReference => update_price_reference
         setLiquidityThreshold => set_liquidity_threshold
         setLpTokenId => set_lp_token_id
         setXcirclexTokenId => set_xcirclex_token_id
         unlockLpTokens => unlock_lp_tokens
+        unlockProtocolLiquidity => unlock_protocol_liquidity
+        removeProtocolLiquidity => remove_protocol_liquidity
+        distributeLiquidityProceeds => distribute_liquidity_proceeds
         withdrawPendingLiquidity => withdraw_pending_liquidity
+        advanceLiquidity => advance_liquidity
         liquidityStep1_WrapEgld => liquidity_step1_wrap_egld
-        processLiquidity => process_liquidity
         liquidityStep2_Swap => liquidity_step2_swap
         liquidityStep3_AddLiquidity => liquidity_step3_add_liquidity
         liquidityStep4_LockLp => liquidity_step4_lock_lp
-        resumeProcessingFromWegld => resume_processing_from_wegld
-        resumeFromAddLiquidity => resume_from_add_liquidity
-        lockPendingLpTokens => lock_pending_lp_tokens
+        setLiquidityStage => set_liquidity_stage
         distributeExistingEgld => distribute_existing_egld
         setRewardToken => set_reward_token
         setRewardPerCycle => set_reward_per_cycle
@@ -56,11 +67,27 @@ multiversx_sc_wasm_adapter::endpoints! {
         setBurnPerSc => set_burn_per_sc
         setStarterBonusPercentage => set_starter_bonus_percentage
         setNftContract => set_nft_contract
+        setRotationStrategy => set_rotation_strategy
+        setFailurePolicy => set_failure_policy
+        setCycleHistoryRetention => set_cycle_history_retention
+        setHopTimeout => set_hop_timeout
+        setExitPolicy => set_exit_policy
+        setKeeperReward => set_keeper_reward
+        setClaimSchedule => set_claim_schedule
+        setAutoSettlementItems => set_auto_settlement_items
         resetCycle => reset_cycle
         initializePioneerIndices => initialize_pioneer_indices
+        rebuildActiveIndex => rebuild_active_index
         simulateNextDay => simulate_next_day
         failCycle => fail_cycle
+        skipHolder => skip_holder
+        appealBan => appeal_ban
+        resolveBanAppeal => resolve_ban_appeal
+        clearBanAppeal => clear_ban_appeal
+        setAppealBond => set_appeal_bond
         withdraw => withdraw
+        withdrawToken => withdraw_token
+        fundCirculation => fund_circulation
         recoverFundsFromPeripheral => recover_funds_from_peripheral
         joinCircle => join_circle
         startDailyCycle => start_daily_cycle
@@ -70,10 +97,16 @@ multiversx_sc_wasm_adapter::endpoints! {
         disableAutoSign => disable_auto_sign
         processNextTransfer => process_next_transfer
         processAllPendingTransfers => process_all_pending_transfers
+        settleCycle => settle_cycle
         signAndForward => sign_and_forward
+        setDelegate => set_delegate
+        revokeDelegate => revoke_delegate
         setInactive => set_inactive
         setActive => set_active
         leaveCircle => leave_circle
+        proposeMembershipTransfer => propose_membership_transfer
+        cancelMembershipTransfer => cancel_membership_transfer
+        acceptMembershipTransfer => accept_membership_transfer
         claimRewards => claim_rewards
         getCircleInfo => get_circle_info
         getMyContract => get_my_contract
@@ -81,18 +114,40 @@ multiversx_sc_wasm_adapter::endpoints! {
         isActive => is_active
         isMyTurn => is_my_turn
         getActiveContracts => get_active_contracts_view
+        getRotationStrategy => get_rotation_strategy
+        getSettlementInfo => get_settlement_info
+        getSettlementRemaining => get_settlement_remaining
+        getRotationOrder => get_rotation_order_view
         getAllContracts => get_all_contracts
         getPeripheralTemplate => get_peripheral_template
+        getPeripheralCodeVersion => get_peripheral_code_version
+        getOutdatedPeripherals => get_outdated_peripherals
         getOwner => get_owner
         isPaused => get_is_paused
         getContractBalance => get_contract_balance
         getCycleHolder => get_cycle_holder
+        getCycleRecord => get_cycle_record
+        getCycleHistory => get_cycle_history
+        getCycleHistoryInfo => get_cycle_history_info
+        getHopInfo => get_hop_info
+        getSkippedHops => get_skipped_hops
         getCycleDay => get_cycle_day
         getCurrentDay => get_current_day_view
         getCycleEpoch => get_cycle_epoch
         getCyclesCompleted => get_cycles_completed
         getCyclesFailed => get_cycles_failed
         getCycleStats => get_cycle_stats
+        getRingCount => get_ring_count
+        getRingInfo => get_ring_info
+        getCirculationPolicy => get_circulation_policy_view
+        getNextCirculationAmount => get_next_circulation_amount
+        getCirculationToken => get_circulation_token_view
+        getExitQuote => get_exit_quote
+        getExitPolicy => get_exit_policy_view
+        getPendingMembershipTransfer => get_pending_membership_transfer
+        getRetiredContract => get_retired_contract
+        isContractRetired => is_contract_retired
+        getMemberRing => get_member_ring
         hasPreSigned => has_pre_signed
         hasSignedThisCycle => has_signed_this_cycle
         getAutoSignStatus => get_auto_sign_status
@@ -103,6 +158,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         getAllContractsWithOwners => get_all_contracts_with_owners
         getPreSignedMembers => get_pre_signed_members
         getScStats => get_sc_stats
+        getAppealInfo => get_appeal_info
         getInfractionInfo => get_infraction_info
         willInfractionReset => will_infraction_reset
         isBanned => is_banned
@@ -117,6 +173,12 @@ multiversx_sc_wasm_adapter::endpoints! {
         isSunday => is_sunday_view
         getDayOfWeek => get_day_of_week
         canClaimRewards => can_claim_rewards
+        getKeeperRewardConfig => get_keeper_reward_config
+        getKeeperStats => get_keeper_stats
+        getDelegation => get_delegation
+        getDelegatedMembers => get_delegated_members
+        getClaimSchedule => get_claim_schedule_view
+        getNextClaimWindow => get_next_claim_window
         getCurrentCycleReward => get_current_cycle_reward
         getCurrentEra => get_current_era_view
         getNextCircleCompleteCycle => get_next_circle_complete_cycle
@@ -131,6 +193,20 @@ multiversx_sc_wasm_adapter::endpoints! {
         getTotalDistributedDao => get_total_distributed_dao
         getPendingLiquidityEgld => get_pending_liquidity_egld
         getPendingLiquidityInfo => get_pending_liquidity_info
+        getDistributionTable => get_distribution_table
+        getDistributionTableHistory => get_distribution_table_history
+        getDistributionTableHistoryCount => get_distribution_table_history_count
+        getTotalDistributedStaking => get_total_distributed_staking
+        getPendingBurnSwapEgld => get_pending_burn_swap_egld
+        getPendingBurnSwapWegld => get_pending_burn_swap_wegld
+        getLiquidityStage => get_liquidity_stage
+        getProtocolLocks => get_protocol_locks
+        getProtocolLock => get_protocol_lock_view
+        getProtocolLiquidity => get_protocol_liquidity
+        getProtocolLiquidityValue => get_protocol_liquidity_value
+        getPendingProtocolWithdrawal => get_pending_protocol_withdrawal
+        getPriceGuardPolicy => get_price_guard_policy_view
+        getTwapPrice => get_twap_price
         isDistributionEnabled => is_distribution_enabled
         getStarterBonusInfo => get_starter_bonus_info
         getStarterBonusPercentage => get_starter_bonus_percentage
@@ -142,15 +218,21 @@ multiversx_sc_wasm_adapter::endpoints! {
         getPioneerInfo => get_pioneer_info
         getRemainingPioneerSlots => get_remaining_pioneer_slots
         getDepositBonusInfo => get_deposit_bonus_info
+        getMemberTokenDeposits => get_member_token_deposits
         getMemberEgldDeposits => get_member_egld_deposits
         getDepositBonusPercent => get_deposit_bonus_percent
         getTotalEgldDeposits => get_total_egld_deposits
         getAllBonuses => get_all_bonuses
         getPendingAutoTransfers => get_pending_auto_transfers
+        burn_swap_wrap_callback => burn_swap_wrap_callback
+        burn_swap_callback => burn_swap_callback
         wrap_egld_callback => wrap_egld_callback
         swap_xcirclex_callback => swap_xcirclex_callback
         add_liquidity_callback => add_liquidity_callback
         lock_lp_callback => lock_lp_callback
+        protocol_unlock_callback => protocol_unlock_callback
+        protocol_remove_callback => protocol_remove_callback
+        proceeds_unwrap_callback => proceeds_unwrap_callback
         unlock_lp_tokens_callback => unlock_lp_tokens_callback
     )
 }
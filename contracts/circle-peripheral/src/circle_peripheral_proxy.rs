@@ -0,0 +1,288 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct CirclePeripheralProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for CirclePeripheralProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = CirclePeripheralProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        CirclePeripheralProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct CirclePeripheralProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> CirclePeripheralProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Initialise le contrat peripherique 
+    /// Appele par SC0 lors du deploiement, avec la version du code du template 
+    pub fn init<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        owner: Arg0,
+        sc0_address: Arg1,
+        code_version: Arg2,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&owner)
+            .argument(&sc0_address)
+            .argument(&code_version)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> CirclePeripheralProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Appele par SC0 (upgradePeripherals) avec la version du nouveau code 
+    pub fn upgrade<
+        Arg0: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        code_version: Arg0,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .argument(&code_version)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> CirclePeripheralProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Permet au contrat de recevoir des EGLD ou des ESDT 
+    pub fn deposit(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit")
+            .original_result()
+    }
+
+    /// Endpoint par defaut pour recevoir des EGLD ou des ESDT 
+    pub fn receive(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("receive")
+            .original_result()
+    }
+
+    /// Transfere des fonds vers une adresse (owner ou SC0 seulement) 
+    /// Token de circulation du cercle par defaut, `token` permet de retirer un autre token 
+    /// Tant que ce SC est holder du cycle, l'owner ne peut transferer que le surplus 
+    pub fn transfer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        to: Arg0,
+        amount: Arg1,
+        token: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transfer")
+            .argument(&to)
+            .argument(&amount)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Transfere tout le solde en token de circulation vers SC0 (appele par SC0 en cas de timeout) 
+    pub fn force_transfer_to_sc0(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("forceTransferToSC0")
+            .original_result()
+    }
+
+    /// Retire vers l'owner le surplus du SC (envoi par erreur, poussieres...) 
+    /// Le montant circulant reste bloque tant que ce SC est le holder du cycle de son cercle 
+    /// Token de circulation par defaut, `token` permet de retirer un autre token (ESDT) 
+    pub fn withdraw_surplus<
+        Arg0: ProxyArg<OptionalValue<EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawSurplus")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Transfere au prochain SC dans le cycle (SC0 seulement) 
+    /// L'owner et ses delegues signent via signAndForward de SC0, qui enregistre le passage 
+    pub fn sign_and_forward<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        next_sc: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("signAndForward")
+            .argument(&next_sc)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Desactive le SC (owner ou delegue avec portee statut) 
+    pub fn deactivate(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("deactivate")
+            .original_result()
+    }
+
+    /// Reactive le SC (owner ou delegue avec portee statut) 
+    pub fn activate(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("activate")
+            .original_result()
+    }
+
+    /// Change l'owner du SC (SC0 seulement, apres acceptation du transfert d'adhesion) 
+    pub fn transfer_ownership<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_owner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transferOwnership")
+            .argument(&new_owner)
+            .original_result()
+    }
+
+    pub fn get_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOwner")
+            .original_result()
+    }
+
+    pub fn get_sc0_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSC0Address")
+            .original_result()
+    }
+
+    pub fn get_is_active(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isActive")
+            .original_result()
+    }
+
+    pub fn get_code_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCodeVersion")
+            .original_result()
+    }
+
+    /// Solde en token de circulation du cercle 
+    pub fn get_balance(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBalance")
+            .original_result()
+    }
+
+    pub fn get_circulation_token_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCirculationToken")
+            .original_result()
+    }
+
+    /// Retourne (montant reserve a la circulation, surplus retirable) pour un token 
+    /// (token de circulation par defaut) 
+    pub fn get_surplus<
+        Arg0: ProxyArg<OptionalValue<EgldOrEsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSurplus")
+            .argument(&token)
+            .original_result()
+    }
+}
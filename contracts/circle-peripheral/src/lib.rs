@@ -3,46 +3,76 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod circle_peripheral_proxy;
+
+/// Portee d'une delegation (miroir de circle-of-life-center, lu dans le storage de SC0)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum DelegateScope {
+    SignOnly,
+    SignAndStatus,
+}
+
+/// Delegation accordee par le membre dans SC0 (meme encodage que dans SC0)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Delegation<M: ManagedTypeApi> {
+    pub delegate: ManagedAddress<M>,
+    pub expires_epoch: u64,
+    pub scope: DelegateScope,
+}
+
 /// Circle Peripheral Contract (SC1, SC2, SC3...)
 ///
 /// Ce smart contract est cree par SC0 pour chaque membre du cercle.
-/// Il peut recevoir et envoyer des EGLD dans le cadre des cycles.
+/// Il peut recevoir et envoyer le token de circulation de son cercle
+/// (EGLD ou ESDT, configure dans SC0) dans le cadre des cycles.
 ///
 /// Caracteristiques:
 /// - Owner: l'utilisateur qui a rejoint le cercle
 /// - Co-owner: SC0 (peut forcer des transferts en cas de timeout)
-/// - Peut recevoir des EGLD
-/// - Peut signer et transferer au SC suivant
+/// - Peut recevoir des EGLD et des ESDT
+/// - Transfere au SC suivant sur appel de SC0 (la signature passe par SC0)
+/// - Delegue (bot keeper): autorise par le membre dans SC0, signe via SC0 et,
+///   selon la portee, change le statut
 #[multiversx_sc::contract]
 pub trait CirclePeripheral {
 
     /// Initialise le contrat peripherique
-    /// Appele par SC0 lors du deploiement
+    /// Appele par SC0 lors du deploiement, avec la version du code du template
     #[init]
-    fn init(&self, owner: ManagedAddress, sc0_address: ManagedAddress) {
+    fn init(&self, owner: ManagedAddress, sc0_address: ManagedAddress, code_version: OptionalValue<u64>) {
         self.owner().set(&owner);
         self.sc0_address().set(&sc0_address);
         self.is_active().set(true);
+        if let OptionalValue::Some(version) = code_version {
+            self.code_version().set(version);
+        }
     }
 
+    /// Appele par SC0 (upgradePeripherals) avec la version du nouveau code
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, code_version: OptionalValue<u64>) {
+        if let OptionalValue::Some(version) = code_version {
+            self.code_version().set(version);
+        }
+    }
 
     // ═══════════════════════════════════════════════════════════════
     // PAYABLE - Recevoir des fonds
     // ═══════════════════════════════════════════════════════════════
 
-    /// Permet au contrat de recevoir des EGLD
-    #[payable("EGLD")]
+    /// Permet au contrat de recevoir des EGLD ou des ESDT
+    #[payable("*")]
     #[endpoint(deposit)]
     fn deposit(&self) {
         let caller = self.blockchain().get_caller();
-        let payment = self.call_value().egld().clone_value();
-        self.deposit_event(&caller, &payment);
+        let (token, payment) = self.call_value().egld_or_single_fungible_esdt();
+        self.deposit_event(&caller, &token, &payment);
     }
 
-    /// Endpoint par defaut pour recevoir des EGLD
-    #[payable("EGLD")]
+    /// Endpoint par defaut pour recevoir des EGLD ou des ESDT
+    #[payable("*")]
     #[endpoint]
     fn receive(&self) {}
 
@@ -50,61 +80,106 @@ pub trait CirclePeripheral {
     // TRANSFERS
     // ═══════════════════════════════════════════════════════════════
 
-    /// Transfere des EGLD vers une adresse (owner ou SC0 seulement)
+    /// Transfere des fonds vers une adresse (owner ou SC0 seulement)
+    /// Token de circulation du cercle par defaut, `token` permet de retirer un autre token
+    /// Tant que ce SC est holder du cycle, l'owner ne peut transferer que le surplus
     #[endpoint(transfer)]
-    fn transfer(&self, to: ManagedAddress, amount: BigUint) {
+    fn transfer(
+        &self,
+        to: ManagedAddress,
+        amount: BigUint,
+        token: OptionalValue<EgldOrEsdtTokenIdentifier>,
+    ) {
         self.require_owner_or_sc0();
 
-        let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        let token = match token {
+            OptionalValue::Some(token) => token,
+            OptionalValue::None => self.get_circulation_token(),
+        };
+        let balance = self.blockchain().get_sc_balance(&token, 0);
         require!(balance >= amount, "Solde insuffisant");
+        self.require_within_surplus(&token, &amount);
 
-        self.send().direct_egld(&to, &amount);
-        self.transfer_event(&to, &amount);
+        self.send().direct(&to, &token, 0, &amount);
+        self.transfer_event(&to, &token, &amount);
     }
 
-    /// Transfere tout le solde vers SC0 (appele par SC0 en cas de timeout)
+    /// Transfere tout le solde en token de circulation vers SC0 (appele par SC0 en cas de timeout)
     #[endpoint(forceTransferToSC0)]
     fn force_transfer_to_sc0(&self) {
         self.require_sc0();
 
-        let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        let token = self.get_circulation_token();
+        let balance = self.blockchain().get_sc_balance(&token, 0);
         if balance > BigUint::zero() {
             let sc0 = self.sc0_address().get();
-            self.send().direct_egld(&sc0, &balance);
-            self.force_transfer_event(&sc0, &balance);
+            self.send().direct(&sc0, &token, 0, &balance);
+            self.force_transfer_event(&sc0, &token, &balance);
         }
     }
 
-    /// Signe et transfere au prochain SC dans le cycle
+    /// Retire vers l'owner le surplus du SC (envoi par erreur, poussieres...)
+    /// Le montant circulant reste bloque tant que ce SC est le holder du cycle de son cercle
+    /// Token de circulation par defaut, `token` permet de retirer un autre token (ESDT)
+    #[endpoint(withdrawSurplus)]
+    fn withdraw_surplus(&self, token: OptionalValue<EgldOrEsdtTokenIdentifier>) -> BigUint {
+        self.require_owner();
+
+        let token = match token {
+            OptionalValue::Some(token) => token,
+            OptionalValue::None => self.get_circulation_token(),
+        };
+        let surplus = self.get_surplus_amount(&token);
+        require!(surplus > 0u64, "Aucun surplus a retirer");
+
+        let owner = self.owner().get();
+        self.send().direct(&owner, &token, 0, &surplus);
+        self.surplus_withdrawn_event(&owner, &token, &surplus);
+        surplus
+    }
+
+    /// Transfere au prochain SC dans le cycle (SC0 seulement)
+    /// L'owner et ses delegues signent via signAndForward de SC0, qui enregistre le passage
     #[endpoint(signAndForward)]
     fn sign_and_forward(&self, next_sc: ManagedAddress, amount: BigUint) {
-        self.require_owner_or_sc0();
+        self.require_sc0();
 
-        let balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        let token = self.get_circulation_token();
+        let balance = self.blockchain().get_sc_balance(&token, 0);
         require!(balance >= amount, "Solde insuffisant");
 
-        self.send().direct_egld(&next_sc, &amount);
-        self.forward_event(&next_sc, &amount);
+        self.send().direct(&next_sc, &token, 0, &amount);
+        self.forward_event(&next_sc, &token, &amount);
     }
 
     // ═══════════════════════════════════════════════════════════════
     // STATUS
     // ═══════════════════════════════════════════════════════════════
 
-    /// Desactive le SC (owner seulement)
+    /// Desactive le SC (owner ou delegue avec portee statut)
     #[endpoint(deactivate)]
     fn deactivate(&self) {
-        self.require_owner();
+        self.require_owner_or_status_delegate();
         self.is_active().set(false);
     }
 
-    /// Reactive le SC (owner seulement)
+    /// Reactive le SC (owner ou delegue avec portee statut)
     #[endpoint(activate)]
     fn activate(&self) {
-        self.require_owner();
+        self.require_owner_or_status_delegate();
         self.is_active().set(true);
     }
 
+    /// Change l'owner du SC (SC0 seulement, apres acceptation du transfert d'adhesion)
+    #[endpoint(transferOwnership)]
+    fn transfer_ownership(&self, new_owner: ManagedAddress) {
+        self.require_sc0();
+
+        let previous_owner = self.owner().get();
+        self.owner().set(&new_owner);
+        self.owner_changed_event(&previous_owner, &new_owner);
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // ACCESS CONTROL
     // ═══════════════════════════════════════════════════════════════
@@ -122,10 +197,46 @@ pub trait CirclePeripheral {
     }
 
     fn require_owner_or_sc0(&self) {
+        require!(self.is_owner_or_sc0(), "Owner ou SC0 seulement");
+    }
+
+    fn require_owner_or_status_delegate(&self) {
         let caller = self.blockchain().get_caller();
-        let owner = self.owner().get();
+        require!(
+            caller == self.owner().get() || self.is_valid_delegate(DelegateScope::SignAndStatus),
+            "Owner ou delegue seulement"
+        );
+    }
+
+    /// Hors SC0, seul le surplus au-dela du montant reserve peut sortir du SC
+    fn require_within_surplus(&self, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        if self.blockchain().get_caller() == self.sc0_address().get() {
+            return;
+        }
+        require!(
+            amount <= &self.get_surplus_amount(token),
+            "Montant circulant verrouille pendant le cycle"
+        );
+    }
+
+    fn is_owner_or_sc0(&self) -> bool {
+        let caller = self.blockchain().get_caller();
+        caller == self.owner().get() || caller == self.sc0_address().get()
+    }
+
+    /// Verifie la delegation enregistree dans SC0 pour ce SC (lecture directe du storage de SC0)
+    fn is_valid_delegate(&self, scope: DelegateScope) -> bool {
         let sc0 = self.sc0_address().get();
-        require!(caller == owner || caller == sc0, "Owner ou SC0 seulement");
+        let own_address = self.blockchain().get_sc_address();
+        let delegation_mapper = self.sc0_delegation(sc0, &own_address);
+        if delegation_mapper.is_empty() {
+            return false;
+        }
+
+        let delegation = delegation_mapper.get();
+        delegation.delegate == self.blockchain().get_caller()
+            && self.blockchain().get_block_epoch() < delegation.expires_epoch
+            && (scope == DelegateScope::SignOnly || delegation.scope == DelegateScope::SignAndStatus)
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -141,6 +252,118 @@ pub trait CirclePeripheral {
     #[storage_mapper("is_active")]
     fn is_active(&self) -> SingleValueMapper<bool>;
 
+    /// Version du code deploye (lue par SC0, 0 = deploye avant le suivi des versions)
+    #[storage_mapper("code_version")]
+    fn code_version(&self) -> SingleValueMapper<u64>;
+
+    // Storage de SC0 (lecture seule, meme shard)
+
+    #[storage_mapper_from_address("sc_delegate")]
+    fn sc0_delegation(
+        &self,
+        sc0: ManagedAddress,
+        sc: &ManagedAddress,
+    ) -> SingleValueMapper<Delegation<Self::Api>, ManagedAddress>;
+
+    #[storage_mapper_from_address("circulation_amount")]
+    fn sc0_circulation_amount(&self, sc0: ManagedAddress) -> SingleValueMapper<BigUint, ManagedAddress>;
+
+    /// Montant circulant d'un cercle secondaire (cle "circulation_amount" + ring_id)
+    #[storage_mapper_from_address("circulation_amount")]
+    fn sc0_ring_circulation_amount(
+        &self,
+        sc0: ManagedAddress,
+        ring_id: u64,
+    ) -> SingleValueMapper<BigUint, ManagedAddress>;
+
+    #[storage_mapper_from_address("sc_ring")]
+    fn sc0_sc_ring(&self, sc0: ManagedAddress, sc: &ManagedAddress) -> SingleValueMapper<u64, ManagedAddress>;
+
+    #[storage_mapper_from_address("circulation_token")]
+    fn sc0_circulation_token(
+        &self,
+        sc0: ManagedAddress,
+    ) -> SingleValueMapper<EgldOrEsdtTokenIdentifier, ManagedAddress>;
+
+    /// Token de circulation d'un cercle secondaire (cle "circulation_token" + ring_id)
+    #[storage_mapper_from_address("circulation_token")]
+    fn sc0_ring_circulation_token(
+        &self,
+        sc0: ManagedAddress,
+        ring_id: u64,
+    ) -> SingleValueMapper<EgldOrEsdtTokenIdentifier, ManagedAddress>;
+
+    #[storage_mapper_from_address("cycle_holder")]
+    fn sc0_cycle_holder(&self, sc0: ManagedAddress) -> SingleValueMapper<ManagedAddress, ManagedAddress>;
+
+    /// Holder du cycle d'un cercle secondaire (cle "cycle_holder" + ring_id)
+    #[storage_mapper_from_address("cycle_holder")]
+    fn sc0_ring_cycle_holder(
+        &self,
+        sc0: ManagedAddress,
+        ring_id: u64,
+    ) -> SingleValueMapper<ManagedAddress, ManagedAddress>;
+
+    /// Le cercle principal (ring 0) garde la cle historique dans SC0
+    fn get_ring_circulation_amount(&self, sc0: ManagedAddress, ring_id: u64) -> BigUint {
+        if ring_id == 0 {
+            self.sc0_circulation_amount(sc0).get()
+        } else {
+            self.sc0_ring_circulation_amount(sc0, ring_id).get()
+        }
+    }
+
+    /// Montant reserve a la circulation: le montant circulant si ce SC est
+    /// le holder du cycle en cours de son cercle (lu dans SC0), zero sinon
+    fn get_reserved_amount(&self, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        if token != &self.get_circulation_token() {
+            return BigUint::zero();
+        }
+
+        let sc0 = self.sc0_address().get();
+        let ring_id = self.sc0_sc_ring(sc0.clone(), &self.blockchain().get_sc_address()).get();
+        if !self.is_cycle_holder(sc0.clone(), ring_id) {
+            return BigUint::zero();
+        }
+        self.get_ring_circulation_amount(sc0, ring_id)
+    }
+
+    /// Ce SC detient-il le montant circulant du cycle en cours de son cercle (lu dans SC0)
+    fn is_cycle_holder(&self, sc0: ManagedAddress, ring_id: u64) -> bool {
+        let holder_mapper = if ring_id == 0 {
+            self.sc0_cycle_holder(sc0)
+        } else {
+            self.sc0_ring_cycle_holder(sc0, ring_id)
+        };
+        !holder_mapper.is_empty() && holder_mapper.get() == self.blockchain().get_sc_address()
+    }
+
+    /// Solde en `token` au-dela du montant reserve a la circulation
+    fn get_surplus_amount(&self, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        let balance = self.blockchain().get_sc_balance(token, 0);
+        let reserved = self.get_reserved_amount(token);
+        if balance > reserved {
+            balance - reserved
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    /// Token de circulation du cercle de ce SC, lu dans SC0 (vide = EGLD)
+    fn get_circulation_token(&self) -> EgldOrEsdtTokenIdentifier {
+        let sc0 = self.sc0_address().get();
+        let ring_id = self.sc0_sc_ring(sc0.clone(), &self.blockchain().get_sc_address()).get();
+        let mapper = if ring_id == 0 {
+            self.sc0_circulation_token(sc0)
+        } else {
+            self.sc0_ring_circulation_token(sc0, ring_id)
+        };
+        if mapper.is_empty() {
+            return EgldOrEsdtTokenIdentifier::egld();
+        }
+        mapper.get()
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // VIEWS
     // ═══════════════════════════════════════════════════════════════
@@ -160,9 +383,31 @@ pub trait CirclePeripheral {
         self.is_active().get()
     }
 
+    #[view(getCodeVersion)]
+    fn get_code_version(&self) -> u64 {
+        self.code_version().get()
+    }
+
+    /// Solde en token de circulation du cercle
     #[view(getBalance)]
     fn get_balance(&self) -> BigUint {
-        self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0)
+        self.blockchain().get_sc_balance(&self.get_circulation_token(), 0)
+    }
+
+    #[view(getCirculationToken)]
+    fn get_circulation_token_view(&self) -> EgldOrEsdtTokenIdentifier {
+        self.get_circulation_token()
+    }
+
+    /// Retourne (montant reserve a la circulation, surplus retirable) pour un token
+    /// (token de circulation par defaut)
+    #[view(getSurplus)]
+    fn get_surplus(&self, token: OptionalValue<EgldOrEsdtTokenIdentifier>) -> MultiValue2<BigUint, BigUint> {
+        let token = match token {
+            OptionalValue::Some(token) => token,
+            OptionalValue::None => self.get_circulation_token(),
+        };
+        (self.get_reserved_amount(&token), self.get_surplus_amount(&token)).into()
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -170,14 +415,45 @@ pub trait CirclePeripheral {
     // ═══════════════════════════════════════════════════════════════
 
     #[event("deposit")]
-    fn deposit_event(&self, #[indexed] from: &ManagedAddress, amount: &BigUint);
+    fn deposit_event(
+        &self,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
 
     #[event("transfer")]
-    fn transfer_event(&self, #[indexed] to: &ManagedAddress, amount: &BigUint);
+    fn transfer_event(
+        &self,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
 
     #[event("forward")]
-    fn forward_event(&self, #[indexed] to: &ManagedAddress, amount: &BigUint);
+    fn forward_event(
+        &self,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("owner_changed")]
+    fn owner_changed_event(&self, #[indexed] previous_owner: &ManagedAddress, #[indexed] new_owner: &ManagedAddress);
+
+    #[event("surplus_withdrawn")]
+    fn surplus_withdrawn_event(
+        &self,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
 
     #[event("force_transfer")]
-    fn force_transfer_event(&self, #[indexed] to: &ManagedAddress, amount: &BigUint);
+    fn force_transfer_event(
+        &self,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
 }
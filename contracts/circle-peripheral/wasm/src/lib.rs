@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           11
+// Endpoints:                           16
 // Async Callback (empty):               1
-// Total number of exported functions:  14
+// Total number of exported functions:  19
 
 #![no_std]
 
@@ -24,13 +24,18 @@ multiversx_sc_wasm_adapter::endpoints! {
         receive => receive
         transfer => transfer
         forceTransferToSC0 => force_transfer_to_sc0
+        withdrawSurplus => withdraw_surplus
         signAndForward => sign_and_forward
         deactivate => deactivate
         activate => activate
+        transferOwnership => transfer_ownership
         getOwner => get_owner
         getSC0Address => get_sc0_address
         isActive => get_is_active
+        getCodeVersion => get_code_version
         getBalance => get_balance
+        getCirculationToken => get_circulation_token_view
+        getSurplus => get_surplus
     )
 }
 
@@ -0,0 +1,110 @@
+#![no_std]
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub mod mock_lp_locker_proxy;
+
+/// Lock de LP tokens
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct LockInfo<M: ManagedTypeApi> {
+    pub owner: ManagedAddress<M>,
+    pub token_id: TokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub unlock_timestamp: u64,
+}
+
+/// Mock de xcirclex-lp-locker (tests uniquement)
+///
+/// Reprend les endpoints appeles par circle-of-life-center (lockLpTokens, unlock).
+/// pause/resume permettent de simuler un echec des appels, setOmitLockId un
+/// locker qui ne retourne pas l'ID du lock
+#[multiversx_sc::contract]
+pub trait MockLpLocker {
+
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Verrouille les LP tokens recus pour lock_duration_days jours (minimum 365)
+    /// Retourne l'ID du lock (rien si setOmitLockId est actif)
+    #[payable("*")]
+    #[endpoint(lockLpTokens)]
+    fn lock_lp_tokens(&self, lock_duration_days: u64) -> OptionalValue<u64> {
+        require!(!self.paused().get(), "Locker en pause");
+        require!(lock_duration_days >= 365, "Duree minimum: 365 jours (12 mois)");
+
+        let payment = self.call_value().single_esdt();
+        require!(payment.amount > 0u32, "Montant invalide");
+
+        let lock_id = self.next_lock_id().get() + 1;
+        self.next_lock_id().set(lock_id);
+
+        let unlock_timestamp = self.blockchain().get_block_timestamp() + lock_duration_days * 24 * 60 * 60;
+        self.locks(lock_id).set(LockInfo {
+            owner: self.blockchain().get_caller(),
+            token_id: payment.token_identifier.clone(),
+            amount: payment.amount.clone(),
+            unlock_timestamp,
+        });
+
+        if self.omit_lock_id().get() {
+            return OptionalValue::None;
+        }
+        OptionalValue::Some(lock_id)
+    }
+
+    /// Renvoie les LP tokens au proprietaire du lock apres expiration
+    #[endpoint]
+    fn unlock(&self, lock_id: u64) {
+        require!(!self.paused().get(), "Locker en pause");
+        require!(!self.locks(lock_id).is_empty(), "Lock inexistant ou inactif");
+
+        let lock = self.locks(lock_id).get();
+        let caller = self.blockchain().get_caller();
+        require!(lock.owner == caller, "Vous n'etes pas le proprietaire de ce lock");
+        require!(
+            self.blockchain().get_block_timestamp() >= lock.unlock_timestamp,
+            "Lock pas encore expire"
+        );
+
+        self.locks(lock_id).clear();
+        self.send().direct_esdt(&caller, &lock.token_id, 0, &lock.amount);
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn pause(&self) {
+        self.paused().set(true);
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn resume(&self) {
+        self.paused().set(false);
+    }
+
+    #[only_owner]
+    #[endpoint(setOmitLockId)]
+    fn set_omit_lock_id(&self, omit: bool) {
+        self.omit_lock_id().set(omit);
+    }
+
+    #[view(getLock)]
+    #[storage_mapper("locks")]
+    fn locks(&self, lock_id: u64) -> SingleValueMapper<LockInfo<Self::Api>>;
+
+    #[view(getLastLockId)]
+    #[storage_mapper("next_lock_id")]
+    fn next_lock_id(&self) -> SingleValueMapper<u64>;
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("omit_lock_id")]
+    fn omit_lock_id(&self) -> SingleValueMapper<bool>;
+}
@@ -0,0 +1,185 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct MockLpLockerProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for MockLpLockerProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = MockLpLockerProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        MockLpLockerProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct MockLpLockerProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> MockLpLockerProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> MockLpLockerProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> MockLpLockerProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Verrouille les LP tokens recus pour lock_duration_days jours (minimum 365) 
+    /// Retourne l'ID du lock (rien si setOmitLockId est actif) 
+    pub fn lock_lp_tokens<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        lock_duration_days: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .raw_call("lockLpTokens")
+            .argument(&lock_duration_days)
+            .original_result()
+    }
+
+    /// Renvoie les LP tokens au proprietaire du lock apres expiration 
+    pub fn unlock<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        lock_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unlock")
+            .argument(&lock_id)
+            .original_result()
+    }
+
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn resume(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resume")
+            .original_result()
+    }
+
+    pub fn set_omit_lock_id<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        omit: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOmitLockId")
+            .argument(&omit)
+            .original_result()
+    }
+
+    pub fn locks<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        lock_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, LockInfo<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLock")
+            .argument(&lock_id)
+            .original_result()
+    }
+
+    pub fn next_lock_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastLockId")
+            .original_result()
+    }
+
+    pub fn paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct LockInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub owner: ManagedAddress<Api>,
+    pub token_id: TokenIdentifier<Api>,
+    pub amount: BigUint<Api>,
+    pub unlock_timestamp: u64,
+}
@@ -0,0 +1,34 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            8
+// Async Callback (empty):               1
+// Total number of exported functions:  11
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    mock_lp_locker
+    (
+        init => init
+        upgrade => upgrade
+        lockLpTokens => lock_lp_tokens
+        unlock => unlock
+        pause => pause
+        resume => resume
+        setOmitLockId => set_omit_lock_id
+        getLock => locks
+        getLastLockId => next_lock_id
+        isPaused => paused
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,79 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod mock_wegld_swap_proxy;
+
+/// Mock du contrat WEGLD swap de MultiversX (tests uniquement)
+///
+/// Reprend les endpoints appeles par circle-of-life-center (wrapEgld, unwrapEgld).
+/// Le WEGLD est un token local: le contrat doit avoir les roles Mint et Burn.
+/// pause/resume permettent de simuler un echec des appels
+#[multiversx_sc::contract]
+pub trait MockWegldSwap {
+
+    #[init]
+    fn init(&self, wrapped_egld_token_id: TokenIdentifier) {
+        self.wrapped_egld_token_id().set(&wrapped_egld_token_id);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Recoit des EGLD et renvoie le meme montant en WEGLD
+    #[payable("EGLD")]
+    #[endpoint(wrapEgld)]
+    fn wrap_egld(&self) -> EsdtTokenPayment {
+        require!(!self.paused().get(), "Contract is paused");
+
+        let amount = self.call_value().egld().clone_value();
+        require!(amount > 0u32, "Payment must be more than 0");
+
+        let token_id = self.wrapped_egld_token_id().get();
+        self.send().esdt_local_mint(&token_id, 0, &amount);
+
+        let caller = self.blockchain().get_caller();
+        self.send().direct_esdt(&caller, &token_id, 0, &amount);
+
+        EsdtTokenPayment::new(token_id, 0, amount)
+    }
+
+    /// Recoit des WEGLD, les brule et renvoie le meme montant en EGLD
+    #[payable("*")]
+    #[endpoint(unwrapEgld)]
+    fn unwrap_egld(&self) {
+        require!(!self.paused().get(), "Contract is paused");
+
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.wrapped_egld_token_id().get(),
+            "Wrong esdt token"
+        );
+        require!(payment.amount > 0u32, "Must pay more than 0 tokens!");
+
+        self.send().esdt_local_burn(&payment.token_identifier, 0, &payment.amount);
+
+        let caller = self.blockchain().get_caller();
+        self.send().direct_egld(&caller, &payment.amount);
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn pause(&self) {
+        self.paused().set(true);
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn resume(&self) {
+        self.paused().set(false);
+    }
+
+    #[view(getWrappedEgldTokenId)]
+    #[storage_mapper("wrappedEgldTokenId")]
+    fn wrapped_egld_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+}
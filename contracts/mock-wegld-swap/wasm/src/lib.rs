@@ -0,0 +1,32 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            6
+// Async Callback (empty):               1
+// Total number of exported functions:   9
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    mock_wegld_swap
+    (
+        init => init
+        upgrade => upgrade
+        wrapEgld => wrap_egld
+        unwrapEgld => unwrap_egld
+        pause => pause
+        resume => resume
+        getWrappedEgldTokenId => wrapped_egld_token_id
+        isPaused => paused
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,225 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod mock_xexchange_pair_proxy;
+
+/// Frais de swap: 0.3% (comme les paires xExchange standard)
+const FEE_DENOMINATOR: u64 = 1_000;
+const FEE_MULTIPLIER: u64 = 997;
+/// LP tokens du premier depot conserves par la paire
+const MINIMUM_LIQUIDITY: u64 = 1_000;
+
+/// Mock d'une paire xExchange a produit constant (tests uniquement)
+///
+/// Reprend les endpoints appeles par circle-of-life-center (swapTokensFixedInput,
+/// addLiquidity, removeLiquidity, getReservesAndTotalSupply). Le LP token est un
+/// token local: la paire doit avoir les roles Mint et Burn.
+/// pause/resume permettent de simuler un echec des appels
+#[multiversx_sc::contract]
+pub trait MockXExchangePair {
+
+    #[init]
+    fn init(
+        &self,
+        first_token_id: TokenIdentifier,
+        second_token_id: TokenIdentifier,
+        lp_token_identifier: TokenIdentifier
+    ) {
+        require!(first_token_id != second_token_id, "Exchange tokens cannot be the same");
+        self.first_token_id().set(&first_token_id);
+        self.second_token_id().set(&second_token_id);
+        self.lp_token_identifier().set(&lp_token_identifier);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Ajoute de la liquidite: deux paiements (premier token, second token).
+    /// Les montants sont ajustes au ratio des reserves, l'excedent est rendu
+    #[payable("*")]
+    #[endpoint(addLiquidity)]
+    fn add_liquidity(
+        &self,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint
+    ) -> MultiValue3<EsdtTokenPayment, EsdtTokenPayment, EsdtTokenPayment> {
+        self.require_active();
+
+        let [first_payment, second_payment] = self.call_value().multi_esdt();
+        let first_token_id = self.first_token_id().get();
+        let second_token_id = self.second_token_id().get();
+        require!(first_payment.token_identifier == first_token_id, "Bad first payment");
+        require!(second_payment.token_identifier == second_token_id, "Bad second payment");
+
+        let first_reserve = self.reserve(&first_token_id).get();
+        let second_reserve = self.reserve(&second_token_id).get();
+        let lp_supply = self.lp_token_supply().get();
+
+        let (first_amount, second_amount, liquidity) = if lp_supply == 0u32 {
+            let first_amount = first_payment.amount.clone();
+            let second_amount = second_payment.amount.clone();
+            let liquidity = core::cmp::min(first_amount.clone(), second_amount.clone());
+            require!(liquidity > MINIMUM_LIQUIDITY, "First tokens needs to be greater than minimum liquidity");
+            self.lp_token_supply().set(BigUint::from(MINIMUM_LIQUIDITY));
+            (first_amount, second_amount, liquidity - MINIMUM_LIQUIDITY)
+        } else {
+            let second_optimal = &first_payment.amount * &second_reserve / &first_reserve;
+            let (first_amount, second_amount) = if second_optimal <= second_payment.amount {
+                (first_payment.amount.clone(), second_optimal)
+            } else {
+                let first_optimal = &second_payment.amount * &first_reserve / &second_reserve;
+                (first_optimal, second_payment.amount.clone())
+            };
+            let liquidity = core::cmp::min(
+                &first_amount * &lp_supply / &first_reserve,
+                &second_amount * &lp_supply / &second_reserve,
+            );
+            (first_amount, second_amount, liquidity)
+        };
+        require!(first_amount >= first_token_amount_min, "Insufficient first token computed amount");
+        require!(second_amount >= second_token_amount_min, "Insufficient second token computed amount");
+        require!(liquidity > 0u32, "Insufficient liquidity minted");
+
+        self.reserve(&first_token_id).update(|reserve| *reserve += &first_amount);
+        self.reserve(&second_token_id).update(|reserve| *reserve += &second_amount);
+        self.lp_token_supply().update(|supply| *supply += &liquidity);
+
+        let lp_token_id = self.lp_token_identifier().get();
+        self.send().esdt_local_mint(&lp_token_id, 0, &liquidity);
+
+        let caller = self.blockchain().get_caller();
+        let lp_payment = EsdtTokenPayment::new(lp_token_id, 0, liquidity);
+        let first_refund = EsdtTokenPayment::new(first_token_id, 0, &first_payment.amount - &first_amount);
+        let second_refund = EsdtTokenPayment::new(second_token_id, 0, &second_payment.amount - &second_amount);
+        self.send_payment(&caller, &lp_payment);
+        self.send_payment(&caller, &first_refund);
+        self.send_payment(&caller, &second_refund);
+
+        (lp_payment, first_refund, second_refund).into()
+    }
+
+    /// Retire de la liquidite: brule les LP tokens recus et rend la part des reserves
+    #[payable("*")]
+    #[endpoint(removeLiquidity)]
+    fn remove_liquidity(
+        &self,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint
+    ) -> MultiValue2<EsdtTokenPayment, EsdtTokenPayment> {
+        self.require_active();
+
+        let payment = self.call_value().single_esdt();
+        let lp_token_id = self.lp_token_identifier().get();
+        require!(payment.token_identifier == lp_token_id, "Bad payment tokens");
+        require!(payment.amount > 0u32, "Bad payment amount");
+
+        let first_token_id = self.first_token_id().get();
+        let second_token_id = self.second_token_id().get();
+        let lp_supply = self.lp_token_supply().get();
+        let first_amount = &payment.amount * &self.reserve(&first_token_id).get() / &lp_supply;
+        let second_amount = &payment.amount * &self.reserve(&second_token_id).get() / &lp_supply;
+        require!(first_amount >= first_token_amount_min, "Slippage amount A");
+        require!(second_amount >= second_token_amount_min, "Slippage amount B");
+
+        self.reserve(&first_token_id).update(|reserve| *reserve -= &first_amount);
+        self.reserve(&second_token_id).update(|reserve| *reserve -= &second_amount);
+        self.lp_token_supply().update(|supply| *supply -= &payment.amount);
+        self.send().esdt_local_burn(&lp_token_id, 0, &payment.amount);
+
+        let caller = self.blockchain().get_caller();
+        let first_payment = EsdtTokenPayment::new(first_token_id, 0, first_amount);
+        let second_payment = EsdtTokenPayment::new(second_token_id, 0, second_amount);
+        self.send_payment(&caller, &first_payment);
+        self.send_payment(&caller, &second_payment);
+
+        (first_payment, second_payment).into()
+    }
+
+    /// Swap d'un montant fixe d'un token de la paire contre l'autre (produit constant)
+    #[payable("*")]
+    #[endpoint(swapTokensFixedInput)]
+    fn swap_tokens_fixed_input(&self, token_out: TokenIdentifier, amount_out_min: BigUint) -> EsdtTokenPayment {
+        self.require_active();
+
+        let payment = self.call_value().single_esdt();
+        let first_token_id = self.first_token_id().get();
+        let second_token_id = self.second_token_id().get();
+        require!(
+            (payment.token_identifier == first_token_id && token_out == second_token_id)
+                || (payment.token_identifier == second_token_id && token_out == first_token_id),
+            "Invalid tokens"
+        );
+        require!(payment.amount > 0u32, "Invalid amount_in");
+
+        let reserve_in = self.reserve(&payment.token_identifier).get();
+        let reserve_out = self.reserve(&token_out).get();
+        require!(reserve_in > 0u32 && reserve_out > 0u32, "Not enough reserve");
+
+        let amount_in_with_fee = &payment.amount * FEE_MULTIPLIER;
+        let amount_out = &amount_in_with_fee * &reserve_out / (reserve_in * FEE_DENOMINATOR + &amount_in_with_fee);
+        require!(amount_out >= amount_out_min, "Slippage exceeded");
+
+        self.reserve(&payment.token_identifier).update(|reserve| *reserve += &payment.amount);
+        self.reserve(&token_out).update(|reserve| *reserve -= &amount_out);
+
+        let caller = self.blockchain().get_caller();
+        let payment_out = EsdtTokenPayment::new(token_out, 0, amount_out);
+        self.send_payment(&caller, &payment_out);
+
+        payment_out
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn pause(&self) {
+        self.paused().set(true);
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn resume(&self) {
+        self.paused().set(false);
+    }
+
+    fn require_active(&self) {
+        require!(!self.paused().get(), "Not active");
+    }
+
+    fn send_payment(&self, to: &ManagedAddress, payment: &EsdtTokenPayment) {
+        if payment.amount > 0u32 {
+            self.send().direct_esdt(to, &payment.token_identifier, 0, &payment.amount);
+        }
+    }
+
+    #[view(getReservesAndTotalSupply)]
+    fn get_reserves_and_total_supply(&self) -> MultiValue3<BigUint, BigUint, BigUint> {
+        let first_reserve = self.reserve(&self.first_token_id().get()).get();
+        let second_reserve = self.reserve(&self.second_token_id().get()).get();
+        (first_reserve, second_reserve, self.lp_token_supply().get()).into()
+    }
+
+    #[view(getFirstTokenId)]
+    #[storage_mapper("first_token_id")]
+    fn first_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getSecondTokenId)]
+    #[storage_mapper("second_token_id")]
+    fn second_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getLpTokenIdentifier)]
+    #[storage_mapper("lpTokenIdentifier")]
+    fn lp_token_identifier(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getReserve)]
+    #[storage_mapper("reserve")]
+    fn reserve(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalSupply)]
+    #[storage_mapper("lp_token_supply")]
+    fn lp_token_supply(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+}
@@ -0,0 +1,38 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           12
+// Async Callback (empty):               1
+// Total number of exported functions:  15
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    mock_xexchange_pair
+    (
+        init => init
+        upgrade => upgrade
+        addLiquidity => add_liquidity
+        removeLiquidity => remove_liquidity
+        swapTokensFixedInput => swap_tokens_fixed_input
+        pause => pause
+        resume => resume
+        getReservesAndTotalSupply => get_reserves_and_total_supply
+        getFirstTokenId => first_token_id
+        getSecondTokenId => second_token_id
+        getLpTokenIdentifier => lp_token_identifier
+        getReserve => reserve
+        getTotalSupply => lp_token_supply
+        isPaused => paused
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -13,6 +13,33 @@ mod staking_proxy {
     }
 }
 
+mod circle_of_life_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait CircleOfLifeContractProxy {
+        #[endpoint(resolveBanAppeal)]
+        fn resolve_ban_appeal(&self, sc: ManagedAddress, approved: bool);
+
+        #[endpoint(unlockProtocolLiquidity)]
+        fn unlock_protocol_liquidity(&self, lock_id: u64, proposal_id: u64);
+
+        #[endpoint(removeProtocolLiquidity)]
+        fn remove_protocol_liquidity(&self, lock_id: u64, proposal_id: u64);
+
+        #[endpoint(distributeLiquidityProceeds)]
+        fn distribute_liquidity_proceeds(&self, proposal_id: u64);
+
+        /// Each share is passed already encoded (proposal call args)
+        #[endpoint(setDistributionTable)]
+        fn set_distribution_table(&self, shares: MultiValueEncoded<ManagedBuffer>);
+
+        /// The policy is passed already encoded (single proposal call arg)
+        #[endpoint(setCirculationPolicy)]
+        fn set_circulation_policy(&self, policy: ManagedBuffer, ring_id: OptionalValue<u64>);
+    }
+}
+
 /// Proposal status
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq)]
@@ -37,6 +64,30 @@ pub enum ProposalType {
     RemoveMember,       // Remove council member
     UpgradeContract,    // Upgrade a contract
     Custom,             // Custom proposal (text only)
+    LiftBan,            // Ban appeal from Circle of Life (target = banned SC, amount = EGLD bond)
+    UnlockProtocolLiquidity,     // Circle of Life: unlock an expired protocol LP lock (amount = lock id)
+    RemoveProtocolLiquidity,     // Circle of Life: remove the liquidity of an unlocked lock (amount = lock id)
+    DistributeLiquidityProceeds, // Circle of Life: unwrap and distribute the recovered WEGLD
+    SetDistributionTable,        // Circle of Life: replace the EGLD distribution table (call args = encoded shares)
+    SetCirculationPolicy,        // Circle of Life: change a ring's circulation policy (amount = ring id, call arg = encoded policy)
+}
+
+impl ProposalType {
+    /// Proposals executed by calling the protocol liquidity endpoints of Circle of Life
+    pub fn is_protocol_liquidity(&self) -> bool {
+        matches!(
+            self,
+            ProposalType::UnlockProtocolLiquidity
+                | ProposalType::RemoveProtocolLiquidity
+                | ProposalType::DistributeLiquidityProceeds
+        )
+    }
+
+    /// Proposals executed by an endpoint call on Circle of Life
+    pub fn is_circle_of_life_call(&self) -> bool {
+        self.is_protocol_liquidity()
+            || matches!(self, ProposalType::SetDistributionTable | ProposalType::SetCirculationPolicy)
+    }
 }
 
 /// Proposal data
@@ -210,12 +261,19 @@ pub trait XCirclexDaoV2 {
         self.egld_received_from_sc0_event(&payment);
     }
 
-    /// Get EGLD treasury balance (returns actual SC balance)
+    /// Get EGLD treasury balance (returns actual SC balance, minus ban appeal bonds in escrow)
     #[view(getEgldTreasuryBalance)]
     fn get_egld_treasury_balance(&self) -> BigUint {
         // Return actual SC EGLD balance instead of tracked storage
         // This ensures correct display even when EGLD is received via direct_egld
         self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0)
+            - self.appeal_bonds_escrowed().get()
+    }
+
+    /// Get the total EGLD held in escrow for open ban appeals
+    #[view(getAppealBondsEscrowed)]
+    fn get_appeal_bonds_escrowed(&self) -> BigUint {
+        self.appeal_bonds_escrowed().get()
     }
 
     // =========================================================================
@@ -234,13 +292,36 @@ pub trait XCirclexDaoV2 {
         proposal_type: ProposalType,
         target_address: ManagedAddress,
         amount: BigUint,
+        call_args: MultiValueEncoded<ManagedBuffer>,
     ) -> u64 {
         let caller = self.blockchain().get_caller();
         let token_id = self.xcirclex_token_id().get();
         let min_threshold = self.min_proposal_threshold().get();
         let is_council = self.council_members().contains(&caller);
+        let call_args = call_args.to_vec();
 
         // Validate proposal type specific requirements
+        require!(
+            proposal_type != ProposalType::LiftBan,
+            "Ban appeals must be created via Circle of Life Center"
+        );
+        if proposal_type.is_circle_of_life_call() {
+            require!(
+                !self.circle_of_life_address().is_empty(),
+                "Circle of Life address not configured"
+            );
+        }
+        if proposal_type.is_protocol_liquidity() {
+            require!(amount.to_u64().is_some(), "Invalid lock id");
+        }
+        if proposal_type == ProposalType::SetDistributionTable {
+            require!(!call_args.is_empty(), "Distribution table required");
+        } else if proposal_type == ProposalType::SetCirculationPolicy {
+            require!(call_args.len() == 1, "Exactly one circulation policy required");
+            require!(amount.to_u64().is_some(), "Invalid ring id");
+        } else {
+            require!(call_args.is_empty(), "Call arguments not supported for this proposal type");
+        }
         if proposal_type == ProposalType::TransferEgld {
             let egld_balance = self.egld_treasury().get();
             require!(
@@ -291,12 +372,75 @@ pub trait XCirclexDaoV2 {
 
         self.proposals(proposal_id).set(&proposal);
         self.active_proposals().insert(proposal_id);
+        if !call_args.is_empty() {
+            self.proposal_call_args(proposal_id).set(&call_args);
+        }
 
         self.proposal_created_event(proposal_id, &caller);
 
         proposal_id
     }
 
+    /// Create a ban appeal proposal on behalf of a banned member (only SC0)
+    /// The EGLD bond is held in escrow: refunded if the appeal passes,
+    /// otherwise added to the EGLD treasury
+    #[payable("EGLD")]
+    #[endpoint(createBanAppeal)]
+    fn create_ban_appeal(
+        &self,
+        member: ManagedAddress,
+        sc: ManagedAddress,
+        reason: ManagedBuffer,
+    ) -> u64 {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.circle_of_life_address().is_empty(),
+            "Circle of Life address not configured"
+        );
+        require!(
+            caller == self.circle_of_life_address().get(),
+            "Only Circle of Life Center can create ban appeals"
+        );
+
+        let bond = self.call_value().egld().clone_value();
+        require!(bond > 0, "Must send EGLD bond");
+
+        let escrowed = self.appeal_bonds_escrowed().get();
+        self.appeal_bonds_escrowed().set(&(&escrowed + &bond));
+
+        let current_time = self.blockchain().get_block_timestamp();
+        let voting_period = self.voting_period().get();
+        let timelock = self.timelock_period().get();
+
+        let proposal_id = self.proposal_count().get() + 1;
+        self.proposal_count().set(proposal_id);
+
+        let proposal = Proposal {
+            id: proposal_id,
+            proposer: member.clone(),
+            title: ManagedBuffer::from(b"Ban appeal"),
+            description: reason,
+            proposal_type: ProposalType::LiftBan,
+            status: ProposalStatus::Active,
+            votes_for: BigUint::zero(),
+            votes_against: BigUint::zero(),
+            created_at: current_time,
+            voting_ends_at: current_time + voting_period,
+            execution_time: current_time + voting_period + timelock,
+            target_address: sc.clone(),
+            amount: bond.clone(),
+            executed: false,
+        };
+
+        self.proposals(proposal_id).set(&proposal);
+        self.active_proposals().insert(proposal_id);
+
+        self.proposal_created_event(proposal_id, &member);
+        self.ban_appeal_created_event(proposal_id, &sc, &bond);
+
+        proposal_id
+    }
+
     /// Vote on a proposal (send tokens as voting power - tokens are returned after vote)
     #[payable("*")]
     #[endpoint(vote)]
@@ -392,6 +536,7 @@ pub trait XCirclexDaoV2 {
             proposal.status = ProposalStatus::Rejected;
             self.proposals(proposal_id).set(&proposal);
             self.active_proposals().swap_remove(&proposal_id);
+            self.reject_ban_appeal(&proposal);
             self.proposal_finalized_event(proposal_id, false);
             return;
         }
@@ -412,6 +557,7 @@ pub trait XCirclexDaoV2 {
             proposal.status = ProposalStatus::Rejected;
             self.proposals(proposal_id).set(&proposal);
             self.active_proposals().swap_remove(&proposal_id);
+            self.reject_ban_appeal(&proposal);
             self.proposal_finalized_event(proposal_id, false);
         }
     }
@@ -463,6 +609,16 @@ pub trait XCirclexDaoV2 {
             ProposalType::Custom => {
                 // Custom proposals are informational only
             },
+            ProposalType::LiftBan => {
+                self.execute_ban_lift(&proposal);
+            },
+            ProposalType::UnlockProtocolLiquidity
+            | ProposalType::RemoveProtocolLiquidity
+            | ProposalType::DistributeLiquidityProceeds
+            | ProposalType::SetDistributionTable
+            | ProposalType::SetCirculationPolicy => {
+                self.execute_circle_of_life_call(&proposal);
+            },
         }
 
         proposal.executed = true;
@@ -497,6 +653,7 @@ pub trait XCirclexDaoV2 {
         proposal.status = ProposalStatus::Cancelled;
         self.proposals(proposal_id).set(&proposal);
         self.active_proposals().swap_remove(&proposal_id);
+        self.reject_ban_appeal(&proposal);
 
         self.proposal_cancelled_event(proposal_id);
     }
@@ -525,6 +682,7 @@ pub trait XCirclexDaoV2 {
         proposal.status = ProposalStatus::Cancelled;
         self.proposals(proposal_id).set(&proposal);
         self.active_proposals().swap_remove(&proposal_id);
+        self.reject_ban_appeal(&proposal);
 
         self.proposal_vetoed_event(proposal_id, &caller);
     }
@@ -571,6 +729,16 @@ pub trait XCirclexDaoV2 {
             },
             ProposalType::UpgradeContract => {},
             ProposalType::Custom => {},
+            ProposalType::LiftBan => {
+                self.execute_ban_lift(&proposal);
+            },
+            ProposalType::UnlockProtocolLiquidity
+            | ProposalType::RemoveProtocolLiquidity
+            | ProposalType::DistributeLiquidityProceeds
+            | ProposalType::SetDistributionTable
+            | ProposalType::SetCirculationPolicy => {
+                self.execute_circle_of_life_call(&proposal);
+            },
         }
 
         proposal.executed = true;
@@ -610,6 +778,132 @@ pub trait XCirclexDaoV2 {
         self.egld_transferred_event(to, amount);
     }
 
+    /// Appeal passed: refund the bond to the member and lift the ban on SC0
+    fn execute_ban_lift(&self, proposal: &Proposal<Self::Api>) {
+        self.release_appeal_bond(&proposal.amount);
+        self.send().direct_egld(&proposal.proposer, &proposal.amount);
+
+        self.resolve_ban_appeal_on_sc0(proposal, true);
+        self.ban_appeal_closed_event(proposal.id, &proposal.target_address, true);
+    }
+
+    /// Appeal rejected, cancelled or vetoed: the bond goes to the EGLD treasury
+    fn reject_ban_appeal(&self, proposal: &Proposal<Self::Api>) {
+        if proposal.proposal_type != ProposalType::LiftBan {
+            return;
+        }
+
+        self.release_appeal_bond(&proposal.amount);
+        let current = self.egld_treasury().get();
+        self.egld_treasury().set(&(&current + &proposal.amount));
+
+        self.resolve_ban_appeal_on_sc0(proposal, false);
+        self.ban_appeal_closed_event(proposal.id, &proposal.target_address, false);
+    }
+
+    /// Closes the appeal on Circle of Life. Async so that a failure on SC0
+    /// (appeal already cleared, DAO address changed) never blocks the proposal
+    fn resolve_ban_appeal_on_sc0(&self, proposal: &Proposal<Self::Api>, approved: bool) {
+        self.circle_of_life_proxy(self.circle_of_life_address().get())
+            .resolve_ban_appeal(&proposal.target_address, approved)
+            .with_gas_limit(10_000_000u64)
+            .with_callback(self.callbacks().resolve_ban_appeal_callback(proposal.id))
+            .with_extra_gas_for_callback(5_000_000u64)
+            .register_promise();
+    }
+
+    /// On failure the appeal stays open on SC0 until its owner clears it
+    /// with clearBanAppeal
+    #[promises_callback]
+    fn resolve_ban_appeal_callback(
+        &self,
+        proposal_id: u64,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        if let ManagedAsyncCallResult::Err(err) = result {
+            self.ban_appeal_resolution_failed_event(proposal_id, &err.err_msg);
+        }
+    }
+
+    /// Calls the proposal's endpoint on SC0. Async: if SC0 rejects it (lock not
+    /// expired yet, price guard, invalid table or policy), the callback reopens the
+    /// proposal so it can be executed again. Protocol liquidity steps that fail later
+    /// on SC0's side (locker, pair, WEGLD) are reported via reopenCircleOfLifeProposal
+    fn execute_circle_of_life_call(&self, proposal: &Proposal<Self::Api>) {
+        let mut sc0 = self.circle_of_life_proxy(self.circle_of_life_address().get());
+        // Lock id or ring id, depending on the proposal type
+        let target_id = proposal.amount.to_u64().unwrap_or_default();
+        let call = match proposal.proposal_type {
+            ProposalType::UnlockProtocolLiquidity => sc0.unlock_protocol_liquidity(target_id, proposal.id),
+            ProposalType::RemoveProtocolLiquidity => sc0.remove_protocol_liquidity(target_id, proposal.id),
+            ProposalType::SetDistributionTable => {
+                let shares = self.proposal_call_args(proposal.id).get();
+                sc0.set_distribution_table(MultiValueEncoded::from(shares))
+            },
+            ProposalType::SetCirculationPolicy => {
+                let policy = self.proposal_call_args(proposal.id).get().get(0).clone();
+                sc0.set_circulation_policy(policy, OptionalValue::Some(target_id))
+            },
+            _ => sc0.distribute_liquidity_proceeds(proposal.id),
+        };
+        call.with_gas_limit(120_000_000u64)
+            .with_callback(self.callbacks().circle_of_life_call_callback(proposal.id))
+            .with_extra_gas_for_callback(10_000_000u64)
+            .register_promise();
+    }
+
+    #[promises_callback]
+    fn circle_of_life_call_callback(
+        &self,
+        proposal_id: u64,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        if let ManagedAsyncCallResult::Err(err) = result {
+            self.reopen_proposal(proposal_id, &err.err_msg);
+        }
+    }
+
+    /// Reports a protocol liquidity step that SC0 accepted but whose nested call
+    /// failed (only SC0). Ignored if the proposal is not an executed protocol
+    /// liquidity proposal, so SC0's callback never fails because of it
+    #[endpoint(reopenCircleOfLifeProposal)]
+    fn reopen_circle_of_life_proposal(&self, proposal_id: u64, reason: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.circle_of_life_address().is_empty()
+                && caller == self.circle_of_life_address().get(),
+            "Only Circle of Life Center can reopen proposals"
+        );
+        if self.proposals(proposal_id).is_empty() {
+            return;
+        }
+        let proposal = self.proposals(proposal_id).get();
+        if proposal.executed && proposal.proposal_type.is_protocol_liquidity() {
+            self.reopen_proposal(proposal_id, &reason);
+        }
+    }
+
+    /// Back to Passed so the proposal can be executed again
+    fn reopen_proposal(&self, proposal_id: u64, reason: &ManagedBuffer) {
+        let mut proposal = self.proposals(proposal_id).get();
+        proposal.executed = false;
+        proposal.status = ProposalStatus::Passed;
+        self.proposals(proposal_id).set(&proposal);
+        self.active_proposals().insert(proposal_id);
+        self.circle_of_life_call_failed_event(proposal_id, reason);
+    }
+
+    fn release_appeal_bond(&self, amount: &BigUint) {
+        let escrowed = self.appeal_bonds_escrowed().get();
+        self.appeal_bonds_escrowed().set(&(&escrowed - amount));
+    }
+
+    #[proxy]
+    fn circle_of_life_proxy(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> circle_of_life_proxy::Proxy<Self::Api>;
+
     fn get_total_token_supply(&self, _token_id: &TokenIdentifier) -> BigUint {
         // PI * 10^8 with 18 decimals
         BigUint::from(314_159_265u64) * BigUint::from(10u64).pow(18)
@@ -710,6 +1004,16 @@ pub trait XCirclexDaoV2 {
             },
             ProposalType::UpgradeContract => {},
             ProposalType::Custom => {},
+            ProposalType::LiftBan => {
+                self.execute_ban_lift(&proposal);
+            },
+            ProposalType::UnlockProtocolLiquidity
+            | ProposalType::RemoveProtocolLiquidity
+            | ProposalType::DistributeLiquidityProceeds
+            | ProposalType::SetDistributionTable
+            | ProposalType::SetCirculationPolicy => {
+                self.execute_circle_of_life_call(&proposal);
+            },
         }
 
         proposal.executed = true;
@@ -742,6 +1046,12 @@ pub trait XCirclexDaoV2 {
         self.proposals(proposal_id).get()
     }
 
+    /// Encoded arguments of a proposal calling Circle of Life (e.g. distribution shares)
+    #[view(getProposalCallArgs)]
+    fn get_proposal_call_args(&self, proposal_id: u64) -> MultiValueEncoded<ManagedBuffer> {
+        MultiValueEncoded::from(self.proposal_call_args(proposal_id).get())
+    }
+
     #[view(getProposalCount)]
     fn get_proposal_count(&self) -> u64 {
         self.proposal_count().get()
@@ -855,6 +1165,9 @@ pub trait XCirclexDaoV2 {
     #[storage_mapper("proposals")]
     fn proposals(&self, proposal_id: u64) -> SingleValueMapper<Proposal<Self::Api>>;
 
+    #[storage_mapper("proposal_call_args")]
+    fn proposal_call_args(&self, proposal_id: u64) -> SingleValueMapper<ManagedVec<ManagedBuffer>>;
+
     #[storage_mapper("proposal_count")]
     fn proposal_count(&self) -> SingleValueMapper<u64>;
 
@@ -899,6 +1212,9 @@ pub trait XCirclexDaoV2 {
     #[storage_mapper("total_egld_received")]
     fn total_egld_received(&self) -> SingleValueMapper<BigUint>;
 
+    #[storage_mapper("appeal_bonds_escrowed")]
+    fn appeal_bonds_escrowed(&self) -> SingleValueMapper<BigUint>;
+
     // =========================================================================
     // EVENTS
     // =========================================================================
@@ -993,6 +1309,28 @@ pub trait XCirclexDaoV2 {
         #[indexed] vetoed_by: &ManagedAddress,
     );
 
+    #[event("ban_appeal_created")]
+    fn ban_appeal_created_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] sc: &ManagedAddress,
+        #[indexed] bond: &BigUint,
+    );
+
+    #[event("ban_appeal_closed")]
+    fn ban_appeal_closed_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] sc: &ManagedAddress,
+        #[indexed] approved: bool,
+    );
+
+    #[event("ban_appeal_resolution_failed")]
+    fn ban_appeal_resolution_failed_event(&self, #[indexed] proposal_id: u64, error: &ManagedBuffer);
+
+    #[event("circle_of_life_call_failed")]
+    fn circle_of_life_call_failed_event(&self, #[indexed] proposal_id: u64, error: &ManagedBuffer);
+
     #[event("council_executed")]
     fn council_executed_event(
         &self,
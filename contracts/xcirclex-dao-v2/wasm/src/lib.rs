@@ -6,9 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           40
+// Endpoints:                           44
 // Async Callback (empty):               1
-// Total number of exported functions:  43
+// Promise callbacks:                    2
+// Total number of exported functions:  49
 
 #![no_std]
 
@@ -26,13 +27,16 @@ multiversx_sc_wasm_adapter::endpoints! {
         depositEgldToTreasury => deposit_egld_to_treasury
         receiveFromCircleOfLife => receive_from_circle_of_life
         getEgldTreasuryBalance => get_egld_treasury_balance
+        getAppealBondsEscrowed => get_appeal_bonds_escrowed
         createProposal => create_proposal
+        createBanAppeal => create_ban_appeal
         vote => vote
         finalizeProposal => finalize_proposal
         executeProposal => execute_proposal
         cancelProposal => cancel_proposal
         vetoProposal => veto_proposal
         councilExecute => council_execute
+        reopenCircleOfLifeProposal => reopen_circle_of_life_proposal
         setMinProposalThreshold => set_min_proposal_threshold
         setVotingPeriod => set_voting_period
         setTimelockPeriod => set_timelock_period
@@ -44,6 +48,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         setStakingContract => set_staking_contract
         setCircleOfLifeAddress => set_circle_of_life_address
         getProposal => get_proposal
+        getProposalCallArgs => get_proposal_call_args
         getProposalCount => get_proposal_count
         getActiveProposals => get_active_proposals
         getVotingPower => get_voting_power
@@ -60,6 +65,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         isCouncilMember => is_council_member
         getCouncilMembers => get_council_members
         getCouncilMemberCount => get_council_member_count
+        resolve_ban_appeal_callback => resolve_ban_appeal_callback
+        circle_of_life_call_callback => circle_of_life_call_callback
     )
 }
 
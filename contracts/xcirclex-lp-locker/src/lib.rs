@@ -40,9 +40,10 @@ pub trait XCirclexLpLocker {
 
     /// Verrouille des tokens LP pour une durée spécifiée (minimum 12 mois)
     /// @param lock_duration_days: Durée du lock en jours (minimum 365)
+    /// @return ID du lock créé
     #[payable("*")]
     #[endpoint(lockLpTokens)]
-    fn lock_lp_tokens(&self, lock_duration_days: u64) {
+    fn lock_lp_tokens(&self, lock_duration_days: u64) -> u64 {
         let caller = self.blockchain().get_caller();
         let payment = self.call_value().single_esdt();
 
@@ -85,6 +86,8 @@ pub trait XCirclexLpLocker {
             unlock_timestamp,
             &payment.amount
         );
+
+        lock_id
     }
 
     /// Prolonge la durée d'un lock existant
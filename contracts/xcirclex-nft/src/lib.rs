@@ -485,6 +485,24 @@ pub trait XCirclexNftV2 {
         self.member_cycles_completed(&member).set(cycles);
     }
 
+    /// Reporte le NFT et les cycles d'un membre vers son nouveau wallet
+    /// (appele par Circle of Life lors d'un transfert d'adhesion)
+    /// Le NFT lui-meme doit etre envoye au nouveau wallet par le membre
+    #[endpoint(transferMember)]
+    fn transfer_member(&self, old_member: ManagedAddress, new_member: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        let circle_contract = self.circle_of_life_contract().get();
+        require!(caller == circle_contract, "Only Circle of Life can transfer");
+        require!(self.member_nft_nonce(&new_member).is_empty(), "Already has NFT");
+
+        if !self.member_nft_nonce(&old_member).is_empty() {
+            let nonce = self.member_nft_nonce(&old_member).take();
+            self.member_nft_nonce(&new_member).set(nonce);
+        }
+        let cycles = self.member_cycles_completed(&old_member).take();
+        self.member_cycles_completed(&new_member).set(cycles);
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // HELPERS
     // ═══════════════════════════════════════════════════════════════
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           24
+// Endpoints:                           25
 // Async Callback:                       1
-// Total number of exported functions:  27
+// Total number of exported functions:  28
 
 #![no_std]
 
@@ -34,6 +34,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         evolveNft => evolve_nft
         checkEvolution => check_evolution
         updateMemberCycles => update_member_cycles
+        transferMember => transfer_member
         getNftInfo => get_nft_info
         hasNft => has_nft
         getNftLevel => get_nft_level_view